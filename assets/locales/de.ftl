@@ -0,0 +1,14 @@
+# Built-in widget texts
+sickle-tab-close = Tab schließen
+sickle-tab-popout = Tab herauslösen
+sickle-dropdown-filter = Zum Filtern tippen...
+sickle-dropdown-combobox = Wert eingeben...
+
+# Custom theme example
+-app = Custom Theme
+panel-title = Mein Panel
+switch-language = { $key } drücken, um die Sprache von { -app } zu wechseln
+switch-direction = { $key } drücken, um das Layout für rechtsläufige Sprachen zu spiegeln
+status-success = Erfolg
+status-warning = Warnung
+status-info = Hinweis
@@ -0,0 +1,14 @@
+# Built-in widget texts
+sickle-tab-close = Close Tab
+sickle-tab-popout = Popout Tab
+sickle-dropdown-filter = Type to filter...
+sickle-dropdown-combobox = Type a value...
+
+# Custom theme example
+-app = Custom Theme
+panel-title = My Panel
+switch-language = Press { $key } to switch the language of { -app }
+switch-direction = Press { $key } to mirror the layout for right-to-left languages
+status-success = success
+status-warning = warning
+status-info = info
@@ -437,6 +437,14 @@ fn prepare_animated_style_attribute(
         .clone()
         .map(to_animated_style_appl_variant)
         .collect();
+    let snapshot_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .map(to_animated_style_snapshot_variant)
+        .collect();
+    let from_static_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .map(to_animated_style_from_static_variant)
+        .collect();
     let builder_fns: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_animated_style_builder_fn).collect();
 
@@ -458,9 +466,20 @@ fn prepare_animated_style_attribute(
         }
 
         impl AnimatedStyleAttribute {
+            /// Converts a static attribute to a single value animated attribute.
+            ///
+            /// Returns `None` if the attribute cannot be animated.
+            pub fn from_static(attribute: &StaticStyleAttribute) -> Option<Self> {
+                match attribute {
+                    #(#from_static_variants)*
+                    _ => None,
+                }
+            }
+
             fn to_attribute(
                 &self,
                 current_state: &AnimationState,
+                transition: Option<(&Self, f32)>,
             ) -> StaticStyleAttribute {
                 match self {
                     #(#apply_variants)*
@@ -468,10 +487,36 @@ fn prepare_animated_style_attribute(
                 }
             }
 
+            /// Captures the value shown at `current_state` as a single value attribute,
+            /// blended from the `transition` source if there is one.
+            ///
+            /// Returns `None` for custom attributes, their values cannot be captured.
+            pub fn snapshot(
+                &self,
+                current_state: &AnimationState,
+                transition: Option<(&Self, f32)>,
+            ) -> Option<Self> {
+                match self {
+                    #(#snapshot_variants)*
+                    Self::Custom(_) => None,
+                }
+            }
+
             pub fn apply(
                 &self,
                 current_state: &AnimationState,
                 ui_style: &mut UiStyle,
+            ) {
+                self.apply_transitioned(current_state, None, ui_style);
+            }
+
+            /// Applies the value at `current_state`, blended from the `transition` source by
+            /// the given ratio. The source is expected to be a [`Self::snapshot`].
+            pub fn apply_transitioned(
+                &self,
+                current_state: &AnimationState,
+                transition: Option<(&Self, f32)>,
+                ui_style: &mut UiStyle,
             ) {
                 match self {
                     Self::Custom(callback) => {
@@ -484,7 +529,7 @@ fn prepare_animated_style_attribute(
                     }
                     _ => {
                         self
-                            .to_attribute(current_state)
+                            .to_attribute(current_state, transition)
                             .apply(ui_style);
                     }
                 }
@@ -682,10 +727,40 @@ fn to_interactive_style_appl_variant(style_attribute: &StyleAttribute) -> proc_m
 
 fn to_animated_style_appl_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
+    let value = to_animated_style_transition_value(style_attribute);
     quote! {
-        Self::#ident(bundle) => StaticStyleAttribute::#ident(
-            bundle.to_value(current_state),
-        ),
+        Self::#ident(bundle) => StaticStyleAttribute::#ident(#value),
+    }
+}
+
+fn to_animated_style_snapshot_variant(
+    style_attribute: &StyleAttribute,
+) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    let value = to_animated_style_transition_value(style_attribute);
+    quote! {
+        Self::#ident(bundle) => Some(Self::#ident(AnimatedVals::from(#value))),
+    }
+}
+
+fn to_animated_style_from_static_variant(
+    style_attribute: &StyleAttribute,
+) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        StaticStyleAttribute::#ident(value) => Some(Self::#ident(AnimatedVals::from(value.clone()))),
+    }
+}
+
+fn to_animated_style_transition_value(
+    style_attribute: &StyleAttribute,
+) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        match transition {
+            Some((Self::#ident(from), t)) => from.idle.lerp(bundle.to_value(current_state), t),
+            _ => bundle.to_value(current_state),
+        }
     }
 }
 
@@ -0,0 +1,263 @@
+use bevy::{prelude::*, ui::widget::measure_text_system};
+use unicode_bidi::{
+    bidi_class, get_base_direction_full, BidiClass, Direction, Level, ParagraphBidiInfo,
+};
+use unicode_bidi_mirroring::get_mirrored;
+
+use crate::{
+    localization::LocalizationUpdate,
+    text_overflow::TruncatedText,
+    theme::theme_data::{LayoutDirection, ThemeData},
+};
+
+pub struct BidiPlugin;
+
+impl Plugin for BidiPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BidiText>()
+            .configure_sets(
+                PostUpdate,
+                BidiUpdate
+                    .after(LocalizationUpdate)
+                    .before(measure_text_system),
+            )
+            .add_systems(PostUpdate, update_bidi_texts.in_set(BidiUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct BidiUpdate;
+
+/// The base direction of a paragraph
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TextDirection {
+    /// Taken from the first strong character, or the layout direction of the theme if the text
+    /// has none
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Shows the text of the entity in visual order.
+///
+/// Text is kept in logical (reading) order and reordered per line and section before layout, so
+/// right-to-left runs, embedded numbers and mirrored brackets show correctly. Added automatically
+/// to texts containing right-to-left characters, text set on the entity after that is picked up as
+/// the new logical text.
+///
+/// Levels and reordering follow the Unicode Bidirectional Algorithm, including explicit embeddings,
+/// isolates and bracket pairs. Runs do not cross section boundaries, wrapped lines are reordered as
+/// a whole and Arabic letters are not shaped into their joining forms.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BidiText {
+    direction: TextDirection,
+    resolved: LayoutDirection,
+    logical: Vec<String>,
+    visual: Vec<String>,
+}
+
+impl BidiText {
+    pub fn new(direction: TextDirection) -> Self {
+        Self {
+            direction,
+            ..default()
+        }
+    }
+
+    pub fn direction(&self) -> TextDirection {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    /// The base direction of the text, resolved from its `direction` and content
+    pub fn resolved_direction(&self) -> LayoutDirection {
+        self.resolved
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        self.resolved.is_rtl()
+    }
+
+    /// The text in logical order, all sections joined
+    pub fn logical_text(&self) -> String {
+        self.logical.concat()
+    }
+
+    /// The section values in logical order
+    pub fn logical_sections(&self) -> &Vec<String> {
+        &self.logical
+    }
+
+    fn resolve(&mut self, layout_direction: LayoutDirection) {
+        self.resolved = match self.direction {
+            TextDirection::LeftToRight => LayoutDirection::LeftToRight,
+            TextDirection::RightToLeft => LayoutDirection::RightToLeft,
+            TextDirection::Auto => self
+                .logical
+                .iter()
+                .find_map(|value| paragraph_direction(value))
+                .unwrap_or(layout_direction),
+        };
+
+        self.visual = self
+            .logical
+            .iter()
+            .map(|value| reorder(value, self.resolved))
+            .collect();
+    }
+}
+
+fn update_bidi_texts(
+    mut q_texts: Query<(
+        Entity,
+        &mut Text,
+        Option<&mut BidiText>,
+        Option<&TruncatedText>,
+    )>,
+    theme_data: Option<Res<ThemeData>>,
+    mut commands: Commands,
+) {
+    let theme_changed = theme_data
+        .as_ref()
+        .is_some_and(|theme_data| theme_data.is_changed());
+    let layout_direction = theme_data.map_or(LayoutDirection::default(), |theme_data| {
+        theme_data.layout_direction
+    });
+
+    for (entity, mut text, bidi_text, truncated_text) in &mut q_texts {
+        let bidi_changed = bidi_text
+            .as_ref()
+            .is_some_and(|bidi_text| bidi_text.is_changed());
+        if !(text.is_changed() || bidi_changed || (theme_changed && bidi_text.is_some())) {
+            continue;
+        }
+
+        let values: Vec<String> = text
+            .sections
+            .iter()
+            .map(|section| section.value.clone())
+            .collect();
+
+        // Neither the reordered nor the truncated text are new logical text
+        let truncated =
+            truncated_text.is_some_and(|truncated_text| *truncated_text.shown_sections() == values);
+        let new_text = match &bidi_text {
+            Some(bidi_text) => values != bidi_text.visual && !truncated,
+            None => true,
+        };
+
+        let mut resolved = match &bidi_text {
+            Some(_) if !new_text && !bidi_changed && !theme_changed => continue,
+            Some(bidi_text) => BidiText::clone(bidi_text),
+            None if values.iter().any(|value| has_rtl(value)) => BidiText::default(),
+            None => continue,
+        };
+
+        if new_text {
+            resolved.logical = values;
+        }
+        resolved.resolve(layout_direction);
+
+        if text.sections.len() == resolved.visual.len() {
+            for (index, visual) in resolved.visual.iter().enumerate() {
+                if text.sections[index].value != *visual {
+                    text.sections[index].value = visual.clone();
+                }
+            }
+        }
+
+        match bidi_text {
+            Some(mut bidi_text) => {
+                bidi_text.set_if_neq(resolved);
+            }
+            None => {
+                commands.entity(entity).insert(resolved);
+            }
+        }
+    }
+}
+
+/// Returns `true` if `text` contains right-to-left characters
+pub fn has_rtl(text: &str) -> bool {
+    text.chars().any(|character| {
+        matches!(
+            bidi_class(character),
+            BidiClass::R | BidiClass::AL | BidiClass::RLE | BidiClass::RLO | BidiClass::RLI
+        )
+    })
+}
+
+/// The direction of the first strong character of `text`, `None` if it has none
+pub fn paragraph_direction(text: &str) -> Option<LayoutDirection> {
+    match get_base_direction_full(text) {
+        Direction::Ltr => LayoutDirection::LeftToRight.into(),
+        Direction::Rtl => LayoutDirection::RightToLeft.into(),
+        Direction::Mixed => None,
+    }
+}
+
+/// Returns `text` in visual order, each line laid out in the `direction` paragraph
+pub fn reorder(text: &str, direction: LayoutDirection) -> String {
+    text.split('\n')
+        .map(|line| reorder_line(line, direction))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn reorder_line(line: &str, direction: LayoutDirection) -> String {
+    let base_level = direction.pick(Level::ltr(), Level::rtl());
+    let bidi_info = ParagraphBidiInfo::new(line, Some(base_level));
+    if !bidi_info.has_rtl() {
+        return line
+            .chars()
+            .filter(|character| !is_format_char(*character))
+            .collect();
+    }
+
+    let characters: Vec<char> = line.chars().collect();
+    let levels = bidi_info.reordered_levels_per_char(0..line.len());
+    let mut order = ParagraphBidiInfo::reorder_visual(&levels);
+
+    // Combining marks stay after their base character in reversed runs (rule L3)
+    let is_reversed_mark =
+        |index: usize| levels[index].is_rtl() && bidi_class(characters[index]) == BidiClass::NSM;
+    let mut i = 0;
+    while i < order.len() {
+        if !is_reversed_mark(order[i]) {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < order.len() && is_reversed_mark(order[i]) {
+            i += 1;
+        }
+        if i < order.len() {
+            order[start..=i].reverse();
+            i += 1;
+        }
+    }
+
+    // Mirrored glyphs are picked for brackets in right-to-left runs (rule L4)
+    order
+        .into_iter()
+        .filter(|index| !is_format_char(characters[*index]))
+        .map(|index| match levels[index].is_rtl() {
+            true => get_mirrored(characters[index]).unwrap_or(characters[index]),
+            false => characters[index],
+        })
+        .collect()
+}
+
+/// Directional marks, embeddings, overrides and isolates, which are not rendered
+fn is_format_char(character: char) -> bool {
+    matches!(
+        character,
+        '\u{200E}' | '\u{200F}' | '\u{061C}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
-use bevy::ui::RelativeCursorPosition;
+use bevy::ui::{CalculatedClip, RelativeCursorPosition};
 use bevy::window::{CursorGrabMode, PrimaryWindow};
 
 use crate::flux_interaction::{FluxInteraction, FluxInteractionUpdate};
+use crate::ui_utils::UiUtils;
 
 pub struct DragInteractionPlugin;
 
@@ -14,6 +15,7 @@ impl Plugin for DragInteractionPlugin {
                 (
                     update_drag_progress,
                     update_drag_state,
+                    update_touch_drag_state,
                     update_cursor_confinement_from_drag.run_if(is_windows_os),
                 )
                     .chain()
@@ -55,11 +57,15 @@ pub enum DragState {
     DragCanceled,
 }
 
+/// The pointer driving a drag
+///
+/// Pen input is reported by the platform as touch input and is tracked as `Touch`.
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
 #[reflect]
 pub enum DragSource {
     #[default]
     Mouse,
+    /// Drag follows the touch with the given id, independent of other touches
     Touch(u64),
 }
 
@@ -76,13 +82,13 @@ fn update_cursor_confinement_from_drag(
         return;
     };
 
-    if let Some(_) = q_draggable
-        .iter()
-        .find(|&draggable| draggable.state == DragState::DragStart)
-    {
+    if let Some(_) = q_draggable.iter().find(|&draggable| {
+        draggable.source == DragSource::Mouse && draggable.state == DragState::DragStart
+    }) {
         window.cursor.grab_mode = CursorGrabMode::Confined;
     } else if let Some(_) = q_draggable.iter().find(|&draggable| {
-        draggable.state == DragState::DragEnd || draggable.state == DragState::DragCanceled
+        draggable.source == DragSource::Mouse
+            && (draggable.state == DragState::DragEnd || draggable.state == DragState::DragCanceled)
     }) {
         window.cursor.grab_mode = CursorGrabMode::None;
     }
@@ -101,12 +107,18 @@ fn update_drag_progress(
     r_keys: Res<ButtonInput<KeyCode>>,
 ) {
     for (mut draggable, flux_interaction, relcurpos, node, global_trans) in &mut q_draggable {
+        // Touch drags are tracked per touch id, the flux interaction only follows the first touch
+        let is_pressed = match draggable.source {
+            DragSource::Mouse => *flux_interaction == FluxInteraction::Pressed,
+            DragSource::Touch(id) => r_touches.get_pressed(id).is_some(),
+        };
+
         if draggable.state == DragState::DragEnd {
             draggable.state = DragState::Inactive;
             draggable.clear();
         } else if draggable.state == DragState::DragCanceled {
             draggable.state = DragState::Inactive;
-        } else if *flux_interaction == FluxInteraction::Pressed
+        } else if is_pressed
             && (draggable.state == DragState::MaybeDragged
                 || draggable.state == DragState::DragStart
                 || draggable.state == DragState::Dragging)
@@ -171,28 +183,33 @@ fn update_drag_state(
     r_touches: Res<Touches>,
 ) {
     for (mut draggable, flux_interaction, relcurpos, node, global_trans) in &mut q_draggable {
+        // Touch drags are started and ended by `update_touch_drag_state`
+        if let DragSource::Touch(_) = draggable.source {
+            if draggable.state != DragState::Inactive {
+                continue;
+            }
+        }
+
         if *flux_interaction == FluxInteraction::Pressed
             && draggable.state != DragState::MaybeDragged
         {
-            let mut drag_source = DragSource::Mouse;
+            // Presses while touches are active are generated by the first touch
+            if r_touches.iter().next().is_some() {
+                continue;
+            }
 
             // No window method: Cursor is at the Node's top left screenspace rect.min,
             // plus the relative screenspace position in the Node, which is relcurpos times the node rect's size
-            let mut initial_position;
-            if let Some(relative_cursor_pos) = relcurpos.normalized {
-                let node_rect = node.logical_rect(global_trans);
-                initial_position = Some(node_rect.min + (node_rect.size() * relative_cursor_pos))
-            } else {
-                initial_position = None;
-            }
-
-            if initial_position.is_none() {
-                initial_position = r_touches.first_pressed_position();
-                drag_source = DragSource::Touch(r_touches.iter().next().unwrap().id());
-            }
+            let initial_position = match relcurpos.normalized {
+                Some(relative_cursor_pos) => {
+                    let node_rect = node.logical_rect(global_trans);
+                    Some(node_rect.min + (node_rect.size() * relative_cursor_pos))
+                }
+                None => None,
+            };
 
             draggable.state = DragState::MaybeDragged;
-            draggable.source = drag_source;
+            draggable.source = DragSource::Mouse;
             draggable.origin = initial_position;
             draggable.position = initial_position;
             draggable.diff = Vec2::default().into();
@@ -208,3 +225,75 @@ fn update_drag_state(
         }
     }
 }
+
+fn update_touch_drag_state(
+    mut q_draggable: Query<(
+        Entity,
+        &mut Draggable,
+        &FluxInteraction,
+        &Node,
+        &GlobalTransform,
+        &ViewVisibility,
+        Option<&CalculatedClip>,
+    )>,
+    r_touches: Res<Touches>,
+) {
+    for touch in r_touches.iter_just_pressed() {
+        let position = touch.position();
+
+        // Only draggables are hit tested, the topmost one under the touch is picked
+        let Some((entity, ..)) = q_draggable
+            .iter()
+            .filter(
+                |(_, draggable, flux_interaction, node, transform, visibility, clip)| {
+                    draggable.state == DragState::Inactive
+                        && **flux_interaction != FluxInteraction::Disabled
+                        && visibility.get()
+                        && UiUtils::node_contains_point(node, transform, *clip, position)
+                },
+            )
+            .max_by_key(|(_, _, _, node, ..)| node.stack_index())
+        else {
+            continue;
+        };
+
+        // Safe unwrap: entity comes from the same query
+        let (_, mut draggable, ..) = q_draggable.get_mut(entity).unwrap();
+        draggable.state = DragState::MaybeDragged;
+        draggable.source = DragSource::Touch(touch.id());
+        draggable.origin = position.into();
+        draggable.position = position.into();
+        draggable.diff = Vec2::default().into();
+    }
+
+    for (_, mut draggable, ..) in &mut q_draggable {
+        let DragSource::Touch(id) = draggable.source else {
+            continue;
+        };
+
+        if draggable.state != DragState::MaybeDragged
+            && draggable.state != DragState::DragStart
+            && draggable.state != DragState::Dragging
+        {
+            continue;
+        }
+
+        let is_dragged =
+            draggable.state == DragState::DragStart || draggable.state == DragState::Dragging;
+        if r_touches.just_canceled(id) {
+            if is_dragged {
+                draggable.state = DragState::DragCanceled;
+            } else {
+                draggable.state = DragState::Inactive;
+            }
+            draggable.clear();
+        } else if r_touches.just_released(id) || r_touches.get_pressed(id).is_none() {
+            if is_dragged {
+                draggable.state = DragState::DragEnd;
+            } else {
+                draggable.state = DragState::Inactive;
+                draggable.clear();
+            }
+        }
+    }
+}
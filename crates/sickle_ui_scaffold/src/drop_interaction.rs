@@ -1,6 +1,9 @@
-use bevy::{prelude::*, ui::RelativeCursorPosition};
+use bevy::{prelude::*, ui::CalculatedClip};
 
-use crate::drag_interaction::{DragState, Draggable, DraggableUpdate};
+use crate::{
+    drag_interaction::{DragState, Draggable, DraggableUpdate},
+    ui_utils::UiUtils,
+};
 
 pub struct DropInteractionPlugin;
 
@@ -46,38 +49,68 @@ fn should_update_drop_zones(
 }
 
 fn update_drop_zones(
-    q_droppables: Query<(Entity, &Draggable), (With<Droppable>, Changed<Draggable>)>,
-    q_drop_zone_data: Query<(Entity, &Interaction, &Node, &RelativeCursorPosition), With<DropZone>>,
+    q_droppables: Query<(Entity, &Draggable), With<Droppable>>,
+    q_drop_zone_data: Query<
+        (
+            Entity,
+            &Node,
+            &GlobalTransform,
+            &ViewVisibility,
+            Option<&CalculatedClip>,
+        ),
+        With<DropZone>,
+    >,
     mut q_drop_zones: Query<(Entity, &mut DropZone)>,
 ) {
     // Run condition makes sure we are dragging a droppable.
-    // We have no information if the interaction is from the same source,
-    // check if cursor is over any.
-    //
-    // Technically, a pointer / finger could be currently hovering the zone
-    // while another pointer / finger drags the droppable, but the ui_focus, flux and
-    // drag interactions only track main pointers.
-    let mut hovered_to_stack_index: Vec<(Entity, u32)> = q_drop_zone_data
-        .iter()
-        .filter(|(_, interaction, _, rel_pos)| {
-            **interaction == Interaction::Hovered && rel_pos.mouse_over()
-        })
-        .map(|(entity, _, node, _)| (entity, node.stack_index()))
-        .collect();
-
-    hovered_to_stack_index.sort_by_key(|(_, i)| *i);
-    if let Some((top_hovered, _)) = hovered_to_stack_index.last() {
-        // Safe unwrap: ID comes from a broader query of DropZones.
-        let (_, mut drop_zone) = q_drop_zones.get_mut(*top_hovered).unwrap();
-
-        // Take the first droppable that is moving
-        // ui_focus, flux and drag interactions only track the main pointer interaction
-        let (droppable_entity, draggable) = q_droppables
+    // Zones are hit tested against the position of each droppable, so that
+    // multiple touches can drag droppables to different zones at the same time.
+    let mut targeted_zones: Vec<(Entity, Entity, &Draggable)> = Vec::new();
+    for (droppable_entity, draggable) in &q_droppables {
+        if draggable.state == DragState::Inactive || draggable.state == DragState::MaybeDragged {
+            continue;
+        }
+
+        let top_hovered = match draggable.position {
+            Some(position) => q_drop_zone_data
+                .iter()
+                .filter(|(_, node, transform, visibility, clip)| {
+                    visibility.get()
+                        && UiUtils::node_contains_point(node, transform, *clip, position)
+                })
+                .max_by_key(|(_, node, ..)| node.stack_index())
+                .map(|(entity, ..)| entity),
+            // Canceled drags are cleared, keep the zone they were over
+            None => q_drop_zones
+                .iter()
+                .find(|(_, drop_zone)| drop_zone.incoming_droppable == Some(droppable_entity))
+                .map(|(entity, _)| entity),
+        };
+
+        let Some(top_hovered) = top_hovered else {
+            continue;
+        };
+
+        // The first droppable over a zone wins
+        if targeted_zones.iter().all(|(zone, ..)| *zone != top_hovered) {
+            targeted_zones.push((top_hovered, droppable_entity, draggable));
+        }
+    }
+
+    for (drop_zone_id, mut drop_zone) in &mut q_drop_zones {
+        let Some((_, droppable_entity, draggable)) = targeted_zones
             .iter()
-            .find(|(_, draggable)| {
-                draggable.state != DragState::Inactive && draggable.state != DragState::MaybeDragged
-            })
-            .unwrap();
+            .find(|(zone, ..)| *zone == drop_zone_id)
+        else {
+            if drop_zone.drop_phase == DropPhase::DroppableEntered
+                || drop_zone.drop_phase == DropPhase::DroppableHover
+            {
+                drop_zone.drop_phase = DropPhase::DroppableLeft;
+                drop_zone.incoming_droppable = None;
+                drop_zone.position = None;
+            }
+            continue;
+        };
 
         // See update_drop_zone_single_frame_state which executes just before this system
         if drop_zone.drop_phase == DropPhase::Inactive {
@@ -92,37 +125,12 @@ fn update_drop_zones(
             || draggable.state == DragState::Dragging
             || draggable.state == DragState::DragEnd
         {
-            drop_zone.incoming_droppable = droppable_entity.into();
+            drop_zone.incoming_droppable = (*droppable_entity).into();
             drop_zone.position = draggable.position;
         } else {
             drop_zone.incoming_droppable = None;
             drop_zone.position = None;
         }
-
-        // Update all the other zones
-        for (dropzone_id, mut drop_zone) in &mut q_drop_zones {
-            if dropzone_id == *top_hovered {
-                continue;
-            }
-
-            if drop_zone.drop_phase == DropPhase::DroppableEntered
-                || drop_zone.drop_phase == DropPhase::DroppableHover
-            {
-                drop_zone.drop_phase = DropPhase::DroppableLeft;
-                drop_zone.incoming_droppable = None;
-                drop_zone.position = None;
-            }
-        }
-    } else {
-        for (_, mut drop_zone) in &mut q_drop_zones {
-            if drop_zone.drop_phase == DropPhase::DroppableEntered
-                || drop_zone.drop_phase == DropPhase::DroppableHover
-            {
-                drop_zone.drop_phase = DropPhase::DroppableLeft;
-                drop_zone.incoming_droppable = None;
-                drop_zone.position = None;
-            }
-        }
     }
 }
 
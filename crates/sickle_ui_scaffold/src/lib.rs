@@ -1,7 +1,10 @@
+pub mod bidi;
 pub mod drag_interaction;
 pub mod drop_interaction;
 pub mod flux_interaction;
+pub mod localization;
 pub mod scroll_interaction;
+pub mod text_overflow;
 pub mod theme;
 pub mod ui_builder;
 pub mod ui_commands;
@@ -13,13 +16,16 @@ use serde::{Deserialize, Serialize};
 
 pub mod prelude {
     pub use super::{
+        bidi::{BidiText, BidiUpdate, TextDirection},
         drag_interaction::{DragState, Draggable, DraggableUpdate},
         drop_interaction::{DropPhase, DropZone, Droppable, DroppableUpdate},
         flux_interaction::{
             FluxInteraction, FluxInteractionStopwatch, FluxInteractionStopwatchLock,
             FluxInteractionUpdate, TrackedInteraction,
         },
+        localization::{Locale, LocaleCatalog, LocalizationUpdate, LocalizedText},
         scroll_interaction::{ScrollAxis, Scrollable, ScrollableUpdate},
+        text_overflow::{TextOverflow, TextOverflowUpdate, TruncatedText},
         theme::prelude::*,
         ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot, UiRoot},
         ui_commands::ManagePseudoStateExt,
@@ -30,7 +36,18 @@ pub mod prelude {
 }
 
 #[derive(
-    Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Reflect,
+    Serialize,
+    Deserialize,
 )]
 pub enum CardinalDirection {
     #[default]
@@ -0,0 +1,493 @@
+use std::fmt;
+
+use bevy::{prelude::*, ui::widget::measure_text_system, utils::HashMap};
+
+use self::loader::LocaleCatalogLoader;
+
+/// Nesting limit of message references, guards against reference cycles
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LocaleCatalog>()
+            .init_asset_loader::<LocaleCatalogLoader>()
+            .init_resource::<Locale>()
+            .register_type::<LocalizedText>()
+            .configure_sets(PostUpdate, LocalizationUpdate.before(measure_text_system))
+            .add_systems(
+                PostUpdate,
+                (update_locale_messages, update_localized_texts)
+                    .chain()
+                    .in_set(LocalizationUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct LocalizationUpdate;
+
+fn update_locale_messages(
+    mut locale: ResMut<Locale>,
+    catalogs: Res<Assets<LocaleCatalog>>,
+    mut asset_events: EventReader<AssetEvent<LocaleCatalog>>,
+) {
+    let catalog_changed = asset_events.read().any(|event| match event {
+        AssetEvent::Added { id } | AssetEvent::Modified { id } | AssetEvent::Removed { id } => {
+            locale.catalogs.iter().any(|catalog| catalog.id() == *id)
+        }
+        _ => false,
+    });
+
+    if locale.is_changed() || catalog_changed {
+        let messages = locale.collect_messages(&catalogs);
+        locale.messages = messages;
+    }
+}
+
+fn update_localized_texts(
+    mut q_texts: Query<(Ref<LocalizedText>, &mut Text)>,
+    locale: Res<Locale>,
+) {
+    for (localized_text, mut text) in &mut q_texts {
+        if !(localized_text.is_changed() || (locale.is_changed() && !localized_text.is_literal())) {
+            continue;
+        }
+
+        let value = locale.text(&localized_text);
+        let Some(section) = text.sections.first() else {
+            continue;
+        };
+
+        if section.value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
+
+/// Messages of a single language, loaded from `.ftl` or `.lang` files.
+///
+/// Both use the same syntax, a subset of [Fluent](https://projectfluent.org/):
+///
+/// ```ftl
+/// # Comment
+/// tab-close = Close Tab
+/// welcome = Welcome, { $name }!
+/// -brand = Sickle
+/// about = About { -brand }
+///     continued on the next line
+///     .tooltip = Available as `about.tooltip`
+/// ```
+///
+/// Placeables can be arguments (`{ $name }`), other messages or terms (`{ -brand }`) and
+/// string literals (`{ "{" }`). Selectors are not supported. The language is the file stem,
+/// i.e. `locales/de-DE.ftl` is German (Germany), unless set in the loader settings.
+#[derive(Asset, Clone, Debug, Default, TypePath)]
+pub struct LocaleCatalog {
+    pub language: String,
+    pub messages: HashMap<String, String>,
+}
+
+impl LocaleCatalog {
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_message(mut self, key: impl Into<String>, pattern: impl Into<String>) -> Self {
+        self.messages.insert(key.into(), pattern.into());
+        self
+    }
+
+    /// Parses the messages of a catalog file, see [`LocaleCatalog`] for the syntax
+    pub fn parse(source: &str) -> Result<HashMap<String, String>, String> {
+        let mut messages = HashMap::new();
+        let mut message: Option<String> = None;
+        let mut entry: Option<(String, String)> = None;
+
+        for (index, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                LocaleCatalog::push_entry(&mut messages, entry.take());
+                message = None;
+
+                if trimmed.starts_with('#') {
+                    continue;
+                }
+
+                let Some((key, pattern)) = trimmed.split_once('=') else {
+                    return Err(format!("Line {}: expected `key = value`", index + 1));
+                };
+                let key = key.trim();
+                if !LocaleCatalog::is_identifier(key.strip_prefix('-').unwrap_or(key)) {
+                    return Err(format!("Line {}: invalid key {}", index + 1, key));
+                }
+
+                message = Some(key.into());
+                entry = Some((key.into(), pattern.trim().into()));
+                continue;
+            }
+
+            let Some(key) = &message else {
+                return Err(format!(
+                    "Line {}: indented line outside of a message",
+                    index + 1
+                ));
+            };
+
+            if let Some(attribute) = trimmed.strip_prefix('.') {
+                let Some((name, pattern)) = attribute.split_once('=') else {
+                    return Err(format!("Line {}: expected `.attribute = value`", index + 1));
+                };
+
+                LocaleCatalog::push_entry(&mut messages, entry.take());
+                entry = Some((format!("{}.{}", key, name.trim()), pattern.trim().into()));
+            } else if let Some((_, pattern)) = entry.as_mut() {
+                if !pattern.is_empty() {
+                    pattern.push('\n');
+                }
+                pattern.push_str(trimmed);
+            }
+        }
+
+        LocaleCatalog::push_entry(&mut messages, entry);
+
+        Ok(messages)
+    }
+
+    fn push_entry(messages: &mut HashMap<String, String>, entry: Option<(String, String)>) {
+        // Messages with only attributes have no value of their own
+        if let Some((key, pattern)) = entry.filter(|(_, pattern)| !pattern.is_empty()) {
+            messages.insert(key, pattern);
+        }
+    }
+
+    fn is_identifier(key: &str) -> bool {
+        key.starts_with(|c: char| c.is_ascii_alphabetic())
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+}
+
+/// The active language and the catalogs its messages are looked up in.
+///
+/// Catalogs of the `fallback` language are used for messages missing in the active language,
+/// and catalogs of the primary language match regional variants, i.e. `de` is used for `de-AT`.
+/// Changing the language re-renders all [`LocalizedText`].
+#[derive(Resource, Debug)]
+pub struct Locale {
+    language: String,
+    fallback: String,
+    catalogs: Vec<Handle<LocaleCatalog>>,
+    messages: HashMap<String, String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new("en-US")
+    }
+}
+
+impl Locale {
+    pub fn new(language: impl Into<String>) -> Self {
+        let language = language.into();
+
+        Self {
+            fallback: language.clone(),
+            language,
+            catalogs: Vec::new(),
+            messages: HashMap::new(),
+        }
+    }
+
+    pub fn with_fallback(self, fallback: impl Into<String>) -> Self {
+        Self {
+            fallback: fallback.into(),
+            ..self
+        }
+    }
+
+    pub fn with_catalog(mut self, catalog: Handle<LocaleCatalog>) -> Self {
+        self.add_catalog(catalog);
+        self
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = language.into();
+    }
+
+    pub fn fallback(&self) -> &str {
+        &self.fallback
+    }
+
+    pub fn set_fallback(&mut self, fallback: impl Into<String>) {
+        self.fallback = fallback.into();
+    }
+
+    pub fn catalogs(&self) -> &Vec<Handle<LocaleCatalog>> {
+        &self.catalogs
+    }
+
+    /// Catalogs can be added before they are loaded, texts are updated once they are
+    pub fn add_catalog(&mut self, catalog: Handle<LocaleCatalog>) {
+        if !self.catalogs.contains(&catalog) {
+            self.catalogs.push(catalog);
+        }
+    }
+
+    pub fn remove_catalog(&mut self, catalog: &Handle<LocaleCatalog>) {
+        self.catalogs.retain(|handle| handle != catalog);
+    }
+
+    pub fn has_message(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    /// Resolves `text` in the active language
+    pub fn text(&self, text: &LocalizedText) -> String {
+        match text {
+            LocalizedText::Literal(text) => text.clone(),
+            LocalizedText::Message {
+                key,
+                args,
+                fallback,
+            } => match self.messages.get(key) {
+                Some(pattern) => self.format_pattern(pattern, args, 0),
+                None => fallback.clone().unwrap_or_else(|| key.clone()),
+            },
+        }
+    }
+
+    fn collect_messages(&self, catalogs: &Assets<LocaleCatalog>) -> HashMap<String, String> {
+        let primary_language = Locale::primary_language(&self.language);
+        let languages = [
+            self.fallback.as_str(),
+            primary_language,
+            self.language.as_str(),
+        ];
+
+        // Later languages override earlier ones
+        let mut messages = HashMap::new();
+        for language in languages {
+            for catalog in self
+                .catalogs
+                .iter()
+                .filter_map(|handle| catalogs.get(handle))
+            {
+                if catalog.language.eq_ignore_ascii_case(language) {
+                    messages.extend(catalog.messages.clone());
+                }
+            }
+        }
+
+        messages
+    }
+
+    fn primary_language(language: &str) -> &str {
+        language.split(['-', '_']).next().unwrap_or(language)
+    }
+
+    fn format_pattern(&self, pattern: &str, args: &[(String, String)], depth: usize) -> String {
+        let mut result = String::new();
+        let mut rest = pattern;
+
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+
+            result.push_str(&self.format_placeable(rest[1..end].trim(), args, depth));
+            rest = &rest[end + 1..];
+        }
+
+        result.push_str(rest);
+
+        result
+    }
+
+    fn format_placeable(
+        &self,
+        expression: &str,
+        args: &[(String, String)],
+        depth: usize,
+    ) -> String {
+        if let Some(name) = expression.strip_prefix('$') {
+            if let Some((_, value)) = args.iter().find(|(arg, _)| arg == name) {
+                return value.clone();
+            }
+        } else if let Some(literal) = expression
+            .strip_prefix('"')
+            .and_then(|expression| expression.strip_suffix('"'))
+        {
+            return literal.into();
+        } else if depth < MAX_REFERENCE_DEPTH {
+            if let Some(pattern) = self.messages.get(expression) {
+                return self.format_pattern(pattern, args, depth + 1);
+            }
+        }
+
+        format!("{{{}}}", expression)
+    }
+}
+
+/// Text that is either shown as is, or looked up in the [`Locale`].
+///
+/// As a component on an entity with [`Text`], the first section is kept in sync with the active
+/// language. Text set on the entity directly is replaced when the language changes, use
+/// `update_text` to change it instead.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum LocalizedText {
+    /// Shown as is, in any language
+    Literal(String),
+    /// A message of the locale catalogs, `fallback` (or the key) is shown if none has it
+    Message {
+        key: String,
+        args: Vec<(String, String)>,
+        fallback: Option<String>,
+    },
+}
+
+impl Default for LocalizedText {
+    fn default() -> Self {
+        Self::Literal(String::new())
+    }
+}
+
+impl LocalizedText {
+    /// A message of the locale catalogs
+    pub fn new(key: impl Into<String>) -> Self {
+        Self::Message {
+            key: key.into(),
+            args: Vec::new(),
+            fallback: None,
+        }
+    }
+
+    pub fn literal(text: impl Into<String>) -> Self {
+        Self::Literal(text.into())
+    }
+
+    /// Sets the value of the `{ $name }` placeables of the message, no-op for literals
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        if let LocalizedText::Message { args, .. } = &mut self {
+            let name = name.into();
+            let value = value.to_string();
+            match args.iter_mut().find(|(arg, _)| *arg == name) {
+                Some((_, current)) => *current = value,
+                None => args.push((name, value)),
+            }
+        }
+
+        self
+    }
+
+    /// Sets the text shown if no catalog has the message, no-op for literals
+    pub fn with_fallback(mut self, text: impl Into<String>) -> Self {
+        if let LocalizedText::Message { fallback, .. } = &mut self {
+            *fallback = Some(text.into());
+        }
+
+        self
+    }
+
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            LocalizedText::Literal(_) => None,
+            LocalizedText::Message { key, .. } => Some(key),
+        }
+    }
+
+    pub fn is_literal(&self) -> bool {
+        matches!(self, LocalizedText::Literal(_))
+    }
+}
+
+/// Literals as is, messages as their fallback or key. Use [`Locale::text`] to resolve them.
+impl fmt::Display for LocalizedText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalizedText::Literal(text) => write!(f, "{}", text),
+            LocalizedText::Message { key, fallback, .. } => {
+                write!(f, "{}", fallback.as_ref().unwrap_or(key))
+            }
+        }
+    }
+}
+
+/// Any text is a literal, so APIs taking `impl Into<LocalizedText>` accept `impl Into<String>` as well.
+impl<T: Into<String>> From<T> for LocalizedText {
+    fn from(text: T) -> Self {
+        LocalizedText::Literal(text.into())
+    }
+}
+
+pub mod loader {
+    use std::io::{Error, ErrorKind};
+
+    use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+    use serde::{Deserialize, Serialize};
+
+    use super::LocaleCatalog;
+
+    #[derive(Default, Serialize, Deserialize)]
+    pub struct LocaleCatalogLoaderSettings {
+        /// Language of the catalog, the file stem if `None`
+        pub language: Option<String>,
+    }
+
+    #[derive(Default)]
+    pub(crate) struct LocaleCatalogLoader;
+
+    impl AssetLoader for LocaleCatalogLoader {
+        type Asset = LocaleCatalog;
+        type Settings = LocaleCatalogLoaderSettings;
+        type Error = Error;
+
+        async fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader<'_>,
+            settings: &'a Self::Settings,
+            load_context: &'a mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut source = String::new();
+            reader.read_to_string(&mut source).await?;
+
+            let messages = LocaleCatalog::parse(&source)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+
+            let language = match &settings.language {
+                Some(language) => language.clone(),
+                None => load_context
+                    .path()
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .map(String::from)
+                    .ok_or_else(|| {
+                        Error::new(ErrorKind::InvalidInput, "Catalog path has no file stem")
+                    })?,
+            };
+
+            Ok(LocaleCatalog { language, messages })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["ftl", "lang"]
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use bevy::{
+    ecs::system::EntityCommand,
+    prelude::*,
+    text::{TextLayoutInfo, TextMeasureInfo},
+    ui::{
+        widget::{measure_text_system, text_system},
+        ContentSize, DefaultUiCamera, FixedMeasure, NodeMeasure, UiSystem,
+    },
+};
+
+use crate::{
+    bidi::BidiText,
+    ui_style::{
+        generated::{
+            LockableStyleAttribute, SetFlexShrinkUncheckedExt, SetMaxWidthUncheckedExt,
+            SetMinWidthUncheckedExt, SetWidthUncheckedExt,
+        },
+        manual::{SetLockedAttribute, SetLockedAttributeExt},
+        LockedStyleAttributes, UiStyleExt, UiStyleUncheckedExt,
+    },
+};
+
+const ELLIPSIS: char = '…';
+
+/// The node size attributes owned by truncation
+const SIZE_ATTRIBUTES: [LockableStyleAttribute; 4] = [
+    LockableStyleAttribute::Width,
+    LockableStyleAttribute::MinWidth,
+    LockableStyleAttribute::MaxWidth,
+    LockableStyleAttribute::FlexShrink,
+];
+
+pub struct TextOverflowPlugin;
+
+impl Plugin for TextOverflowPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            TextOverflowUpdate
+                .after(UiSystem::Layout)
+                .before(text_system),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                (lock_truncated_text_size, size_truncated_text)
+                    .chain()
+                    .after(measure_text_system)
+                    .before(UiSystem::Layout),
+                fit_truncated_text.in_set(TextOverflowUpdate),
+                measure_truncated_text.after(text_system),
+            ),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TextOverflowUpdate;
+
+/// How single line text is shortened when it doesn't fit its node
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TextOverflow {
+    /// Text is laid out in full and may overflow the node
+    #[default]
+    Visible,
+    /// Characters that don't fully fit are dropped
+    Clip,
+    /// The end is replaced with an ellipsis, i.e. "A long tit…"
+    Ellipsis,
+    /// The middle is replaced with an ellipsis, best for paths, i.e. "assets/…/icon.png"
+    EllipsisMiddle,
+}
+
+/// Truncates the text of the entity to the width of its node, after layout.
+///
+/// The full text is measured from its laid out glyphs once, when it is set. The node is sized
+/// to the full text, but may shrink down to its parent, so truncation follows resizes in the
+/// same frame. Added by the `text_overflow` style attribute, text set on the entity after that
+/// is picked up as the new full text.
+///
+/// The `width`, `min_width`, `max_width` and `flex_shrink` of the node are owned by truncation
+/// and locked while the component is present. Constrain the width of the parent instead.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct TruncatedText {
+    mode: TextOverflow,
+    full_text: Vec<String>,
+    shown_text: Vec<String>,
+    truncated: bool,
+    /// Attributes locked by truncation, unlocked when the full text is restored
+    locked_attributes: Vec<LockableStyleAttribute>,
+    #[reflect(ignore)]
+    measurement: Option<TextMeasurement>,
+}
+
+/// Layout of the full text, taken from its [`TextLayoutInfo`]
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TextMeasurement {
+    glyphs: Vec<MeasuredGlyph>,
+    size: Vec2,
+    ellipsis_width: f32,
+    scale_factor: f32,
+    fonts: Vec<(AssetId<Font>, f32)>,
+}
+
+/// A visible glyph of the full text, in logical pixels from the start of the text
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MeasuredGlyph {
+    section: usize,
+    start: usize,
+    end: usize,
+    left: f32,
+    right: f32,
+}
+
+impl TruncatedText {
+    pub fn new(mode: TextOverflow) -> Self {
+        Self { mode, ..default() }
+    }
+
+    pub fn mode(&self) -> TextOverflow {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: TextOverflow) {
+        self.mode = mode;
+    }
+
+    /// The text before truncation, all sections joined
+    pub fn full_text(&self) -> String {
+        self.full_text.concat()
+    }
+
+    /// The section values before truncation
+    pub fn full_sections(&self) -> &Vec<String> {
+        &self.full_text
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// The section values last written to the text
+    pub(crate) fn shown_sections(&self) -> &Vec<String> {
+        &self.shown_text
+    }
+
+    /// Picks up text set externally as the new full text, returns `true` if it changed
+    fn sync_full_text(&mut self, text: &Text) -> bool {
+        let changed = text.sections.len() != self.shown_text.len()
+            || text
+                .sections
+                .iter()
+                .zip(self.shown_text.iter())
+                .any(|(section, shown)| section.value != *shown);
+
+        if changed {
+            self.full_text = text
+                .sections
+                .iter()
+                .map(|section| section.value.clone())
+                .collect();
+            self.shown_text = self.full_text.clone();
+            self.measurement = None;
+        }
+
+        changed
+    }
+
+    /// Returns `true` if the measured layout no longer matches the fonts or scale of the text
+    fn is_measurement_stale(&self, text: &Text, scale_factor: f32) -> bool {
+        let Some(measurement) = &self.measurement else {
+            return false;
+        };
+
+        measurement.scale_factor != scale_factor
+            || measurement.fonts.len() != text.sections.len()
+            || text
+                .sections
+                .iter()
+                .zip(measurement.fonts.iter())
+                .any(|(section, (font, size))| {
+                    section.style.font.id() != *font || section.style.font_size != *size
+                })
+    }
+
+    /// Returns the section values fitting in `available` width, or `None` if all text fits.
+    /// With `keep_end` (right-to-left text) the visual end is kept and the start is cut instead.
+    fn fit(
+        &self,
+        measurement: &TextMeasurement,
+        available: f32,
+        keep_end: bool,
+    ) -> Option<Vec<String>> {
+        if measurement.size.x <= available || self.mode == TextOverflow::Visible {
+            return None;
+        }
+
+        let glyphs = &measurement.glyphs;
+        let full_width = measurement.size.x;
+        let budget = match self.mode {
+            TextOverflow::Clip => available,
+            _ => available - measurement.ellipsis_width,
+        };
+
+        let keep_end = keep_end && self.mode != TextOverflow::EllipsisMiddle;
+        let head_budget = match self.mode {
+            TextOverflow::EllipsisMiddle => budget / 2.,
+            _ => budget,
+        };
+        let head = match keep_end {
+            true => 0,
+            false => glyphs
+                .iter()
+                .take_while(|glyph| glyph.right <= head_budget)
+                .count(),
+        };
+        let head_width = head.checked_sub(1).map_or(0., |last| glyphs[last].right);
+
+        let mut tail = glyphs.len();
+        if self.mode == TextOverflow::EllipsisMiddle || keep_end {
+            tail -= glyphs[head..]
+                .iter()
+                .rev()
+                .take_while(|glyph| head_width + full_width - glyph.left <= budget)
+                .count();
+        }
+
+        // Whitespace next to the cut is dropped, as it has no glyph
+        let mut values = vec![String::new(); self.full_text.len()];
+        if let Some(last) = head.checked_sub(1).map(|last| glyphs[last]) {
+            for (index, value) in self.full_text[..last.section].iter().enumerate() {
+                values[index].push_str(value);
+            }
+            values[last.section].push_str(&self.full_text[last.section][..last.end]);
+        }
+
+        if self.mode != TextOverflow::Clip {
+            let section = match head.checked_sub(1) {
+                Some(last) => glyphs[last].section,
+                None => glyphs
+                    .get(tail)
+                    .or(glyphs.first())
+                    .map_or(0, |glyph| glyph.section),
+            };
+            values[section].push(ELLIPSIS);
+        }
+
+        if let Some(first) = glyphs.get(tail) {
+            values[first.section].push_str(&self.full_text[first.section][first.start..]);
+            for (index, value) in self.full_text.iter().enumerate().skip(first.section + 1) {
+                values[index].push_str(value);
+            }
+        }
+
+        values.into()
+    }
+}
+
+/// The scale factor text of the entity is laid out with, as in `text_system`
+fn text_scale_factor(
+    target_camera: Option<&TargetCamera>,
+    default_ui_camera: &DefaultUiCamera,
+    q_cameras: &Query<&Camera>,
+    ui_scale: &UiScale,
+) -> f32 {
+    target_camera
+        .map(TargetCamera::entity)
+        .or(default_ui_camera.get())
+        .and_then(|camera| q_cameras.get(camera).ok())
+        .and_then(Camera::target_scaling_factor)
+        .unwrap_or(1.)
+        * ui_scale.0
+}
+
+fn lock_truncated_text_size(
+    mut q_texts: Query<
+        (Entity, &mut TruncatedText, Option<&LockedStyleAttributes>),
+        Added<TruncatedText>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, mut truncated_text, locked_attributes) in &mut q_texts {
+        // The node takes the width of the full text from its content size, shrinking to fit
+        commands
+            .style_unchecked(entity)
+            .width(Val::Auto)
+            .min_width(Val::Px(0.))
+            .max_width(Val::Percent(100.))
+            .flex_shrink(1.);
+
+        let mut style = commands.style(entity);
+        let mut newly_locked = Vec::with_capacity(SIZE_ATTRIBUTES.len());
+        for attribute in SIZE_ATTRIBUTES {
+            if locked_attributes.is_some_and(|locked| locked.contains(attribute)) {
+                continue;
+            }
+
+            style.lock_attribute(attribute);
+            newly_locked.push(attribute);
+        }
+
+        truncated_text.bypass_change_detection().locked_attributes = newly_locked;
+    }
+}
+
+/// Keeps the content size of the node at the size of the full text, the text measure is
+/// replaced each time the truncated text is set.
+fn size_truncated_text(mut q_texts: Query<(Ref<TruncatedText>, &mut ContentSize)>) {
+    for (truncated_text, mut content_size) in &mut q_texts {
+        if !(content_size.is_changed() || truncated_text.is_changed()) {
+            continue;
+        }
+
+        let Some(measurement) = &truncated_text.measurement else {
+            continue;
+        };
+
+        content_size.set(NodeMeasure::Fixed(FixedMeasure {
+            size: measurement.size,
+        }));
+    }
+}
+
+fn fit_truncated_text(
+    mut q_texts: Query<(
+        Ref<Node>,
+        &mut Text,
+        &mut TruncatedText,
+        Option<Ref<BidiText>>,
+        Option<&TargetCamera>,
+    )>,
+    default_ui_camera: DefaultUiCamera,
+    q_cameras: Query<&Camera>,
+    ui_scale: Res<UiScale>,
+) {
+    for (node, mut text, mut truncated_text, bidi_text, target_camera) in &mut q_texts {
+        if !(node.is_changed()
+            || text.is_changed()
+            || truncated_text.is_changed()
+            || bidi_text
+                .as_ref()
+                .is_some_and(|bidi_text| bidi_text.is_changed()))
+        {
+            continue;
+        }
+
+        if truncated_text
+            .bypass_change_detection()
+            .sync_full_text(&text)
+        {
+            truncated_text.set_changed();
+        }
+
+        let scale_factor =
+            text_scale_factor(target_camera, &default_ui_camera, &q_cameras, &ui_scale);
+        let stale = truncated_text.is_measurement_stale(&text, scale_factor);
+        let values = match (&truncated_text.measurement, node.unrounded_size().x) {
+            // The full text is shown until it is measured
+            (None, _) => None,
+            (Some(_), _) if stale => None,
+            (Some(_), available) if available <= 0. => continue,
+            (Some(measurement), available) => {
+                let keep_end = bidi_text.is_some_and(|bidi_text| bidi_text.is_rtl());
+                truncated_text.fit(measurement, available.ceil(), keep_end)
+            }
+        };
+        let truncated = values.is_some();
+        let values = values.unwrap_or_else(|| truncated_text.full_text.clone());
+
+        let changed = text
+            .sections
+            .iter()
+            .zip(values.iter())
+            .any(|(section, value)| section.value != *value);
+        if changed {
+            for (section, value) in text.sections.iter_mut().zip(values.iter()) {
+                section.value = value.clone();
+            }
+        }
+
+        if stale {
+            truncated_text.measurement = None;
+        }
+        if truncated_text.shown_text != values {
+            truncated_text.bypass_change_detection().shown_text = values;
+        }
+        // Unmeasured text keeps its state until it is fitted again
+        if truncated_text.measurement.is_some() && truncated_text.truncated != truncated {
+            truncated_text.truncated = truncated;
+        }
+    }
+}
+
+/// Measures the full text from its layout. Until it is truncated in the next frame, glyphs
+/// that overflow the node are dropped.
+fn measure_truncated_text(
+    mut q_texts: Query<(
+        &Node,
+        &Text,
+        &mut TruncatedText,
+        &mut TextLayoutInfo,
+        Option<&TargetCamera>,
+    )>,
+    default_ui_camera: DefaultUiCamera,
+    q_cameras: Query<&Camera>,
+    ui_scale: Res<UiScale>,
+    fonts: Res<Assets<Font>>,
+) {
+    for (node, text, mut truncated_text, mut layout_info, target_camera) in &mut q_texts {
+        if truncated_text.measurement.is_some()
+            || !(layout_info.is_changed() || truncated_text.is_added())
+        {
+            continue;
+        }
+
+        // Fonts are not loaded yet
+        if layout_info.glyphs.is_empty() && !truncated_text.full_text().trim().is_empty() {
+            continue;
+        }
+
+        let laid_out_in_full = text.sections.len() == truncated_text.full_text.len()
+            && text
+                .sections
+                .iter()
+                .zip(truncated_text.full_text.iter())
+                .all(|(section, value)| section.value == *value);
+        if !laid_out_in_full {
+            continue;
+        }
+
+        let scale_factor =
+            text_scale_factor(target_camera, &default_ui_camera, &q_cameras, &ui_scale);
+        let inverse_scale_factor = scale_factor.recip();
+
+        // Glyph positions are the physical centers of the glyphs, offset by their padding
+        let mut glyphs: Vec<MeasuredGlyph> = layout_info
+            .glyphs
+            .iter()
+            .filter_map(|glyph| {
+                let value = truncated_text.full_text.get(glyph.section_index)?;
+                let character = value[glyph.byte_index..].chars().next()?;
+                let center = (glyph.position.x + 1.) * inverse_scale_factor;
+                let half_width = glyph.size.x / 2. * inverse_scale_factor;
+
+                Some(MeasuredGlyph {
+                    section: glyph.section_index,
+                    start: glyph.byte_index,
+                    end: glyph.byte_index + character.len_utf8(),
+                    left: center - half_width,
+                    right: center + half_width,
+                })
+            })
+            .collect();
+        glyphs.sort_by_key(|glyph| (glyph.section, glyph.start));
+
+        // Justified text may not start at zero
+        let origin = glyphs.first().map_or(0., |glyph| glyph.left.min(0.));
+        for glyph in glyphs.iter_mut() {
+            glyph.left -= origin;
+            glyph.right -= origin;
+        }
+
+        let ellipsis_width = text
+            .sections
+            .iter()
+            .filter_map(|section| {
+                let ellipsis = Text::from_section(ELLIPSIS, section.style.clone());
+                TextMeasureInfo::from_text(&ellipsis, &fonts, scale_factor).ok()
+            })
+            .map(|measure| measure.max.x * inverse_scale_factor)
+            .fold(0., f32::max);
+
+        let available = node.unrounded_size().x;
+        if available > 0. && layout_info.logical_size.x > available {
+            let overflowing: Vec<(usize, usize)> = glyphs
+                .iter()
+                .filter(|glyph| glyph.right > available)
+                .map(|glyph| (glyph.section, glyph.start))
+                .collect();
+            layout_info
+                .bypass_change_detection()
+                .glyphs
+                .retain(|glyph| !overflowing.contains(&(glyph.section_index, glyph.byte_index)));
+        }
+
+        truncated_text.measurement = Some(TextMeasurement {
+            glyphs,
+            size: layout_info.logical_size,
+            ellipsis_width,
+            scale_factor,
+            fonts: text
+                .sections
+                .iter()
+                .map(|section| (section.style.font.id(), section.style.font_size))
+                .collect(),
+        });
+    }
+}
+
+/// Restores the full text and the node size of an entity with a [`TruncatedText`]
+pub(crate) fn restore_full_text(entity: Entity, world: &mut World) {
+    let Some(truncated_text) = world.entity_mut(entity).take::<TruncatedText>() else {
+        return;
+    };
+
+    if let Some(mut text) = world.get_mut::<Text>(entity) {
+        for (section, value) in text.sections.iter_mut().zip(truncated_text.full_text) {
+            section.value = value;
+        }
+    }
+
+    if let Some(mut style) = world.get_mut::<Style>(entity) {
+        style.width = Val::Auto;
+        style.min_width = Val::Auto;
+        style.max_width = Val::Auto;
+    }
+
+    for attribute in truncated_text.locked_attributes {
+        SetLockedAttribute {
+            attribute,
+            locked: false,
+        }
+        .apply(entity, world);
+    }
+}
@@ -3,6 +3,7 @@ pub mod dynamic_style_attribute;
 pub mod icons;
 pub mod pseudo_state;
 pub mod style_animation;
+pub mod theme_cache;
 pub mod theme_colors;
 pub mod theme_data;
 pub mod theme_spacing;
@@ -11,12 +12,16 @@ pub mod typography;
 use std::{
     any::{type_name, TypeId},
     collections::HashSet,
+    fmt::{self, Debug, Formatter},
     marker::PhantomData,
+    sync::Arc,
 };
 
 use bevy::{prelude::*, ui::UiSystem};
 use dynamic_style::{DynamicStyle, DynamicStylePlugin};
+use icons::{loader::IconSetLoader, IconSet};
 use pseudo_state::{AutoPseudoStatePlugin, PseudoState, PseudoStates};
+use theme_cache::ThemeCache;
 use theme_colors::{loader::ThemeColorsLoader, ThemeColors};
 use theme_data::ThemeData;
 
@@ -28,17 +33,18 @@ pub mod prelude {
             ContextStyleAttribute, DynamicStyle, DynamicStyleEnterState, DynamicStylePostUpdate,
         },
         dynamic_style_attribute::{DynamicStyleAttribute, DynamicStyleController},
-        icons::IconData,
+        icons::{IconData, IconSet},
         pseudo_state::{
             FlexDirectionToPseudoState, HierarchyToPseudoState, PseudoState, PseudoStates,
             VisibilityToPseudoState,
         },
         style_animation::{
-            AnimationLoop, AnimationSettings, AnimationState, InteractionStyle,
+            AnimationConfig, AnimationLoop, AnimationSettings, AnimationState, InteractionStyle,
             LoopedAnimationConfig,
         },
-        theme_colors::{Accent, Container, OnColor, Surface},
-        theme_data::{Contrast, Scheme, ThemeData},
+        theme_cache::ThemeCache,
+        theme_colors::{Accent, Container, OnColor, Role, Surface},
+        theme_data::{Contrast, LayoutDirection, Scheme, ThemeData, UiDensity},
         typography::{FontScale, FontStyle, FontType, SizedFont},
         ComponentThemePlugin, CustomThemeUpdate, DefaultTheme, DynamicStyleBuilder, PseudoTheme,
         Theme, ThemeUpdate, UiContext,
@@ -55,8 +61,11 @@ impl Plugin for ThemePlugin {
         )
         .init_resource::<ThemeData>()
         .init_resource::<ThemeRegistry>()
+        .init_resource::<ThemeCache>()
         .init_asset::<ThemeColors>()
         .init_asset_loader::<ThemeColorsLoader>()
+        .init_asset::<IconSet>()
+        .init_asset_loader::<IconSetLoader>()
         .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin));
     }
 }
@@ -67,7 +76,21 @@ pub struct ThemeUpdate;
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct CustomThemeUpdate;
 
-#[derive(Clone, Debug)]
+pub type CustomStyleBuilder = Arc<dyn Fn(&mut StyleBuilder, &ThemeData) + Send + Sync>;
+pub type CustomContextStyleBuilder<C> =
+    Arc<dyn Fn(&mut StyleBuilder, &C, &ThemeData) + Send + Sync>;
+pub type CustomWorldStyleBuilder<C> =
+    Arc<dyn Fn(&mut StyleBuilder, Entity, &C, &World) + Send + Sync>;
+pub type CustomInfoWorldStyleBuilder<C> = Arc<
+    dyn Fn(&mut StyleBuilder, Option<Entity>, &Option<Vec<PseudoState>>, Entity, &C, &World)
+        + Send
+        + Sync,
+>;
+
+/// The `Custom*` variants are the closure counterparts of the `fn` builders,
+/// for themes that capture runtime state. They are never cached, so captured state
+/// read at call time is picked up on each refresh.
+#[derive(Clone)]
 pub enum DynamicStyleBuilder<C> {
     Static(DynamicStyle),
     StyleBuilder(fn(&mut StyleBuilder, &ThemeData)),
@@ -76,6 +99,37 @@ pub enum DynamicStyleBuilder<C> {
     InfoWorldStyleBuilder(
         fn(&mut StyleBuilder, Option<Entity>, &Option<Vec<PseudoState>>, Entity, &C, &World),
     ),
+    CustomStyleBuilder(CustomStyleBuilder),
+    CustomContextStyleBuilder(CustomContextStyleBuilder<C>),
+    CustomWorldStyleBuilder(CustomWorldStyleBuilder<C>),
+    CustomInfoWorldStyleBuilder(CustomInfoWorldStyleBuilder<C>),
+}
+
+impl<C> Debug for DynamicStyleBuilder<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static(style) => f.debug_tuple("Static").field(style).finish(),
+            Self::StyleBuilder(builder) => f.debug_tuple("StyleBuilder").field(builder).finish(),
+            Self::ContextStyleBuilder(builder) => {
+                f.debug_tuple("ContextStyleBuilder").field(builder).finish()
+            }
+            Self::WorldStyleBuilder(builder) => {
+                f.debug_tuple("WorldStyleBuilder").field(builder).finish()
+            }
+            Self::InfoWorldStyleBuilder(builder) => f
+                .debug_tuple("InfoWorldStyleBuilder")
+                .field(builder)
+                .finish(),
+            Self::CustomStyleBuilder(_) => f.debug_tuple("CustomStyleBuilder").finish(),
+            Self::CustomContextStyleBuilder(_) => {
+                f.debug_tuple("CustomContextStyleBuilder").finish()
+            }
+            Self::CustomWorldStyleBuilder(_) => f.debug_tuple("CustomWorldStyleBuilder").finish(),
+            Self::CustomInfoWorldStyleBuilder(_) => {
+                f.debug_tuple("CustomInfoWorldStyleBuilder").finish()
+            }
+        }
+    }
 }
 
 impl<C> From<StyleBuilder> for DynamicStyleBuilder<C> {
@@ -175,6 +229,60 @@ impl<C> PseudoTheme<C> {
         }
     }
 
+    /// Same as [`PseudoTheme::deferred`], with a closure that can capture state.
+    ///
+    /// ```ignore
+    /// let accent = document.accent_color;
+    /// PseudoTheme::custom(None, move |style_builder, _| {
+    ///     style_builder.background_color(accent);
+    /// });
+    /// ```
+    pub fn custom(
+        state: impl Into<Option<Vec<PseudoState>>>,
+        builder: impl Fn(&mut StyleBuilder, &ThemeData) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            state: state.into(),
+            builder: DynamicStyleBuilder::CustomStyleBuilder(Arc::new(builder)),
+        }
+    }
+
+    /// Same as [`PseudoTheme::deferred_context`], with a closure that can capture state.
+    pub fn custom_context(
+        state: impl Into<Option<Vec<PseudoState>>>,
+        builder: impl Fn(&mut StyleBuilder, &C, &ThemeData) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            state: state.into(),
+            builder: DynamicStyleBuilder::CustomContextStyleBuilder(Arc::new(builder)),
+        }
+    }
+
+    /// Same as [`PseudoTheme::deferred_world`], with a closure that can capture state.
+    pub fn custom_world(
+        state: impl Into<Option<Vec<PseudoState>>>,
+        builder: impl Fn(&mut StyleBuilder, Entity, &C, &World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            state: state.into(),
+            builder: DynamicStyleBuilder::CustomWorldStyleBuilder(Arc::new(builder)),
+        }
+    }
+
+    /// Same as [`PseudoTheme::deferred_info_world`], with a closure that can capture state.
+    pub fn custom_info_world<F>(state: impl Into<Option<Vec<PseudoState>>>, builder: F) -> Self
+    where
+        F: Fn(&mut StyleBuilder, Option<Entity>, &Option<Vec<PseudoState>>, Entity, &C, &World)
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            state: state.into(),
+            builder: DynamicStyleBuilder::CustomInfoWorldStyleBuilder(Arc::new(builder)),
+        }
+    }
+
     pub fn is_base_theme(&self) -> bool {
         match &self.state {
             Some(list) => list.is_empty(),
@@ -278,12 +386,15 @@ where
         q_removed_themes: RemovedComponents<Theme<C>>,
         q_changed_themes: Query<Entity, Changed<Theme<C>>>,
         theme_data: Res<ThemeData>,
+        mut theme_cache: ResMut<ThemeCache>,
         mut commands: Commands,
     ) {
-        if theme_data.is_changed()
-            || q_removed_themes.len() > 0
-            || q_changed_themes.iter().count() > 0
-        {
+        let themes_changed = q_removed_themes.len() > 0 || q_changed_themes.iter().count() > 0;
+        if themes_changed {
+            theme_cache.invalidate::<C>();
+        }
+
+        if theme_data.is_changed() || themes_changed {
             for entity in &q_targets {
                 commands.entity(entity).refresh_theme::<C>();
             }
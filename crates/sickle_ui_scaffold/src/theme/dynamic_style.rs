@@ -72,7 +72,7 @@ fn update_dynamic_style_on_flux_change(
     mut q_styles: Query<
         (
             Entity,
-            Ref<DynamicStyle>,
+            &mut DynamicStyle,
             &FluxInteraction,
             Option<&mut DynamicStyleStopwatch>,
         ),
@@ -80,8 +80,11 @@ fn update_dynamic_style_on_flux_change(
     >,
     mut commands: Commands,
 ) {
-    for (entity, style, interaction, stopwatch) in &mut q_styles {
+    for (entity, mut style, interaction, stopwatch) in &mut q_styles {
+        let style_changed = style.is_changed();
+        let style = style.bypass_change_detection();
         let mut lock_needed = StopwatchLock::None;
+        let mut transition_lock = StopwatchLock::None;
         let mut keep_stop_watch = false;
 
         for context_attribute in &style.attributes {
@@ -107,20 +110,46 @@ fn update_dynamic_style_on_flux_change(
                     if animation_lock > lock_needed {
                         lock_needed = animation_lock;
                     }
+
+                    let remaining_transition = controller.transition_lock_duration();
+                    if remaining_transition > transition_lock {
+                        transition_lock = remaining_transition;
+                    }
                 }
                 _ => continue,
             }
         }
 
-        if let Some(mut stopwatch) = stopwatch {
-            if !keep_stop_watch || style.is_changed() {
-                stopwatch.0.reset();
+        let restart_stopwatch = match stopwatch {
+            Some(mut stopwatch) => {
+                let restart = !keep_stop_watch || style_changed;
+                if restart {
+                    stopwatch.0.reset();
+                } else if transition_lock != StopwatchLock::None {
+                    // Transitions are tracked from the last restart
+                    transition_lock =
+                        StopwatchLock::Duration(stopwatch.0.elapsed()) + transition_lock;
+                }
+                stopwatch.1 = lock_needed.max(transition_lock);
+
+                restart
+            }
+            None => {
+                commands.entity(entity).insert(DynamicStyleStopwatch(
+                    Stopwatch::new(),
+                    lock_needed.max(transition_lock),
+                ));
+
+                true
+            }
+        };
+
+        if restart_stopwatch {
+            for context_attribute in &mut style.attributes {
+                if let Ok(controller) = context_attribute.attribute.controller_mut() {
+                    controller.rebase_transition();
+                }
             }
-            stopwatch.1 = lock_needed;
-        } else {
-            commands
-                .entity(entity)
-                .insert(DynamicStyleStopwatch(Stopwatch::new(), lock_needed));
         }
     }
 }
@@ -183,13 +212,17 @@ fn update_dynamic_style_on_stopwatch_change(
                     };
 
                     par_commands.command_scope(|mut commands| {
-                        attribute.apply(controller.current_state(), &mut commands.style(target));
+                        attribute.apply_transitioned(
+                            controller.current_state(),
+                            controller.transition(),
+                            &mut commands.style(target),
+                        );
                     });
                 }
 
                 if controller.entering() {
                     enter_completed = false;
-                } else if controller.animation.delete_on_entered {
+                } else if controller.animation.delete_on_entered && !controller.transitioning() {
                     filter_entered = true;
                 }
             }
@@ -214,7 +247,9 @@ fn update_dynamic_style_on_stopwatch_change(
                         return true;
                     };
 
-                    !(controller.animation.delete_on_entered && !controller.entering())
+                    !(controller.animation.delete_on_entered
+                        && !controller.entering()
+                        && !controller.transitioning())
                 });
 
                 if style.attributes.len() == 0 {
@@ -277,6 +312,14 @@ impl ContextStyleAttribute {
             attribute,
         }
     }
+
+    pub(crate) fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    pub(crate) fn attribute(&self) -> &DynamicStyleAttribute {
+        &self.attribute
+    }
 }
 
 // TODO: Consider moving to sparse set. Static styles are removed in
@@ -310,6 +353,10 @@ impl DynamicStyle {
         }
     }
 
+    pub(crate) fn attributes(&self) -> &Vec<ContextStyleAttribute> {
+        &self.attributes
+    }
+
     pub fn merge(mut self, mut other: DynamicStyle) -> Self {
         self.merge_in_place(&mut other);
         self
@@ -375,8 +422,20 @@ impl DynamicStyle {
                 continue;
             };
 
-            if attribute == old_attribute && controller.animation == old_controller.animation {
+            let same_animation = controller.animation == old_controller.animation;
+            if attribute == old_attribute && same_animation {
                 controller.copy_state_from(old_controller);
+            } else if controller.animation.transition.is_some() {
+                // Keep the interaction state, only the values change
+                if same_animation {
+                    controller.copy_state_from(old_controller);
+                }
+
+                if let Some(from) = old_attribute
+                    .snapshot(old_controller.current_state(), old_controller.transition())
+                {
+                    controller.start_transition(from);
+                }
             }
         }
     }
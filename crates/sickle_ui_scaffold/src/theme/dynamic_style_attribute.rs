@@ -1,7 +1,10 @@
+use std::time::Duration;
+
 use bevy::utils::default;
+use sickle_math::ease::ValueEasing;
 
 use crate::{
-    flux_interaction::FluxInteraction,
+    flux_interaction::{FluxInteraction, StopwatchLock},
     ui_style::{
         generated::{AnimatedStyleAttribute, InteractiveStyleAttribute, StaticStyleAttribute},
         LogicalEq,
@@ -10,6 +13,8 @@ use crate::{
 
 use super::style_animation::{AnimationSettings, AnimationState, InteractionStyle};
 
+// Boxing the animated variant would allocate for each attribute on every re-theme
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
 pub enum DynamicStyleAttribute {
     // Remove on apply
@@ -115,10 +120,20 @@ impl DynamicStyleAttribute {
     }
 }
 
+/// The value an attribute tweens away from after a re-theme changed it.
+#[derive(Clone, Debug, PartialEq)]
+struct StyleTransition {
+    from: AnimatedStyleAttribute,
+    elapsed: f32,
+    last_tick: f32,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DynamicStyleController {
     pub animation: AnimationSettings,
     current_state: AnimationState,
+    // Boxed as transitions are rare and every animated attribute has a controller
+    transition: Option<Box<StyleTransition>>,
     dirty: bool,
     entering: bool,
 }
@@ -128,6 +143,7 @@ impl Default for DynamicStyleController {
         Self {
             animation: Default::default(),
             current_state: Default::default(),
+            transition: None,
             dirty: Default::default(),
             entering: true,
         }
@@ -144,6 +160,8 @@ impl DynamicStyleController {
     }
 
     pub fn update(&mut self, flux_interaction: &FluxInteraction, mut elapsed: f32) {
+        self.tick_transition(elapsed);
+
         // TODO: `enter` animation is currently played when a style animation different from
         // the previous one is requested. This means that playing the enter animation is *contextual*
         // and cannot be directly controlled by the developer. Figure out a way to factor out these
@@ -217,7 +235,80 @@ impl DynamicStyleController {
 
     pub fn copy_state_from(&mut self, other: &DynamicStyleController) {
         self.current_state = other.current_state().clone();
+        self.transition = other.transition.clone();
         self.entering = other.entering;
         self.dirty = other.dirty;
     }
+
+    /// Starts tweening from `from` if the animation has a `transition` configured.
+    ///
+    /// `from` is expected to be a [`AnimatedStyleAttribute::snapshot`] of the replaced attribute.
+    pub fn start_transition(&mut self, from: AnimatedStyleAttribute) {
+        if self.animation.transition.is_none() {
+            return;
+        }
+
+        self.transition = Some(Box::new(StyleTransition {
+            from,
+            elapsed: 0.,
+            last_tick: 0.,
+        }));
+        self.dirty = true;
+    }
+
+    /// The transition source and the eased ratio of the transition, if one is in progress.
+    pub fn transition(&self) -> Option<(&AnimatedStyleAttribute, f32)> {
+        let (Some(config), Some(transition)) = (self.animation.transition, &self.transition) else {
+            return None;
+        };
+
+        let ratio = match config.duration > 0. {
+            true => ((transition.elapsed - config.delay()) / config.duration).clamp(0., 1.),
+            false => 0.,
+        };
+
+        Some((&transition.from, ratio.ease(config.easing())))
+    }
+
+    pub fn transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// The time remaining from the transition, measured from the last stopwatch reset.
+    pub fn transition_lock_duration(&self) -> StopwatchLock {
+        let (Some(config), Some(transition)) = (self.animation.transition, &self.transition) else {
+            return StopwatchLock::None;
+        };
+
+        let remaining = config.delay() + config.duration - transition.elapsed;
+        StopwatchLock::Duration(Duration::from_secs_f32(remaining.max(0.)))
+    }
+
+    /// Keeps the transition progress when the style stopwatch is restarted, e.g. on
+    /// interaction changes.
+    pub(crate) fn rebase_transition(&mut self) {
+        if let Some(transition) = &mut self.transition {
+            transition.last_tick = 0.;
+        }
+    }
+
+    fn tick_transition(&mut self, elapsed: f32) {
+        let Some(config) = self.animation.transition else {
+            self.transition = None;
+            return;
+        };
+        let Some(transition) = &mut self.transition else {
+            return;
+        };
+
+        transition.elapsed += (elapsed - transition.last_tick).max(0.);
+        transition.last_tick = elapsed;
+
+        if transition.elapsed >= config.delay() + config.duration {
+            self.transition = None;
+        }
+
+        // The blended value changes even if the animation state does not
+        self.dirty = true;
+    }
 }
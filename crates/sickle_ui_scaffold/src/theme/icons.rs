@@ -1,29 +1,41 @@
 use std::char;
 
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
 
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
 pub enum IconData {
     #[default]
     None,
     Image(String, Color),
     FontCodepoint(String, char, Color, f32),
-    // TODO: add texture atlas config
+    /// Image path, atlas layout, index and tint
+    AtlasImage(String, Handle<TextureAtlasLayout>, usize, Color),
+    /// An icon looked up by name in the [`Icons`] of the theme, with optional color and size
+    /// overrides
+    Named(String, Option<Color>, Option<f32>),
 }
 
 impl IconData {
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::Named(name.into(), None, None)
+    }
+
     pub fn is_none(&self) -> bool {
         matches!(self, Self::None)
     }
 
     pub fn is_image(&self) -> bool {
-        matches!(self, Self::Image(_, _))
+        matches!(self, Self::Image(_, _) | Self::AtlasImage(_, _, _, _))
     }
 
     pub fn is_codepoint(&self) -> bool {
         matches!(self, Self::FontCodepoint(_, _, _, _))
     }
 
+    pub fn is_named(&self) -> bool {
+        matches!(self, Self::Named(_, _, _))
+    }
+
     pub fn with_color(&self, color: Color) -> Self {
         match self {
             IconData::None => IconData::None,
@@ -31,6 +43,10 @@ impl IconData {
             IconData::FontCodepoint(path, codepoint, _, size) => {
                 Self::FontCodepoint(path.clone(), codepoint.clone(), color, size.clone())
             }
+            IconData::AtlasImage(path, layout, index, _) => {
+                Self::AtlasImage(path.clone(), layout.clone(), *index, color)
+            }
+            IconData::Named(name, _, size) => Self::Named(name.clone(), color.into(), *size),
         }
     }
 
@@ -41,6 +57,8 @@ impl IconData {
             IconData::FontCodepoint(path, codepoint, color, _) => {
                 Self::FontCodepoint(path.clone(), codepoint.clone(), color.clone(), size)
             }
+            IconData::AtlasImage(_, _, _, _) => self.clone(),
+            IconData::Named(name, color, _) => Self::Named(name.clone(), *color, size.into()),
         }
     }
 
@@ -51,6 +69,10 @@ impl IconData {
             IconData::FontCodepoint(path, codepoint, _, _) => {
                 Self::FontCodepoint(path.clone(), codepoint.clone(), color, size)
             }
+            IconData::AtlasImage(path, layout, index, _) => {
+                Self::AtlasImage(path.clone(), layout.clone(), *index, color)
+            }
+            IconData::Named(name, _, _) => Self::Named(name.clone(), color.into(), size.into()),
         }
     }
 }
@@ -63,6 +85,7 @@ pub struct CustomIconData {
 
 #[derive(Clone, Debug, Reflect)]
 pub struct Icons {
+    pub arrow_left: IconData,
     pub arrow_right: IconData,
     pub checkmark: IconData,
     pub chevron_left: IconData,
@@ -71,6 +94,7 @@ pub struct Icons {
     pub exit_to_app: IconData,
     pub expand_less: IconData,
     pub expand_more: IconData,
+    pub indeterminate: IconData,
     pub open_in_new: IconData,
     pub radio_button_checked: IconData,
     pub radio_button_unchecked: IconData,
@@ -78,12 +102,282 @@ pub struct Icons {
     pub submenu: IconData,
     pub undo: IconData,
     pub custom: Vec<CustomIconData>,
+    /// Icon sets searched by name after the named and custom icons, in order
+    pub sets: Vec<IconSet>,
+}
+
+impl Icons {
+    /// Returns the icon called `name`, `IconData::None` if no icon has the name.
+    ///
+    /// Looks up the named fields (i.e. `"arrow_right"`), then `custom`, then the `sets`.
+    pub fn get(&self, name: &str) -> IconData {
+        let icon = match name {
+            "arrow_left" => &self.arrow_left,
+            "arrow_right" => &self.arrow_right,
+            "checkmark" => &self.checkmark,
+            "chevron_left" => &self.chevron_left,
+            "chevron_right" => &self.chevron_right,
+            "close" => &self.close,
+            "exit_to_app" => &self.exit_to_app,
+            "expand_less" => &self.expand_less,
+            "expand_more" => &self.expand_more,
+            "indeterminate" => &self.indeterminate,
+            "open_in_new" => &self.open_in_new,
+            "radio_button_checked" => &self.radio_button_checked,
+            "radio_button_unchecked" => &self.radio_button_unchecked,
+            "redo" => &self.redo,
+            "submenu" => &self.submenu,
+            "undo" => &self.undo,
+            _ => {
+                if let Some(custom) = self.custom.iter().find(|custom| custom.name == name) {
+                    return custom.data.clone();
+                }
+
+                return self
+                    .sets
+                    .iter()
+                    .find_map(|set| set.get(name))
+                    .unwrap_or_default();
+            }
+        };
+
+        icon.clone()
+    }
+
+    /// Resolves `IconData::Named` icons, applying their color and size overrides.
+    /// Other icons are returned as is.
+    pub fn resolve(&self, icon: &IconData) -> IconData {
+        let IconData::Named(name, color, size) = icon else {
+            return icon.clone();
+        };
+
+        let mut resolved = self.get(name);
+        if let Some(color) = color {
+            resolved = resolved.with_color(*color);
+        }
+        if let Some(size) = size {
+            resolved = resolved.with_size(*size);
+        }
+
+        resolved
+    }
+
+    /// Returns the horizontally mirrored counterpart of `icon`, i.e. `arrow_left` for
+    /// `arrow_right`. Icons without a counterpart are returned as is.
+    pub fn mirrored(&self, icon: &IconData) -> IconData {
+        const PAIRS: [(&str, &str); 2] = [
+            ("arrow_left", "arrow_right"),
+            ("chevron_left", "chevron_right"),
+        ];
+
+        if let IconData::Named(name, color, size) = icon {
+            let mirrored = PAIRS.iter().find_map(|(left, right)| match name.as_str() {
+                name if name == *left => Some(*right),
+                name if name == *right => Some(*left),
+                _ => None,
+            });
+
+            return match mirrored {
+                Some(name) => IconData::Named(name.into(), *color, *size),
+                None => icon.clone(),
+            };
+        }
+
+        if icon.is_none() {
+            return IconData::None;
+        }
+
+        PAIRS
+            .iter()
+            .find_map(|(left, right)| {
+                let (left, right) = (self.get(left), self.get(right));
+                if *icon == left {
+                    Some(right)
+                } else if *icon == right {
+                    Some(left)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| icon.clone())
+    }
+
+    pub fn add_set(&mut self, set: IconSet) {
+        self.sets.push(set);
+    }
+}
+
+/// Where the icons of an [`IconSet`] are drawn from
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum IconSetSource {
+    /// An icon font, icons are codepoints
+    Font(String),
+    /// A texture atlas image and its layout, icons are atlas indices
+    Atlas(String, Handle<TextureAtlasLayout>),
+}
+
+impl Default for IconSetSource {
+    fn default() -> Self {
+        Self::Font("embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into())
+    }
+}
+
+/// Maps icon names to the codepoints of an icon font or the regions of a texture atlas.
+///
+/// Icon font sets can be loaded from a Material `codepoints` file (`name hex_codepoint` per
+/// line). The font defaults to the `.ttf` next to it, see [`IconSetLoaderSettings`].
+/// Add loaded sets to the theme with `theme_data.icons.add_set(icon_set.clone())`.
+#[derive(Asset, Clone, Debug, Reflect)]
+pub struct IconSet {
+    pub source: IconSetSource,
+    pub icons: HashMap<String, u32>,
+    /// The color of font icons and tint of atlas icons
+    pub color: Color,
+    /// The font size of font icons
+    pub size: f32,
+}
+
+impl Default for IconSet {
+    fn default() -> Self {
+        Self {
+            source: Default::default(),
+            icons: Default::default(),
+            color: Color::WHITE,
+            size: 12.,
+        }
+    }
+}
+
+impl IconSet {
+    pub fn font(
+        path: impl Into<String>,
+        codepoints: impl IntoIterator<Item = (String, char)>,
+    ) -> Self {
+        Self {
+            source: IconSetSource::Font(path.into()),
+            icons: codepoints
+                .into_iter()
+                .map(|(name, codepoint)| (name, codepoint as u32))
+                .collect(),
+            ..default()
+        }
+    }
+
+    pub fn atlas(
+        path: impl Into<String>,
+        layout: Handle<TextureAtlasLayout>,
+        indices: impl IntoIterator<Item = (String, usize)>,
+    ) -> Self {
+        Self {
+            source: IconSetSource::Atlas(path.into(), layout),
+            icons: indices
+                .into_iter()
+                .map(|(name, index)| (name, index as u32))
+                .collect(),
+            ..default()
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<IconData> {
+        let value = *self.icons.get(name)?;
+        match &self.source {
+            IconSetSource::Font(path) => Some(IconData::FontCodepoint(
+                path.clone(),
+                char::from_u32(value)?,
+                self.color,
+                self.size,
+            )),
+            IconSetSource::Atlas(path, layout) => Some(IconData::AtlasImage(
+                path.clone(),
+                layout.clone(),
+                value as usize,
+                self.color,
+            )),
+        }
+    }
 }
 
-// TODO: create codepoint parser?
+pub mod loader {
+    use std::io::{Error, ErrorKind};
+
+    use bevy::{
+        asset::{io::Reader, AssetLoader, AssetPath, AsyncReadExt, LoadContext},
+        prelude::default,
+        utils::HashMap,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use super::{IconSet, IconSetSource};
+
+    #[derive(Default, Serialize, Deserialize)]
+    pub struct IconSetLoaderSettings {
+        /// Path of the icon font, the `.ttf` next to the codepoints file if `None`
+        pub font: Option<String>,
+    }
+
+    /// Loads [`IconSet`]s from Material `codepoints` files
+    #[derive(Default)]
+    pub(crate) struct IconSetLoader;
+
+    impl AssetLoader for IconSetLoader {
+        type Asset = IconSet;
+        type Settings = IconSetLoaderSettings;
+        type Error = Error;
+
+        async fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader<'_>,
+            settings: &'a Self::Settings,
+            load_context: &'a mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut codepoints = String::new();
+            reader.read_to_string(&mut codepoints).await?;
+
+            let mut icons = HashMap::new();
+            for line in codepoints.lines().filter(|line| !line.trim().is_empty()) {
+                let Some((name, codepoint)) = line.split_once(char::is_whitespace) else {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Invalid codepoint line: {}", line),
+                    ));
+                };
+                let codepoint = u32::from_str_radix(codepoint.trim(), 16)
+                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+
+                icons.insert(name.to_string(), codepoint);
+            }
+
+            let font = match &settings.font {
+                Some(font) => font.clone(),
+                None => {
+                    let asset_path = load_context.asset_path();
+                    AssetPath::from_path(&asset_path.path().with_extension("ttf"))
+                        .with_source(asset_path.source().clone_owned())
+                        .to_string()
+                }
+            };
+
+            Ok(IconSet {
+                source: IconSetSource::Font(font),
+                icons,
+                ..default()
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["codepoints"]
+        }
+    }
+}
 impl Default for Icons {
     fn default() -> Self {
         Self {
+            arrow_left: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E5DE}',
+                Color::WHITE,
+                12.,
+            ),
             arrow_right: IconData::FontCodepoint(
                 "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
                 '\u{E5DF}',
@@ -132,6 +426,12 @@ impl Default for Icons {
                 Color::WHITE,
                 12.,
             ),
+            indeterminate: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E15B}',
+                Color::WHITE,
+                12.,
+            ),
             open_in_new: IconData::FontCodepoint(
                 "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
                 '\u{E89E}',
@@ -154,6 +454,7 @@ impl Default for Icons {
             submenu: IconData::Image("".into(), Color::WHITE),
             undo: IconData::Image("".into(), Color::WHITE),
             custom: Vec::new(),
+            sets: Vec::new(),
         }
     }
 }
@@ -210,7 +210,7 @@ impl<C: Component> HierarchyToPseudoState<C> {
 }
 
 #[derive(
-    Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+    Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect, Serialize, Deserialize,
 )]
 pub enum PseudoState {
     #[default]
@@ -219,6 +219,7 @@ pub enum PseudoState {
     Visible,
     Selected,
     Checked,
+    Indeterminate,
     Empty,
     SingleChild,
     FirstChild,
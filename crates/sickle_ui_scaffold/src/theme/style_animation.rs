@@ -242,6 +242,12 @@ pub struct AnimationSettings {
     pub hover: Option<LoopedAnimationConfig>,
     #[reflect(default)]
     pub pressed: Option<LoopedAnimationConfig>,
+    /// Tweens from the previously shown value when a re-theme changes the attribute,
+    /// e.g. on a pseudo state change. Static attributes are transitioned by adding them
+    /// after
+    /// [`StyleBuilder::switch_transition`](crate::ui_style::builder::StyleBuilder::switch_transition).
+    #[reflect(default)]
+    pub transition: Option<AnimationConfig>,
     #[reflect(default)]
     pub delete_on_entered: bool,
 }
@@ -342,6 +348,7 @@ impl AnimationSettings {
         self.idle = other.idle;
         self.hover = other.hover;
         self.pressed = other.pressed;
+        self.transition = other.transition;
         self.delete_on_entered = other.delete_on_entered;
 
         self
@@ -370,6 +377,8 @@ impl AnimationSettings {
     state_from_animation_setter!(hover, hover_from);
     state_animation_setter!(pressed);
     state_from_animation_setter!(pressed, pressed_from);
+    transition_animation_setter!(transition);
+    transition_from_animation_setter!(transition, transition_from);
 
     pub fn delete_on_entered(&mut self, do_delete: bool) -> &mut Self {
         self.delete_on_entered = do_delete;
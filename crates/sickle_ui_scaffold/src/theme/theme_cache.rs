@@ -0,0 +1,113 @@
+use std::{any::TypeId, sync::Arc};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::ui_style::builder::StyleBuilder;
+
+use super::pseudo_state::PseudoState;
+
+/// The cache is dropped when it grows past this many resolved themes.
+const MAX_ENTRIES: usize = 4096;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ThemeCacheKey {
+    component: TypeId,
+    sources: Vec<Entity>,
+    pseudo_states: Vec<PseudoState>,
+    generation: u32,
+}
+
+impl ThemeCacheKey {
+    /// `sources` are the entities holding a `Theme<C>` for the refreshed entity, from itself up.
+    /// `generation` is the change tick of [`ThemeData`](super::theme_data::ThemeData).
+    pub(crate) fn new<C: 'static>(
+        sources: Vec<Entity>,
+        pseudo_states: &[PseudoState],
+        generation: u32,
+    ) -> Self {
+        let mut pseudo_states = pseudo_states.to_vec();
+        pseudo_states.sort_unstable();
+        pseudo_states.dedup();
+
+        Self {
+            component: TypeId::of::<C>(),
+            sources,
+            pseudo_states,
+            generation,
+        }
+    }
+}
+
+/// Caches merged theme styles, so widgets of the same type, under the same themes and in the
+/// same pseudo states resolve their theme once.
+///
+/// Only themes built from static styles and `PseudoTheme::deferred` callbacks are cached, as
+/// they only depend on [`ThemeData`](super::theme_data::ThemeData). Themes using context or world
+/// callbacks, or `PseudoTheme::custom*` closures, are resolved per entity. Entries are unresolved, context names are mapped to
+/// entities per refresh.
+///
+/// The cache is cleared when `ThemeData` changes, and entries of a component are dropped
+/// when one of its `Theme`s is changed or removed.
+#[derive(Resource, Debug)]
+pub struct ThemeCache {
+    enabled: bool,
+    generation: u32,
+    entries: HashMap<ThemeCacheKey, Arc<StyleBuilder>>,
+}
+
+impl Default for ThemeCache {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            generation: 0,
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl ThemeCache {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Disabling the cache also clears it.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.clear();
+        }
+    }
+
+    /// The number of cached themes
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drops the cached themes of component `C`
+    pub fn invalidate<C: 'static>(&mut self) {
+        let component = TypeId::of::<C>();
+        self.entries.retain(|key, _| key.component != component);
+    }
+
+    pub(crate) fn get(&self, key: &ThemeCacheKey) -> Option<Arc<StyleBuilder>> {
+        self.entries.get(key).cloned()
+    }
+
+    /// `style_builder` must be sorted by placement.
+    pub(crate) fn insert(&mut self, key: ThemeCacheKey, style_builder: StyleBuilder) {
+        if key.generation != self.generation || self.entries.len() >= MAX_ENTRIES {
+            self.entries.clear();
+            self.generation = key.generation;
+        }
+
+        self.entries.insert(key, Arc::new(style_builder));
+    }
+}
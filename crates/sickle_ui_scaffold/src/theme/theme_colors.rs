@@ -1,7 +1,11 @@
-use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
+use bevy::{
+    ecs::intern::{Interned, Interner},
+    prelude::*,
+    utils::HashMap,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::theme_data::Contrast;
+use super::theme_data::{Contrast, Scheme};
 
 /// Custom serialization and deserialization functions necessary for the loading and saving of
 /// [`Color`] structs to their hex string representation.
@@ -76,7 +80,8 @@ pub mod loader {
         ) -> Result<Self::Asset, Self::Error> {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
-            let theme_colors_asset = serde_json::from_slice(&bytes)?;
+            let mut theme_colors_asset: ThemeColors = serde_json::from_slice(&bytes)?;
+            theme_colors_asset.resolve_extended_colors();
             Ok(theme_colors_asset)
         }
     }
@@ -155,6 +160,205 @@ pub struct ExtendedColor {
     pub harmonized: bool,
 }
 
+impl ExtendedColor {
+    /// Returns the roles of the color in the given scheme, using the tones the Material Theme
+    /// Builder uses for custom colors. Harmonized colors have their hue shifted towards `seed`.
+    pub fn scheme_colors(&self, seed: Color, scheme: Scheme) -> ExtendedSchemeColors {
+        let mut source = Lcha::from(self.color);
+        if self.harmonized {
+            source.hue = harmonize_hue(source.hue, Lcha::from(seed).hue);
+        }
+
+        let [color, on_color, color_container, on_color_container] = match scheme {
+            Scheme::Light(Contrast::Standard) => [40., 100., 90., 10.],
+            Scheme::Light(Contrast::Medium) => [30., 100., 45., 100.],
+            Scheme::Light(Contrast::High) => [15., 100., 30., 100.],
+            Scheme::Dark(Contrast::Standard) => [80., 20., 30., 90.],
+            Scheme::Dark(Contrast::Medium) => [82., 10., 55., 0.],
+            Scheme::Dark(Contrast::High) => [97., 0., 82., 0.],
+        }
+        .map(|tone| tonal_color(source, tone));
+
+        ExtendedSchemeColors {
+            color,
+            on_color,
+            color_container,
+            on_color_container,
+        }
+    }
+}
+
+/// Rotates `hue` towards `seed_hue` by half their difference, at most 15 degrees
+fn harmonize_hue(hue: f32, seed_hue: f32) -> f32 {
+    let difference = 180. - ((hue - seed_hue).abs() - 180.).abs();
+    let rotation = (difference * 0.5).min(15.);
+    let direction = match (seed_hue - hue).rem_euclid(360.) <= 180. {
+        true => 1.,
+        false => -1.,
+    };
+
+    (hue + rotation * direction).rem_euclid(360.)
+}
+
+/// Returns `source` at the given tone (CIE L*), reducing chroma until it fits in sRGB
+fn tonal_color(source: Lcha, tone: f32) -> Color {
+    let mut color = Lcha {
+        lightness: tone / 100.,
+        alpha: 1.,
+        ..source
+    };
+
+    for _ in 0..64 {
+        let linear = Color::from(color).to_linear();
+        if [linear.red, linear.green, linear.blue]
+            .iter()
+            .all(|channel| (-0.001..=1.001).contains(channel))
+        {
+            break;
+        }
+
+        color.chroma *= 0.9;
+    }
+
+    Color::Srgba(Srgba::from(color))
+}
+
+/// The roles of an [`ExtendedColor`], matching the roles of the core accents
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Color,
+    OnColor,
+    Container,
+    OnContainer,
+}
+
+impl Role {
+    /// The role of content shown on top of this role, i.e. [`Role::OnContainer`] for
+    /// [`Role::Container`]
+    pub fn on(&self) -> Role {
+        match self {
+            Role::Color => Role::OnColor,
+            Role::OnColor => Role::Color,
+            Role::Container => Role::OnContainer,
+            Role::OnContainer => Role::Container,
+        }
+    }
+}
+
+/// An [`ExtendedColor`] resolved for a scheme / contrast.
+///
+/// Derived from `extendedColors` when the theme is loaded, and can be set per scheme in theme
+/// files to override the derived colors:
+/// `"extended": { "warning": { "color": "#7C5800", "onColor": "#FFFFFF", ... } }`
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedSchemeColors {
+    #[serde(with = "serialize_color")]
+    pub color: Color,
+
+    #[serde(with = "serialize_color")]
+    pub on_color: Color,
+
+    #[serde(with = "serialize_color")]
+    pub color_container: Color,
+
+    #[serde(with = "serialize_color")]
+    pub on_color_container: Color,
+}
+
+impl ExtendedSchemeColors {
+    pub fn role(&self, role: Role) -> Color {
+        match role {
+            Role::Color => self.color,
+            Role::OnColor => self.on_color,
+            Role::Container => self.color_container,
+            Role::OnContainer => self.on_color_container,
+        }
+    }
+}
+
+/// Number of extended colors a scheme can hold. They are stored inline to keep
+/// [`SchemeColors`] `Copy`.
+pub const MAX_EXTENDED_COLORS: usize = 8;
+
+static EXTENDED_COLOR_NAMES: Interner<str> = Interner::new();
+
+/// The extended colors of a scheme by name
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExtendedColors {
+    entries: [Option<(Interned<str>, ExtendedSchemeColors)>; MAX_EXTENDED_COLORS],
+}
+
+impl ExtendedColors {
+    pub fn get(&self, name: &str) -> Option<ExtendedSchemeColors> {
+        self.iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, colors)| colors)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Sets the colors of `name`, returns `false` if the scheme already holds
+    /// [`MAX_EXTENDED_COLORS`] other colors
+    pub fn insert(&mut self, name: &str, colors: ExtendedSchemeColors) -> bool {
+        let slot = match self
+            .entries
+            .iter()
+            .position(|entry| entry.is_some_and(|(entry, _)| &*entry == name))
+        {
+            Some(index) => Some(index),
+            None => self.entries.iter().position(Option::is_none),
+        };
+        let Some(slot) = slot else {
+            warn!(
+                "Extended color {} dropped, schemes hold at most {} extended colors",
+                name, MAX_EXTENDED_COLORS
+            );
+            return false;
+        };
+
+        self.entries[slot] = Some((EXTENDED_COLOR_NAMES.intern(name), colors));
+        true
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, ExtendedSchemeColors)> + '_ {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|(name, colors)| (name.0, *colors))
+    }
+}
+
+impl Serialize for ExtendedColors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtendedColors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let colors: HashMap<String, ExtendedSchemeColors> = HashMap::deserialize(deserializer)?;
+        let mut extended = ExtendedColors::default();
+        for (name, colors) in colors.iter() {
+            extended.insert(name, *colors);
+        }
+
+        Ok(extended)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct CoreColors {
     #[serde(with = "serialize_color")]
@@ -337,6 +541,11 @@ pub struct SchemeColors {
 
     #[serde(with = "serialize_color")]
     pub surface_container_highest: Color,
+
+    /// Extended colors by name
+    #[serde(default, skip_serializing_if = "ExtendedColors::is_empty")]
+    #[reflect(ignore)]
+    pub extended: ExtendedColors,
 }
 
 impl SchemeColors {
@@ -407,6 +616,23 @@ impl SchemeColors {
             OnColor::InverseSurface => self.inverse_on_surface,
         }
     }
+
+    /// Returns a role of the extended color `name`, i.e. `colors.extended("warning", Role::Container)`.
+    /// Falls back to the matching primary role if the theme doesn't define the color.
+    pub fn extended(&self, name: &str, role: Role) -> Color {
+        match self.extended.get(name) {
+            Some(extended) => extended.role(role),
+            None => {
+                warn!("Extended color {} isn't defined by the theme", name);
+                match role {
+                    Role::Color => self.primary,
+                    Role::OnColor => self.on_primary,
+                    Role::Container => self.primary_container,
+                    Role::OnContainer => self.on_primary_container,
+                }
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
@@ -495,6 +721,38 @@ pub struct ColorPalette {
     pub p_100: Color,
 }
 
+impl ColorPalette {
+    /// Tones available in a palette, from darkest to lightest
+    pub const TONES: [u8; 18] = [
+        0, 5, 10, 15, 20, 25, 30, 35, 40, 50, 60, 70, 80, 90, 95, 98, 99, 100,
+    ];
+
+    /// Returns the color of the given tone, `None` if the palette doesn't define the tone
+    pub fn tone(&self, tone: u8) -> Option<Color> {
+        match tone {
+            0 => self.p_0.into(),
+            5 => self.p_5.into(),
+            10 => self.p_10.into(),
+            15 => self.p_15.into(),
+            20 => self.p_20.into(),
+            25 => self.p_25.into(),
+            30 => self.p_30.into(),
+            35 => self.p_35.into(),
+            40 => self.p_40.into(),
+            50 => self.p_50.into(),
+            60 => self.p_60.into(),
+            70 => self.p_70.into(),
+            80 => self.p_80.into(),
+            90 => self.p_90.into(),
+            95 => self.p_95.into(),
+            98 => self.p_98.into(),
+            99 => self.p_99.into(),
+            100 => self.p_100.into(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct ColorPalettes {
@@ -520,6 +778,45 @@ pub struct ThemeColors {
     pub palettes: ColorPalettes,
 }
 
+impl ThemeColors {
+    /// Derives the scheme colors of `extended_colors`, keeping colors the schemes already define.
+    ///
+    /// Called when a theme file is loaded, call it after changing `extended_colors` in code.
+    pub fn resolve_extended_colors(&mut self) {
+        let schemes = [
+            (Scheme::Light(Contrast::Standard), &mut self.schemes.light),
+            (
+                Scheme::Light(Contrast::Medium),
+                &mut self.schemes.light_medium_contrast,
+            ),
+            (
+                Scheme::Light(Contrast::High),
+                &mut self.schemes.light_high_contrast,
+            ),
+            (Scheme::Dark(Contrast::Standard), &mut self.schemes.dark),
+            (
+                Scheme::Dark(Contrast::Medium),
+                &mut self.schemes.dark_medium_contrast,
+            ),
+            (
+                Scheme::Dark(Contrast::High),
+                &mut self.schemes.dark_high_contrast,
+            ),
+        ];
+
+        for (scheme, scheme_colors) in schemes {
+            for extended_color in self.extended_colors.iter() {
+                if !scheme_colors.extended.contains(&extended_color.name) {
+                    scheme_colors.extended.insert(
+                        &extended_color.name,
+                        extended_color.scheme_colors(self.seed, scheme),
+                    );
+                }
+            }
+        }
+    }
+}
+
 impl Default for ThemeColors {
     fn default() -> Self {
         Self {
@@ -582,6 +879,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("FCEBE2").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("F6E5DC").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("F0DFD7").unwrap()),
+                    extended: Default::default(),
                 },
                 light_medium_contrast: SchemeColors {
                     primary: Color::Srgba(Srgba::hex("69350B").unwrap()),
@@ -632,6 +930,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("FCEBE2").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("F6E5DC").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("F0DFD7").unwrap()),
+                    extended: Default::default(),
                 },
 
                 light_high_contrast: SchemeColors {
@@ -683,6 +982,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("FCEBE2").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("F6E5DC").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("F0DFD7").unwrap()),
+                    extended: Default::default(),
                 },
                 dark: SchemeColors {
                     primary: Color::Srgba(Srgba::hex("FFB688").unwrap()),
@@ -733,6 +1033,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("261E19").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("312823").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("3D332D").unwrap()),
+                    extended: Default::default(),
                 },
 
                 dark_medium_contrast: SchemeColors {
@@ -784,6 +1085,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("261E19").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("312823").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("3D332D").unwrap()),
+                    extended: Default::default(),
                 },
                 dark_high_contrast: SchemeColors {
                     primary: Color::Srgba(Srgba::hex("FFFAF8").unwrap()),
@@ -834,6 +1136,7 @@ impl Default for ThemeColors {
                     surface_container: Color::Srgba(Srgba::hex("261E19").unwrap()),
                     surface_container_high: Color::Srgba(Srgba::hex("312823").unwrap()),
                     surface_container_highest: Color::Srgba(Srgba::hex("3D332D").unwrap()),
+                    extended: Default::default(),
                 },
             },
             palettes: ColorPalettes {
@@ -4,11 +4,11 @@ use sickle_math::ease::Ease;
 use crate::ui_style::builder::StyleBuilder;
 
 use super::{
-    icons::Icons,
-    style_animation::AnimationSettings,
+    icons::{IconData, Icons},
+    style_animation::{AnimationConfig, AnimationSettings},
     theme_colors::{SchemeColors, ThemeColors},
     theme_spacing::ThemeSpacing,
-    typography::ThemeTypography,
+    typography::{FontScale, FontStyle, FontType, SizedFont, ThemeTypography},
 };
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
@@ -41,16 +41,129 @@ impl Scheme {
     }
 }
 
+/// How tightly the UI is laid out
+///
+/// Scales spacing, icon and font sizes, and sets the minimum size of interactive elements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum UiDensity {
+    Compact,
+    #[default]
+    Default,
+    Comfortable,
+    /// For touch screens
+    Touch,
+}
+
+impl UiDensity {
+    pub fn spacing_factor(&self) -> f32 {
+        match self {
+            UiDensity::Compact => 0.75,
+            UiDensity::Default => 1.,
+            UiDensity::Comfortable => 1.25,
+            UiDensity::Touch => 1.5,
+        }
+    }
+
+    pub fn font_factor(&self) -> f32 {
+        match self {
+            UiDensity::Compact => 0.9,
+            UiDensity::Default => 1.,
+            UiDensity::Comfortable => 1.1,
+            UiDensity::Touch => 1.2,
+        }
+    }
+
+    /// The smallest height of interactive elements, before scaling
+    pub fn min_hit_target(&self) -> f32 {
+        match self {
+            UiDensity::Compact => 20.,
+            UiDensity::Default => 24.,
+            UiDensity::Comfortable => 32.,
+            UiDensity::Touch => 48.,
+        }
+    }
+}
+
+/// The horizontal direction widgets are laid out in
+///
+/// Taffy does not support `Direction`, built-in widgets mirror their styles, icons and
+/// placement through [`ThemeData::layout_direction`] instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    /// For Arabic, Hebrew and other right-to-left scripts
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub fn is_ltr(&self) -> bool {
+        matches!(self, LayoutDirection::LeftToRight)
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, LayoutDirection::RightToLeft)
+    }
+
+    /// Returns `ltr` in left-to-right and `rtl` in right-to-left layouts
+    pub fn pick<T>(&self, ltr: T, rtl: T) -> T {
+        match self {
+            LayoutDirection::LeftToRight => ltr,
+            LayoutDirection::RightToLeft => rtl,
+        }
+    }
+
+    /// The direction of horizontal rows, start to end
+    pub fn row(&self) -> FlexDirection {
+        self.pick(FlexDirection::Row, FlexDirection::RowReverse)
+    }
+
+    /// Mirrors `direction` horizontally in right-to-left layouts
+    pub fn flex_direction(&self, direction: FlexDirection) -> FlexDirection {
+        match (self, direction) {
+            (LayoutDirection::RightToLeft, FlexDirection::Row) => FlexDirection::RowReverse,
+            (LayoutDirection::RightToLeft, FlexDirection::RowReverse) => FlexDirection::Row,
+            _ => direction,
+        }
+    }
+
+    /// Mirrors `rect` horizontally in right-to-left layouts, `left` is treated as the start side
+    pub fn rect(&self, rect: UiRect) -> UiRect {
+        match self {
+            LayoutDirection::LeftToRight => rect,
+            LayoutDirection::RightToLeft => UiRect {
+                left: rect.right,
+                right: rect.left,
+                ..rect
+            },
+        }
+    }
+
+    /// `1.` in left-to-right and `-1.` in right-to-left layouts, for horizontal offsets and
+    /// values that grow towards the end side
+    pub fn sign(&self) -> f32 {
+        self.pick(1., -1.)
+    }
+}
+
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct ThemeData {
     pub active_scheme: Scheme,
     pub colors: ThemeColors,
+    /// Spacing at `UiDensity::Default` and a scale of 1, use [`ThemeData::spacing()`] in themes
     pub spacing: ThemeSpacing,
+    /// Typography at `UiDensity::Default` and a scale of 1, use [`ThemeData::font()`] in themes
     pub text: ThemeTypography,
+    pub density: UiDensity,
+    pub layout_direction: LayoutDirection,
+    /// Scales all sizes on top of the density, e.g. for high DPI displays
+    pub scale: f32,
     pub icons: Icons,
     pub interaction_animation: AnimationSettings,
     pub delayed_interaction_animation: AnimationSettings,
     pub enter_animation: AnimationSettings,
+    /// Used by widgets to tween between the values of their pseudo state themes
+    pub style_transition: AnimationConfig,
 }
 
 impl Default for ThemeData {
@@ -77,10 +190,14 @@ impl Default for ThemeData {
             colors: Default::default(),
             spacing: Default::default(),
             text: Default::default(),
+            density: Default::default(),
+            layout_direction: Default::default(),
+            scale: 1.,
             icons: Default::default(),
             interaction_animation,
             delayed_interaction_animation,
             enter_animation,
+            style_transition: AnimationConfig::new(0.15, Ease::OutExpo, None),
         }
     }
 }
@@ -94,6 +211,35 @@ impl ThemeData {
         style_builder
     }
 
+    /// Returns the spacing scaled to the current density and scale
+    pub fn spacing(&self) -> ThemeSpacing {
+        self.spacing
+            .scaled(self.density.spacing_factor() * self.scale, self.scale)
+    }
+
+    /// Returns the font of the typography, sized to the current density and scale
+    pub fn font(&self, style: FontStyle, scale: FontScale, font_type: FontType) -> SizedFont {
+        let font = self.text.get(style, scale, font_type);
+        SizedFont {
+            size: font.size * self.density.font_factor() * self.scale,
+            ..font
+        }
+    }
+
+    /// Returns the smallest height of interactive elements at the current density and scale
+    pub fn min_hit_target(&self) -> f32 {
+        self.density.min_hit_target() * self.scale
+    }
+
+    /// Returns `icon` mirrored for the layout direction, i.e. `chevron_right` becomes
+    /// `chevron_left` in right-to-left layouts
+    pub fn directional_icon(&self, icon: &IconData) -> IconData {
+        match self.layout_direction {
+            LayoutDirection::LeftToRight => icon.clone(),
+            LayoutDirection::RightToLeft => self.icons.mirrored(icon),
+        }
+    }
+
     /// Returns the scheme colors of the current active scheme / contrast
     pub fn colors(&self) -> SchemeColors {
         match self.active_scheme {
@@ -10,6 +10,19 @@ pub struct DividerSpacing {
     pub custom_2: f32,
 }
 
+impl DividerSpacing {
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            extra_small: self.extra_small * factor,
+            small: self.small * factor,
+            medium: self.medium * factor,
+            large: self.large * factor,
+            custom_1: self.custom_1 * factor,
+            custom_2: self.custom_2 * factor,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Reflect)]
 pub struct Spacing {
     pub tiny: f32,
@@ -24,6 +37,23 @@ pub struct Spacing {
     pub custom_4: f32,
 }
 
+impl Spacing {
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            tiny: self.tiny * factor,
+            extra_small: self.extra_small * factor,
+            small: self.small * factor,
+            medium: self.medium * factor,
+            large: self.large * factor,
+            extra_large: self.extra_large * factor,
+            custom_1: self.custom_1 * factor,
+            custom_2: self.custom_2 * factor,
+            custom_3: self.custom_3 * factor,
+            custom_4: self.custom_4 * factor,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Reflect)]
 pub struct IconSizes {
     pub extra_small: f32,
@@ -34,6 +64,19 @@ pub struct IconSizes {
     pub custom_2: f32,
 }
 
+impl IconSizes {
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            extra_small: self.extra_small * factor,
+            small: self.small * factor,
+            medium: self.medium * factor,
+            large: self.large * factor,
+            custom_1: self.custom_1 * factor,
+            custom_2: self.custom_2 * factor,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Reflect)]
 pub struct CheckboxSizes {
     pub line_height: f32,
@@ -46,6 +89,16 @@ impl CheckboxSizes {
     pub fn checkbox_size(&self) -> f32 {
         self.checkbox_size + 2. * self.border_size
     }
+
+    /// Borders are scaled by `border_factor`, everything else by `factor`
+    pub fn scaled(&self, factor: f32, border_factor: f32) -> Self {
+        Self {
+            line_height: self.line_height * factor,
+            border_size: self.border_size * border_factor,
+            checkbox_size: self.checkbox_size * factor,
+            checkmark_size: self.checkmark_size * factor,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Reflect)]
@@ -59,12 +112,63 @@ impl RedioButtonSizes {
     pub fn radiomark_full_outer_size(&self) -> f32 {
         self.radiomark_outer_size + 2. * self.border_size
     }
+
+    /// Borders are scaled by `border_factor`, everything else by `factor`
+    pub fn scaled(&self, factor: f32, border_factor: f32) -> Self {
+        Self {
+            border_size: self.border_size * border_factor,
+            radiomark_outer_size: self.radiomark_outer_size * factor,
+            radiomark_size: self.radiomark_size * factor,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+pub struct SwitchSizes {
+    pub track_width: f32,
+    pub track_height: f32,
+    pub border_size: f32,
+    pub thumb_size: f32,
+}
+
+impl SwitchSizes {
+    /// Offset of the thumb from the inner edges of the track
+    pub fn thumb_inset(&self) -> f32 {
+        (self.track_height - 2. * self.border_size - self.thumb_size) / 2.
+    }
+
+    /// Left offset of the thumb when the switch is on
+    pub fn thumb_travel(&self) -> f32 {
+        self.track_width - 2. * self.border_size - self.thumb_size - self.thumb_inset()
+    }
+
+    /// Borders are scaled by `border_factor`, everything else by `factor`
+    pub fn scaled(&self, factor: f32, border_factor: f32) -> Self {
+        Self {
+            track_width: self.track_width * factor,
+            track_height: self.track_height * factor,
+            border_size: self.border_size * border_factor,
+            thumb_size: self.thumb_size * factor,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Reflect)]
 pub struct InputSizes {
     pub checkbox: CheckboxSizes,
     pub radio_button: RedioButtonSizes,
+    pub switch: SwitchSizes,
+}
+
+impl InputSizes {
+    /// Borders are scaled by `border_factor`, everything else by `factor`
+    pub fn scaled(&self, factor: f32, border_factor: f32) -> Self {
+        Self {
+            checkbox: self.checkbox.scaled(factor, border_factor),
+            radio_button: self.radio_button.scaled(factor, border_factor),
+            switch: self.switch.scaled(factor, border_factor),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Reflect)]
@@ -74,6 +178,16 @@ pub struct ResizeZone {
     pub handle_gap: f32,
 }
 
+impl ResizeZone {
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            width: self.width * factor,
+            pullback: self.pullback * factor,
+            handle_gap: self.handle_gap * factor,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Reflect)]
 pub struct ThemeSpacing {
     pub borders: DividerSpacing,
@@ -153,6 +267,12 @@ impl Default for ThemeSpacing {
                     radiomark_outer_size: 14.,
                     radiomark_size: 6.,
                 },
+                switch: SwitchSizes {
+                    track_width: 36.,
+                    track_height: 20.,
+                    border_size: 2.,
+                    thumb_size: 12.,
+                },
             },
             resize_zone: ResizeZone {
                 width: 4.,
@@ -163,3 +283,20 @@ impl Default for ThemeSpacing {
         }
     }
 }
+
+impl ThemeSpacing {
+    /// Scales the spacing by `factor`, except for borders and dividers that are scaled
+    /// by `border_factor`, so that they stay crisp in denser layouts.
+    pub fn scaled(&self, factor: f32, border_factor: f32) -> Self {
+        Self {
+            borders: self.borders.scaled(border_factor),
+            corners: self.corners.scaled(factor),
+            gaps: self.gaps.scaled(factor),
+            areas: self.areas.scaled(factor),
+            icons: self.icons.scaled(factor),
+            inputs: self.inputs.scaled(factor, border_factor),
+            resize_zone: self.resize_zone.scaled(factor),
+            scroll_bar_size: self.scroll_bar_size * factor,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum FontStyle {
     Display,
     Headline,
@@ -9,14 +9,14 @@ pub enum FontStyle {
     Label,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum FontScale {
     Small,
     Medium,
     Large,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum FontType {
     Regular,
     Bold,
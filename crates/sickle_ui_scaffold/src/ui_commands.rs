@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use bevy::{
     core::Name,
     ecs::{
+        change_detection::DetectChanges,
         component::ComponentInfo,
         entity::Entity,
         query::With,
@@ -21,8 +22,9 @@ use crate::{
     flux_interaction::{
         FluxInteraction, FluxInteractionStopwatchLock, StopwatchLock, TrackedInteraction,
     },
+    localization::{Locale, LocalizedText},
     prelude::UiUtils,
-    theme::prelude::*,
+    theme::{prelude::*, theme_cache::ThemeCacheKey},
     ui_style::builder::StyleBuilder,
 };
 
@@ -96,11 +98,16 @@ impl SetTextExt for EntityCommands<'_> {
 }
 
 struct UpdateText {
-    text: String,
+    text: LocalizedText,
 }
 
 impl EntityCommand for UpdateText {
     fn apply(self, entity: Entity, world: &mut World) {
+        let value = match world.get_resource::<Locale>() {
+            Some(locale) => locale.text(&self.text),
+            None => self.text.to_string(),
+        };
+
         let Some(mut text) = world.get_mut::<Text>(entity) else {
             warn!(
                 "Failed to set text on entity {}: No Text component found!",
@@ -110,11 +117,17 @@ impl EntityCommand for UpdateText {
         };
 
         let first_section = match text.sections.get(0) {
-            Some(section) => TextSection::new(self.text, section.style.clone()),
-            None => TextSection::new(self.text, TextStyle::default()),
+            Some(section) => TextSection::new(value, section.style.clone()),
+            None => TextSection::new(value, TextStyle::default()),
         };
 
         text.sections = vec![first_section];
+
+        // Literals are not tracked, so other text set on the entity isn't replaced on locale change
+        match self.text.is_literal() {
+            true => world.entity_mut(entity).remove::<LocalizedText>(),
+            false => world.entity_mut(entity).insert(self.text),
+        };
     }
 }
 
@@ -122,11 +135,12 @@ pub trait UpdateTextExt {
     /// Update an entity's [`Text`]
     ///
     /// The [`Text`] component must already exist.
-    fn update_text(&mut self, text: impl Into<String>) -> &mut Self;
+    /// Localized text is kept in sync with the [`Locale`].
+    fn update_text(&mut self, text: impl Into<LocalizedText>) -> &mut Self;
 }
 
 impl UpdateTextExt for EntityCommands<'_> {
-    fn update_text(&mut self, text: impl Into<String>) -> &mut Self {
+    fn update_text(&mut self, text: impl Into<LocalizedText>) -> &mut Self {
         self.add(UpdateText { text: text.into() });
 
         self
@@ -410,9 +424,42 @@ where
             }
         }
 
+        // Themes that only depend on theme data are shared by all entities with the same theme chain
+        let theme_cache = world.resource::<ThemeCache>();
+        let cache_key = (theme_cache.is_enabled()
+            && pseudo_themes.iter().all(|(pseudo_theme, _)| {
+                matches!(
+                    pseudo_theme.builder(),
+                    DynamicStyleBuilder::Static(_) | DynamicStyleBuilder::StyleBuilder(_)
+                )
+            }))
+        .then(|| {
+            ThemeCacheKey::new::<C>(
+                themes.iter().filter_map(|(_, source)| *source).collect(),
+                pseudo_states,
+                world.resource_ref::<ThemeData>().last_changed().get(),
+            )
+        });
+        let cached_style = cache_key.as_ref().and_then(|key| theme_cache.get(key));
+        let mut cache_entry: Option<(ThemeCacheKey, StyleBuilder)> = None;
+
         // Merge base attributes on top of the default and down the chain, overwriting per-attribute at each level
         let mut styles = Vec::<(Option<Entity>, DynamicStyle)>::default();
         let mut style_builder = StyleBuilder::new();
+        if let Some(cached_style) = cached_style {
+            styles = cached_style.convert_sorted(context);
+            pseudo_themes.clear();
+        } else if let Some(mut merged_style) = cache_key
+            .is_some()
+            .then(|| merge_cacheable_pseudo_themes(&pseudo_themes, theme_data))
+            .flatten()
+        {
+            merged_style.sort_by_placement();
+            styles = merged_style.convert_sorted(context);
+            cache_entry = cache_key.map(|key| (key, merged_style));
+            pseudo_themes.clear();
+        }
+
         for (pseudo_theme, source_entity) in pseudo_themes.iter() {
             let builder = pseudo_theme.builder();
             if let DynamicStyleBuilder::Static(style) = builder {
@@ -448,6 +495,33 @@ where
                             world,
                         );
 
+                        style_builder.convert_to_iter(context)
+                    }
+                    DynamicStyleBuilder::CustomStyleBuilder(builder) => {
+                        builder(&mut style_builder, theme_data);
+
+                        style_builder.convert_to_iter(context)
+                    }
+                    DynamicStyleBuilder::CustomContextStyleBuilder(builder) => {
+                        builder(&mut style_builder, context, theme_data);
+
+                        style_builder.convert_to_iter(context)
+                    }
+                    DynamicStyleBuilder::CustomWorldStyleBuilder(builder) => {
+                        builder(&mut style_builder, entity, context, world);
+
+                        style_builder.convert_to_iter(context)
+                    }
+                    DynamicStyleBuilder::CustomInfoWorldStyleBuilder(builder) => {
+                        builder(
+                            &mut style_builder,
+                            *source_entity,
+                            pseudo_theme.state(),
+                            entity,
+                            context,
+                            world,
+                        );
+
                         style_builder.convert_to_iter(context)
                     }
                 };
@@ -465,6 +539,10 @@ where
             .filter(|e| *e != Entity::PLACEHOLDER)
             .collect();
 
+        if let Some((key, merged_style)) = cache_entry {
+            world.resource_mut::<ThemeCache>().insert(key, merged_style);
+        }
+
         for (placement, mut style) in styles {
             let placement_entity = match placement {
                 Some(placement_entity) => placement_entity,
@@ -507,6 +585,34 @@ where
     }
 }
 
+/// Merges the pseudo themes into a single unresolved style.
+///
+/// Returns `None` if any of them depends on more than the theme data.
+fn merge_cacheable_pseudo_themes<C: DefaultTheme>(
+    pseudo_themes: &[(&PseudoTheme<C>, Option<Entity>)],
+    theme_data: &ThemeData,
+) -> Option<StyleBuilder> {
+    let mut merged_style = StyleBuilder::new();
+    let mut style_builder = StyleBuilder::new();
+    for (pseudo_theme, _) in pseudo_themes {
+        match pseudo_theme.builder() {
+            DynamicStyleBuilder::Static(style) => {
+                if !merged_style.merge_from_style(style) {
+                    return None;
+                }
+            }
+            DynamicStyleBuilder::StyleBuilder(builder) => {
+                style_builder.clear();
+                builder(&mut style_builder, theme_data);
+                merged_style.merge_from(&style_builder);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(merged_style)
+}
+
 fn fold_dynamic_styles(
     mut acc: Vec<(Option<Entity>, DynamicStyle)>,
     mut context_style: (Option<Entity>, DynamicStyle),
@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommand, prelude::*};
 use smol_str::SmolStr;
 
 use crate::{prelude::FluxInteraction, theme::prelude::*};
@@ -84,6 +84,7 @@ impl LogicalEq for ContextStyleAttributeConfig {
 pub struct StyleBuilder {
     placement: Option<SmolStr>,
     target: Option<SmolStr>,
+    transition: Option<AnimationConfig>,
     attributes: Vec<ContextStyleAttributeConfig>,
 }
 
@@ -120,11 +121,28 @@ impl StyleBuilder {
         Self {
             placement: None,
             target: None,
+            transition: None,
             attributes: Vec::with_capacity(num_attributes),
         }
     }
 
-    pub fn add(&mut self, attribute: DynamicStyleAttribute) -> usize {
+    pub fn add(&mut self, mut attribute: DynamicStyleAttribute) -> usize {
+        if let (Some(transition), DynamicStyleAttribute::Static(static_attribute)) =
+            (self.transition, &attribute)
+        {
+            // Kept as a single value animation, so the value shown is known on re-theme
+            if let Some(animated_attribute) = AnimatedStyleAttribute::from_static(static_attribute)
+            {
+                let mut animation = AnimationSettings::new();
+                animation.transition_from(transition);
+
+                attribute = DynamicStyleAttribute::Animated {
+                    attribute: animated_attribute,
+                    controller: DynamicStyleController::new(animation, default()),
+                };
+            }
+        }
+
         let index = self.attributes.iter().position(|csac| {
             csac.placement == self.placement
                 && csac.target == self.target
@@ -163,6 +181,51 @@ impl StyleBuilder {
         self
     }
 
+    /// Sets the background color to a role of the theme's extended color `name`, i.e.
+    /// `style_builder.extended_background_color("warning", Role::Container)`.
+    ///
+    /// The color is read from [`ThemeData`] when the style is applied, so static themes follow
+    /// scheme changes too.
+    pub fn extended_background_color(&mut self, name: impl Into<String>, role: Role) -> &mut Self {
+        let name = name.into();
+        self.custom(move |entity, world| {
+            let background_color = world.resource::<ThemeData>().colors().extended(&name, role);
+            SetBackgroundColor {
+                background_color,
+                check_lock: true,
+            }
+            .apply(entity, world);
+        })
+    }
+
+    /// Sets the border color to a role of the theme's extended color `name`, read from
+    /// [`ThemeData`] when the style is applied
+    pub fn extended_border_color(&mut self, name: impl Into<String>, role: Role) -> &mut Self {
+        let name = name.into();
+        self.custom(move |entity, world| {
+            let border_color = world.resource::<ThemeData>().colors().extended(&name, role);
+            SetBorderColor {
+                border_color,
+                check_lock: true,
+            }
+            .apply(entity, world);
+        })
+    }
+
+    /// Sets the font color to a role of the theme's extended color `name`, read from
+    /// [`ThemeData`] when the style is applied
+    pub fn extended_font_color(&mut self, name: impl Into<String>, role: Role) -> &mut Self {
+        let name = name.into();
+        self.custom(move |entity, world| {
+            let font_color = world.resource::<ThemeData>().colors().extended(&name, role);
+            SetFontColor {
+                font_color,
+                check_lock: true,
+            }
+            .apply(entity, world);
+        })
+    }
+
     pub fn interactive(&mut self) -> InteractiveStyleBuilder {
         InteractiveStyleBuilder {
             style_builder: self,
@@ -222,6 +285,20 @@ impl StyleBuilder {
         self
     }
 
+    /// All subsequent static attributes added to the StyleBuilder tween from their previous
+    /// value when a re-theme changes them, i.e. on pseudo state changes.
+    /// NOTE: Custom attributes and attributes that cannot be animated are still applied as is.
+    pub fn switch_transition(&mut self, transition: AnimationConfig) -> &mut Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Revert StyleBuilder to apply static attributes without transitions.
+    pub fn reset_transition(&mut self) -> &mut Self {
+        self.transition = None;
+        self
+    }
+
     /// All subsequent calls to the StyleBuilder will target styling to the selected sub-component.
     /// NOTE: The DynamicStyle will still be set on the main entity and interactions will be
     /// detected on it. This allows styling sub-components by proxy from the current placement.
@@ -264,9 +341,28 @@ impl StyleBuilder {
         context: &'a impl UiContext,
         buffer_source: impl FnMut() -> Vec<ContextStyleAttribute> + 'a,
     ) -> impl Iterator<Item = (Option<Entity>, DynamicStyle)> + 'a {
+        self.sort_by_placement();
+        self.convert_sorted_to_iter(context, buffer_source)
+    }
+
+    /// Converts a builder sorted with [`StyleBuilder::sort_by_placement`] without cloning it.
+    pub(crate) fn convert_sorted(
+        &self,
+        context: &impl UiContext,
+    ) -> Vec<(Option<Entity>, DynamicStyle)> {
+        self.convert_sorted_to_iter(context, Vec::default).collect()
+    }
+
+    pub(crate) fn sort_by_placement(&mut self) {
         self.attributes
             .sort_unstable_by(|a, b| a.placement.cmp(&b.placement));
+    }
 
+    fn convert_sorted_to_iter<'a>(
+        &'a self,
+        context: &'a impl UiContext,
+        buffer_source: impl FnMut() -> Vec<ContextStyleAttribute> + 'a,
+    ) -> impl Iterator<Item = (Option<Entity>, DynamicStyle)> + 'a {
         self.attributes
             .chunk_by(|a, b| a.placement == b.placement)
             .scan(0, |index, placement_chunk| {
@@ -322,9 +418,46 @@ impl StyleBuilder {
     pub fn clear(&mut self) {
         self.target = None;
         self.placement = None;
+        self.transition = None;
         self.attributes.clear();
     }
 
+    /// Merges the attributes of `other` on top of the current ones, overwriting per-attribute
+    /// for the same placement and target. Context names are kept unresolved.
+    pub(crate) fn merge_from(&mut self, other: &StyleBuilder) {
+        for attribute in other.attributes.iter() {
+            match self
+                .attributes
+                .iter()
+                .position(|csac| csac.logical_eq(attribute))
+            {
+                Some(index) => self.attributes[index] = attribute.clone(),
+                None => self.attributes.push(attribute.clone()),
+            }
+        }
+    }
+
+    /// Merges a context-free [`DynamicStyle`] on top of the current attributes, placed on the main entity.
+    ///
+    /// Returns `false` without merging if the style targets specific entities.
+    pub(crate) fn merge_from_style(&mut self, style: &DynamicStyle) -> bool {
+        if style.attributes().iter().any(|csa| csa.target().is_some()) {
+            return false;
+        }
+
+        let mut builder = StyleBuilder::new_with_capacity(style.attributes().len());
+        for csa in style.attributes() {
+            builder.attributes.push(ContextStyleAttributeConfig {
+                placement: None,
+                target: None,
+                attribute: csa.attribute().clone(),
+            });
+        }
+        self.merge_from(&builder);
+
+        true
+    }
+
     fn fold_context_style_attributes(
         mut acc: Vec<ContextStyleAttribute>,
         csac: &ContextStyleAttributeConfig,
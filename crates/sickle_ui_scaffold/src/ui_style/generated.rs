@@ -1,8 +1,9 @@
 use bevy::{ecs::system::EntityCommand, prelude::*, ui::FocusPolicy};
 
 use sickle_macros::StyleCommands;
+use sickle_math::lerp::Lerp;
 
-use crate::{flux_interaction::FluxInteraction, theme::prelude::*};
+use crate::{flux_interaction::FluxInteraction, text_overflow::TextOverflow, theme::prelude::*};
 
 use super::{
     attribute::{
@@ -227,6 +228,11 @@ enum _StyleAttributes {
     FontColor {
         font_color: Color,
     },
+    #[skip_lockable_enum]
+    #[skip_enity_command]
+    TextOverflow {
+        text_overflow: TextOverflow,
+    },
     #[skip_enity_command]
     #[animatable]
     Scale {
@@ -1,6 +1,10 @@
 use bevy::{ecs::system::EntityCommand, prelude::*, text::TextLayoutInfo, ui::widget::TextFlags};
 
-use crate::{flux_interaction::FluxInteraction, theme::icons::IconData};
+use crate::{
+    flux_interaction::FluxInteraction,
+    text_overflow::{restore_full_text, TextOverflow, TruncatedText},
+    theme::{icons::IconData, theme_data::ThemeData},
+};
 
 use super::{
     generated::*, LockableStyleAttribute, LockedStyleAttributes, UiStyle, UiStyleUnchecked,
@@ -556,8 +560,10 @@ impl EntityCommand for SetIcon {
 
                 world.entity_mut(entity).remove::<Text>();
                 world.entity_mut(entity).remove::<UiImage>();
+                world.entity_mut(entity).remove::<TextureAtlas>();
             }
             IconData::Image(path, color) => {
+                world.entity_mut(entity).remove::<TextureAtlas>();
                 SetImage {
                     source: ImageSource::Path(path),
                     check_lock: self.check_lock,
@@ -577,6 +583,7 @@ impl EntityCommand for SetIcon {
                     .insert(BackgroundColor(Color::NONE));
 
                 world.entity_mut(entity).remove::<UiImage>();
+                world.entity_mut(entity).remove::<TextureAtlas>();
                 let font = world.resource::<AssetServer>().load(font);
 
                 if let Some(mut text) = world.get_mut::<Text>(entity) {
@@ -605,6 +612,55 @@ impl EntityCommand for SetIcon {
                     ));
                 }
             }
+            IconData::AtlasImage(path, layout, index, color) => {
+                SetImage {
+                    source: ImageSource::Path(path),
+                    check_lock: self.check_lock,
+                }
+                .apply(entity, world);
+                SetImageTint {
+                    image_tint: color,
+                    check_lock: self.check_lock,
+                }
+                .apply(entity, world);
+
+                if let Some(mut atlas) = world.get_mut::<TextureAtlas>(entity) {
+                    if atlas.layout != layout || atlas.index != index {
+                        atlas.layout = layout;
+                        atlas.index = index;
+                    }
+                } else {
+                    world
+                        .entity_mut(entity)
+                        .insert(TextureAtlas { layout, index });
+                }
+            }
+            IconData::Named(ref name, _, _) => {
+                let Some(theme_data) = world.get_resource::<ThemeData>() else {
+                    warn!(
+                        "Failed to set icon {} on entity {}: No ThemeData found!",
+                        name, entity
+                    );
+                    return;
+                };
+
+                let icon = match theme_data.icons.resolve(&self.icon) {
+                    IconData::None | IconData::Named(_, _, _) => {
+                        warn!(
+                            "Failed to set icon on entity {}: No icon named {}",
+                            entity, name
+                        );
+                        IconData::None
+                    }
+                    icon => icon,
+                };
+
+                SetIcon {
+                    icon,
+                    check_lock: self.check_lock,
+                }
+                .apply(entity, world);
+            }
         }
     }
 }
@@ -726,9 +782,28 @@ impl EntityCommand for SetFontColor {
     }
 }
 
-struct SetLockedAttribute {
-    attribute: LockableStyleAttribute,
-    locked: bool,
+impl EntityCommand for SetTextOverflow {
+    fn apply(self, entity: Entity, world: &mut World) {
+        if self.text_overflow == TextOverflow::Visible {
+            restore_full_text(entity, world);
+            return;
+        }
+
+        if let Some(mut truncated_text) = world.get_mut::<TruncatedText>(entity) {
+            if truncated_text.mode() != self.text_overflow {
+                truncated_text.set_mode(self.text_overflow);
+            }
+        } else {
+            world
+                .entity_mut(entity)
+                .insert(TruncatedText::new(self.text_overflow));
+        }
+    }
+}
+
+pub(crate) struct SetLockedAttribute {
+    pub(crate) attribute: LockableStyleAttribute,
+    pub(crate) locked: bool,
 }
 
 impl EntityCommand for SetLockedAttribute {
@@ -2,9 +2,12 @@ use bevy::{
     ecs::component::ComponentInfo,
     prelude::*,
     render::camera::{ManualTextureViews, RenderTarget},
+    ui::CalculatedClip,
     window::{PrimaryWindow, WindowRef, WindowResolution},
 };
 
+use crate::theme::theme_data::LayoutDirection;
+
 pub struct UiUtils;
 
 impl UiUtils {
@@ -141,12 +144,7 @@ impl UiUtils {
     ///
     /// Returned Vec4 contains sizes in the order: Top, Right, Bottom, Left.
     pub fn ui_rect_to_px(rect: UiRect, entity: Entity, world: &World) -> Vec4 {
-        let viewport_size = if let Some(render_target) = UiUtils::find_render_target(entity, world)
-        {
-            UiUtils::render_target_size(render_target, world)
-        } else {
-            UiUtils::resolution_to_vec2(&UiUtils::get_primary_window(world).resolution)
-        };
+        let viewport_size = UiUtils::viewport_size(entity, world);
 
         let parent_size = if let Some(parent) = world.get::<Parent>(entity) {
             let parent_id = parent.get();
@@ -182,6 +180,17 @@ impl UiUtils {
         }
     }
 
+    /// Returns the size of the viewport the entity is rendered to
+    ///
+    /// This will either be based on TargetCamera or the Primary Window.
+    pub fn viewport_size(entity: Entity, world: &World) -> Vec2 {
+        if let Some(render_target) = UiUtils::find_render_target(entity, world) {
+            UiUtils::render_target_size(render_target, world)
+        } else {
+            UiUtils::resolution_to_vec2(&UiUtils::get_primary_window(world).resolution)
+        }
+    }
+
     /// Finds a UI entity's render target by searching for the closest ancestor with a TargetCamera
     pub fn find_render_target(entity: Entity, world: &World) -> Option<RenderTarget> {
         let mut current_ancestor = entity;
@@ -252,6 +261,47 @@ impl UiUtils {
         world.get::<Window>(entity).unwrap()
     }
 
+    /// Checks if a logical screen position is over a node, respecting its clip rect
+    ///
+    /// Used to hit test pointers that are not tracked by `Interaction`, like secondary touches.
+    pub fn node_contains_point(
+        node: &Node,
+        transform: &GlobalTransform,
+        clip: Option<&CalculatedClip>,
+        point: Vec2,
+    ) -> bool {
+        let mut rect = node.logical_rect(transform);
+        if let Some(clip) = clip {
+            rect = rect.intersect(clip.clip);
+        }
+
+        rect.contains(point)
+    }
+
+    /// Decides which edges of an anchor a floating panel is aligned to, so it fits its container
+    ///
+    /// Panels open towards the end side of the layout direction, unless the anchor is closer to
+    /// the end side of the container. They open below the anchor, unless there is less than
+    /// `panel_height` space below it. The anchor is in container space.
+    ///
+    /// Returns whether the panel should be aligned to the right edge of the anchor and whether it
+    /// should be placed above it.
+    pub fn flip_to_fit(
+        anchor: Rect,
+        panel_height: f32,
+        container_size: Vec2,
+        layout_direction: LayoutDirection,
+    ) -> (bool, bool) {
+        let halfway_point = container_size / 2.;
+        let align_right = match layout_direction {
+            LayoutDirection::LeftToRight => anchor.min.x > halfway_point.x,
+            LayoutDirection::RightToLeft => anchor.max.x >= halfway_point.x,
+        };
+        let place_above = container_size.y - anchor.max.y < panel_height;
+
+        (align_right, place_above)
+    }
+
     /// Extracts width and height from a WindowResolution
     pub fn resolution_to_vec2(resolution: &WindowResolution) -> Vec2 {
         Vec2::new(resolution.width(), resolution.height())
@@ -15,8 +15,12 @@ fn main() {
             ..default()
         }))
         .add_plugins(SickleUiPlugin)
+        .add_plugins(ComponentThemePlugin::<StatusChip>::new())
         .add_systems(Startup, setup)
-        .add_systems(Update, on_theme_loaded)
+        .add_systems(
+            Update,
+            (on_theme_loaded, switch_language, switch_layout_direction),
+        )
         .run();
 }
 
@@ -29,6 +33,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(CustomMaterialTheme {
         handle: asset_server.load::<ThemeColors>("themes/material-theme.json"),
     });
+    commands.insert_resource(
+        Locale::new("en-US")
+            .with_catalog(asset_server.load("locales/en-US.ftl"))
+            .with_catalog(asset_server.load("locales/de.ftl")),
+    );
 
     let main_camera = commands
         .spawn((Camera3dBundle {
@@ -58,7 +67,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         |container| {
             container.floating_panel(
                 FloatingPanelConfig {
-                    title: Some("My Panel".into()),
+                    title: Some(LocalizedText::new("panel-title")),
                     ..default()
                 },
                 FloatingPanelLayout {
@@ -67,6 +76,13 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     droppable: false,
                 },
                 |panel| {
+                    panel.label(LabelConfig::from(
+                        LocalizedText::new("switch-language").with_arg("key", "L"),
+                    ));
+                    panel.label(LabelConfig::from(
+                        LocalizedText::new("switch-direction").with_arg("key", "R"),
+                    ));
+
                     panel.slider(SliderConfig {
                         label: Some("Slider".into()),
                         min: 0.,
@@ -74,9 +90,32 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         initial_value: 5.,
                         show_current: true,
                         axis: SliderAxis::Horizontal,
+                        ..default()
                     });
 
                     panel.radio_group(vec!["A", "B"], Some(0), false);
+
+                    for status in ["success", "warning", "info"] {
+                        let mut chip = panel
+                            .container((NodeBundle::default(), StatusChip::theme(status)), |_| {});
+                        let label = chip
+                            .label(LabelConfig::from(
+                                LocalizedText::new(format!("status-{}", status))
+                                    .with_fallback(status),
+                            ))
+                            .id();
+                        chip.insert(StatusChip { label });
+                    }
+
+                    panel
+                        .rich_text(
+                            "[b]Status:[/b] [color=success]ready[/color], \
+                            [color=warning]2 warnings[/color] - [link=details]show details[/link]",
+                        )
+                        .entity_commands()
+                        .observe(|trigger: Trigger<RichTextLinkClicked>| {
+                            info!("Link clicked: {}", trigger.event().link);
+                        });
                 },
             );
         },
@@ -99,3 +138,71 @@ fn on_theme_loaded(
         }
     }
 }
+
+fn switch_language(r_keys: Res<ButtonInput<KeyCode>>, mut locale: ResMut<Locale>) {
+    if r_keys.just_pressed(KeyCode::KeyL) {
+        let language = match locale.language() {
+            "de" => "en-US",
+            _ => "de",
+        };
+        locale.set_language(language);
+    }
+}
+
+fn switch_layout_direction(r_keys: Res<ButtonInput<KeyCode>>, mut theme_data: ResMut<ThemeData>) {
+    if r_keys.just_pressed(KeyCode::KeyR) {
+        theme_data.layout_direction = match theme_data.layout_direction {
+            LayoutDirection::LeftToRight => LayoutDirection::RightToLeft,
+            LayoutDirection::RightToLeft => LayoutDirection::LeftToRight,
+        };
+    }
+}
+
+/// A chip colored by one of the theme's extended colors
+#[derive(Component)]
+struct StatusChip {
+    label: Entity,
+}
+
+impl UiContext for StatusChip {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            StatusChip::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for StatusChip. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [StatusChip::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for StatusChip {}
+
+impl StatusChip {
+    const LABEL: &'static str = "Label";
+
+    fn theme(status: &'static str) -> Theme<StatusChip> {
+        let base_theme = PseudoTheme::custom(None, move |style_builder, theme_data| {
+            let theme_spacing = theme_data.spacing();
+            let colors = theme_data.colors();
+
+            style_builder
+                .margin(UiRect::top(Val::Px(theme_spacing.gaps.small)))
+                .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.medium)))
+                .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+                .border_color(colors.extended(status, Role::Color))
+                .background_color(colors.extended(status, Role::Container));
+
+            style_builder
+                .switch_target(StatusChip::LABEL)
+                .extended_font_color(status, Role::OnContainer);
+        });
+
+        Theme::new(vec![base_theme])
+    }
+}
@@ -65,7 +65,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                         row.column(|column| {
                             column
                                 .row(|row| {
-                                    let headline_font = theme_data.text.get(
+                                    let headline_font = theme_data.font(
                                         FontStyle::Headline,
                                         FontScale::Large,
                                         FontType::Regular,
@@ -89,7 +89,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                 })
                                 .style()
                                 .width(Val::Percent(100.0))
-                                .padding(UiRect::bottom(Val::Px(theme_data.spacing.gaps.large)));
+                                .padding(UiRect::bottom(Val::Px(theme_data.spacing().gaps.large)));
                             column
                                 .row(|row| {
                                     row.column(|column| {
@@ -104,7 +104,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column
                                                     .color_block(
@@ -115,7 +115,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column.color_block(
                                                     "Tertiary".into(),
@@ -138,7 +138,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column
                                                     .color_block(
@@ -149,7 +149,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column.color_block(
                                                     "On Tertiary".into(),
@@ -160,7 +160,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -177,7 +177,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column
                                                     .color_block(
@@ -190,7 +190,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column.color_block(
                                                     "Tertiary Container".into(),
@@ -215,7 +215,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column
                                                     .color_block(
@@ -228,7 +228,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                     )
                                                     .style()
                                                     .margin(UiRect::right(Val::Px(
-                                                        theme_data.spacing.gaps.small,
+                                                        theme_data.spacing().gaps.small,
                                                     )));
                                                 column.color_block(
                                                     "On Tertiary Container".into(),
@@ -239,7 +239,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.large,
+                                                theme_data.spacing().gaps.large,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -266,7 +266,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(20.0));
@@ -305,7 +305,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(20.0));
@@ -367,7 +367,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -394,7 +394,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.large,
+                                                theme_data.spacing().gaps.large,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -421,7 +421,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -436,7 +436,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             })
                                             .style()
                                             .padding(UiRect::bottom(Val::Px(
-                                                theme_data.spacing.gaps.small,
+                                                theme_data.spacing().gaps.small,
                                             )))
                                             .width(Val::Percent(100.0))
                                             .height(Val::Percent(10.0));
@@ -453,7 +453,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                                 )
                                                 .style()
                                                 .margin(UiRect::right(Val::Px(
-                                                    theme_data.spacing.gaps.small,
+                                                    theme_data.spacing().gaps.small,
                                                 )));
                                                 row.color_block(
                                                     "Shadow".into(),
@@ -470,7 +470,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                                             .height(Val::Percent(10.0));
                                     })
                                     .style()
-                                    .padding(UiRect::left(Val::Px(theme_data.spacing.gaps.large)))
+                                    .padding(UiRect::left(Val::Px(theme_data.spacing().gaps.large)))
                                     .width(Val::Percent(25.0))
                                     .height(Val::Percent(100.0));
                                 })
@@ -480,7 +480,7 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
                         })
                         .style()
                         .width(Val::Percent(100.0))
-                        .padding(UiRect::all(Val::Px(theme_data.spacing.gaps.large)));
+                        .padding(UiRect::all(Val::Px(theme_data.spacing().gaps.large)));
                     })
                     .style()
                     .width(Val::Percent(100.0))
@@ -492,7 +492,9 @@ fn setup(mut commands: Commands, theme_data: ResMut<ThemeData>, asset_server: Re
             .align_self(AlignSelf::Center)
             .align_items(AlignItems::FlexStart)
             .background_color(bevy::color::palettes::css::GHOST_WHITE.into())
-            .border_radius(BorderRadius::all(Val::Px(theme_data.spacing.corners.large)));
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_data.spacing().corners.large,
+            )));
         })
         .style()
         .width(Val::Percent(100.0))
@@ -553,11 +555,9 @@ impl ColorBlock {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, block: &ColorBlock, theme_data: &ThemeData) {
-        style_builder.padding(UiRect::all(Val::Px(theme_data.spacing.gaps.medium)));
+        style_builder.padding(UiRect::all(Val::Px(theme_data.spacing().gaps.medium)));
 
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Large, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Large, FontType::Regular);
 
         match block.material_background_color {
             Material3::Surface(surface) => {
@@ -4,7 +4,8 @@ use bevy::prelude::*;
 use ease::Ease;
 #[cfg(feature = "dev_panels")]
 use sickle_ui::dev_panels::{
-    hierarchy::{HierarchyTreeViewPlugin, UiHierarchyExt},
+    hierarchy::{HierarchyContainer, HierarchyTreeViewPlugin, UiHierarchyExt},
+    inspector::{EntityInspector, EntityInspectorPlugin, UiEntityInspectorExt},
     scene_view::{SceneView, SceneViewPlugin, SpawnSceneViewPreUpdate, UiSceneViewExt},
 };
 
@@ -30,6 +31,8 @@ fn main() {
     .add_plugins(OutlinedBlockPlugin)
     .add_plugins(TextureAtlasInteractionPlugin)
     .init_resource::<CurrentPage>()
+    .register_type::<EditorSettings>()
+    .init_resource::<EditorSettings>()
     .init_state::<Page>()
     .add_systems(Startup, setup.in_set(UiStartupSet))
     .add_systems(OnEnter(Page::Layout), layout_showcase)
@@ -44,6 +47,7 @@ fn main() {
             handle_theme_data_update,
             handle_theme_switch,
             handle_theme_contrast_select,
+            handle_theme_density_select,
         )
             .chain()
             .after(WidgetLibraryUpdate),
@@ -52,10 +56,12 @@ fn main() {
     #[cfg(feature = "dev_panels")]
     app.add_plugins(HierarchyTreeViewPlugin)
         .add_plugins(SceneViewPlugin)
+        .add_plugins(EntityInspectorPlugin)
         .add_systems(
             PreUpdate,
             (spawn_hierarchy_view, despawn_hierarchy_view).after(SpawnSceneViewPreUpdate),
-        );
+        )
+        .add_systems(Update, inspect_hierarchy_selection);
 
     app.run();
 }
@@ -92,7 +98,7 @@ impl UiFooterRootNode {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -153,7 +159,7 @@ impl OutlinedBlock {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -250,7 +256,7 @@ impl TextureAtlasInteraction {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -343,12 +349,31 @@ struct HierarchyPanel;
 #[reflect(Resource)]
 struct CurrentPage(Page);
 
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+struct EditorSettings {
+    snap_to_grid: bool,
+    grid_size: u32,
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self {
+            snap_to_grid: true,
+            grid_size: 8,
+        }
+    }
+}
+
 #[derive(Component, Debug)]
 pub struct ThemeSwitch;
 
 #[derive(Component, Debug)]
 pub struct ThemeContrastSelect;
 
+#[derive(Component, Debug)]
+pub struct ThemeDensitySelect;
+
 fn setup(mut commands: Commands) {
     // The main camera which will render UI
     let main_camera = commands
@@ -695,6 +720,11 @@ fn setup(mut commands: Commands) {
                     .insert(ThemeContrastSelect)
                     .style()
                     .width(Val::Px(150.));
+                extra
+                    .dropdown(vec!["Compact", "Default", "Comfortable", "Touch"], 1)
+                    .insert(ThemeDensitySelect)
+                    .style()
+                    .width(Val::Px(150.));
             });
         });
 
@@ -780,6 +810,20 @@ fn despawn_hierarchy_view(
     }
 }
 
+#[cfg(feature = "dev_panels")]
+fn inspect_hierarchy_selection(
+    q_hierarchies: Query<&HierarchyContainer, Changed<HierarchyContainer>>,
+    mut q_inspectors: Query<&mut EntityInspector>,
+) {
+    for hierarchy in &q_hierarchies {
+        for mut inspector in &mut q_inspectors {
+            if inspector.entity != hierarchy.selected() {
+                inspector.entity = hierarchy.selected();
+            }
+        }
+    }
+}
+
 fn layout_showcase(root_node: Query<Entity, With<ShowcaseContainer>>, mut commands: Commands) {
     let root_entity = root_node.single();
 
@@ -900,6 +944,41 @@ fn layout_showcase(root_node: Query<Entity, With<ShowcaseContainer>>, mut comman
                                     row.radio_group(vec!["Light", "Dark"], 1, false);
                                 });
 
+                                placeholder.column(|column| {
+                                    let all = column.checkbox(String::from("All layers"), false).id();
+                                    let layers: Vec<Entity> = ["Terrain", "Props", "Lights"]
+                                        .into_iter()
+                                        .map(|layer| {
+                                            column
+                                                .checkbox(String::from(layer), layer == "Props")
+                                                .style()
+                                                .margin(UiRect::left(Val::Px(16.)))
+                                                .id()
+                                        })
+                                        .collect();
+
+                                    column
+                                        .commands()
+                                        .entity(all)
+                                        .insert(CheckboxTree::new(layers));
+                                    column
+                                        .switch(String::from("Snap to grid"), true)
+                                        .insert(Bind::resource::<EditorSettings>("snap_to_grid"));
+                                    column
+                                        .slider(SliderConfig::horizontal(
+                                            String::from("Grid size"),
+                                            1.,
+                                            64.,
+                                            8.,
+                                            true,
+                                        ))
+                                        .insert((
+                                            Bind::resource::<EditorSettings>("grid_size"),
+                                            Validation::new(vec![Validator::range(4., 32.)])
+                                                .with_helper_text("Grid cell size in pixels"),
+                                        ));
+                                });
+
                                 placeholder.row(|row| {
                                     row.style().justify_content(JustifyContent::SpaceBetween);
                                     row.dropdown(
@@ -946,6 +1025,16 @@ fn layout_showcase(root_node: Query<Entity, With<ShowcaseContainer>>, mut comman
                                 });
                             });
 
+                            tab_container.add_tab("Inspector".into(), |panel| {
+                                #[cfg(feature = "dev_panels")]
+                                panel.entity_inspector(None);
+
+                                #[cfg(not(feature = "dev_panels"))]
+                                panel.label(
+                                    "Run with '--features=dev_panels'\nto inspect the selected entity here!",
+                                );
+                            });
+
                             tab_container.add_tab("Sliders".into(), |slider_tab| {
                                 slider_tab
                                     .row(|row| {
@@ -994,6 +1083,37 @@ fn layout_showcase(root_node: Query<Entity, With<ShowcaseContainer>>, mut comman
                                         row.slider(SliderConfig::horizontal(
                                             None, 0., 5., 2., false,
                                         ));
+                                        row.slider(
+                                            SliderConfig::horizontal(
+                                                String::from("Ticks"),
+                                                0.,
+                                                4.,
+                                                2.,
+                                                true,
+                                            )
+                                            .with_ticks([
+                                                (0., "Off"),
+                                                (1., "Low"),
+                                                (2., "Medium"),
+                                                (3., "High"),
+                                                (4., "Ultra"),
+                                            ])
+                                            .with_snap_to_ticks()
+                                            .with_precision(0),
+                                        );
+                                        row.range_slider(
+                                            RangeSliderConfig::horizontal(
+                                                String::from("Range"),
+                                                0.,
+                                                100.,
+                                                20.,
+                                                80.,
+                                                true,
+                                            )
+                                            .with_ticks([0., 25., 50., 75., 100.])
+                                            .with_step(1.)
+                                            .with_precision(0),
+                                        );
                                     })
                                     .style()
                                     .justify_content(JustifyContent::End)
@@ -1091,6 +1211,26 @@ fn handle_theme_contrast_select(
     }
 }
 
+fn handle_theme_density_select(
+    mut theme_data: ResMut<ThemeData>,
+    q_theme_density_select: Query<&Dropdown, (With<ThemeDensitySelect>, Changed<Dropdown>)>,
+) {
+    let Ok(theme_density_select) = q_theme_density_select.get_single() else {
+        return;
+    };
+
+    let density = match theme_density_select.value() {
+        Some(0) => UiDensity::Compact,
+        Some(2) => UiDensity::Comfortable,
+        Some(3) => UiDensity::Touch,
+        _ => UiDensity::Default,
+    };
+
+    if theme_data.density != density {
+        theme_data.density = density;
+    }
+}
+
 fn get_selected_scheme(
     theme_switch: &RadioGroup,
     theme_contrast_select: &Dropdown,
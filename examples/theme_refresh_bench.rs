@@ -0,0 +1,167 @@
+//! A headless benchmark of theme application, comparing refreshes with and without the `ThemeCache`.
+//!
+//! Run it in release mode: `cargo run --release --example theme_refresh_bench`
+use std::time::{Duration, Instant};
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+use sickle_ui::{
+    theme::{
+        pseudo_state::{PseudoState, PseudoStates},
+        theme_cache::ThemeCache,
+        theme_colors::{Accent, Surface},
+        theme_data::{Contrast, Scheme, ThemeData},
+        ComponentThemePlugin, DefaultTheme, PseudoTheme, Theme, ThemePlugin, UiContext,
+    },
+    ui_style::prelude::*,
+};
+
+const NODE_COUNT: usize = 5000;
+const SCHEME_SWITCHES: u32 = 10;
+
+fn main() {
+    for enabled in [false, true] {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), ThemePlugin))
+            .add_plugins(ComponentThemePlugin::<BenchBox>::new());
+        app.world_mut()
+            .resource_mut::<ThemeCache>()
+            .set_enabled(enabled);
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        app.world_mut().run_system_once(spawn_boxes);
+        let spawn = time_update(&mut app);
+
+        let mut switches = Duration::ZERO;
+        for _ in 0..SCHEME_SWITCHES {
+            let mut theme_data = app.world_mut().resource_mut::<ThemeData>();
+            theme_data.active_scheme = match theme_data.active_scheme.is_dark() {
+                true => Scheme::Light(Contrast::Standard),
+                false => Scheme::Dark(Contrast::Standard),
+            };
+            switches += time_update(&mut app);
+        }
+
+        println!(
+            "{} nodes, cache {}: spawn {:.2?}, scheme switch {:.2?} (cached themes: {})",
+            NODE_COUNT,
+            match enabled {
+                true => "enabled ",
+                false => "disabled",
+            },
+            spawn,
+            switches / SCHEME_SWITCHES,
+            app.world().resource::<ThemeCache>().len(),
+        );
+    }
+}
+
+fn time_update(app: &mut App) -> Duration {
+    let start = Instant::now();
+    app.update();
+    start.elapsed()
+}
+
+fn spawn_boxes(mut commands: Commands) {
+    let root = commands.spawn(NodeBundle::default()).id();
+    let panels = [
+        root,
+        commands
+            .spawn((NodeBundle::default(), BenchBox::panel_theme()))
+            .set_parent(root)
+            .id(),
+    ];
+
+    for i in 0..NODE_COUNT {
+        let inner = commands.spawn(NodeBundle::default()).id();
+        let mut pseudo_states = PseudoStates::new();
+        if i % 3 == 0 {
+            pseudo_states.add(PseudoState::Selected);
+        }
+
+        commands
+            .spawn((NodeBundle::default(), BenchBox { inner }, pseudo_states))
+            .set_parent(panels[i % panels.len()])
+            .add_child(inner);
+    }
+}
+
+#[derive(Component)]
+pub struct BenchBox {
+    inner: Entity,
+}
+
+impl UiContext for BenchBox {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            BenchBox::INNER => Ok(self.inner),
+            _ => Err(format!(
+                "{} doesn't exist for BenchBox. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [BenchBox::INNER].into_iter()
+    }
+}
+
+impl DefaultTheme for BenchBox {
+    fn default_theme() -> Option<Theme<BenchBox>> {
+        BenchBox::theme().into()
+    }
+}
+
+impl BenchBox {
+    pub const INNER: &'static str = "Inner";
+
+    fn theme() -> Theme<BenchBox> {
+        let base_theme = PseudoTheme::deferred(None, BenchBox::primary_style);
+        let selected_theme =
+            PseudoTheme::deferred(vec![PseudoState::Selected], BenchBox::selected_style);
+        Theme::new(vec![base_theme, selected_theme])
+    }
+
+    fn panel_theme() -> Theme<BenchBox> {
+        let base_theme = PseudoTheme::deferred(None, BenchBox::panel_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Px(theme_spacing.areas.medium))
+            .height(Val::Px(theme_spacing.areas.medium))
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::OutlineVariant))
+            .background_color(colors.surface(Surface::Surface));
+
+        style_builder
+            .switch_placement(BenchBox::INNER)
+            .width(Val::Percent(100.))
+            .height(Val::Percent(100.))
+            .background_color(colors.accent(Accent::Primary));
+    }
+
+    fn selected_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .border_color(colors.accent(Accent::Primary))
+            .switch_placement(BenchBox::INNER)
+            .background_color(colors.accent(Accent::Secondary));
+    }
+
+    fn panel_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.background_color(colors.surface(Surface::SurfaceVariant));
+    }
+}
@@ -1,3 +1,4 @@
 pub mod entity_component_list;
 pub mod hierarchy;
+pub mod inspector;
 pub mod scene_view;
@@ -137,11 +137,9 @@ impl EntityComponentTag {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
         style_builder
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
@@ -174,7 +172,7 @@ impl UiEntityComponentTagExt for UiBuilder<'_, Entity> {
     fn entity_component_tag(&mut self, label: String) -> UiBuilder<Entity> {
         let mut tag = EntityComponentTag::default();
         let mut widget = self.container(EntityComponentTag::frame(), |container| {
-            tag.label = container.label(LabelConfig { label, ..default() }).id();
+            tag.label = container.label(LabelConfig::from(label)).id();
         });
 
         widget.insert(tag);
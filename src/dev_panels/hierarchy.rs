@@ -247,6 +247,11 @@ pub struct HierarchyContainer {
 }
 
 impl HierarchyContainer {
+    /// The entity of the selected hierarchy node
+    pub fn selected(&self) -> Option<Entity> {
+        self.selected
+    }
+
     pub fn foldable_theme() -> Theme<Foldable> {
         let base_theme = PseudoTheme::deferred(None, HierarchyContainer::foldable_style);
         let selected_theme = PseudoTheme::deferred(
@@ -264,7 +269,7 @@ impl HierarchyContainer {
     }
 
     fn refresh_button_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
 
         style_builder
             .border(UiRect::bottom(Val::Px(theme_spacing.borders.extra_small)))
@@ -273,7 +278,7 @@ impl HierarchyContainer {
     }
 
     fn foldable_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder.background_color(Color::NONE);
@@ -0,0 +1,1141 @@
+use bevy::{
+    ecs::{
+        archetype::ArchetypeId,
+        component::{ComponentId, Tick},
+        world::CommandQueue,
+    },
+    prelude::*,
+    reflect::{
+        DynamicEnum, DynamicVariant, Enum, ReflectMut, ReflectRef, TypeInfo, TypeRegistry,
+        VariantInfo,
+    },
+};
+
+use crate::{
+    prelude::*,
+    widgets::inputs::numeric_field::{read_number, write_number, NumberKind},
+};
+
+/// Longest debug representation shown for read-only values and map keys
+const MAX_DEBUG_LENGTH: usize = 48;
+
+pub struct EntityInspectorPlugin;
+
+impl Plugin for EntityInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<InspectorRow>::default())
+            .add_systems(
+                Update,
+                (
+                    write_inspector_numbers,
+                    write_inspector_bools,
+                    write_inspector_variants,
+                    write_inspector_vectors,
+                    handle_inspector_list_buttons,
+                    update_entity_inspectors,
+                )
+                    .chain()
+                    .after(FluxInteractionUpdate)
+                    .after(NumericFieldUpdate),
+            );
+    }
+}
+
+fn write_inspector_numbers(
+    q_fields: Query<(Ref<NumericField>, &InspectorField), Changed<NumericField>>,
+    mut commands: Commands,
+) {
+    for (input, field) in &q_fields {
+        // Fields are spawned with the reflected value, only edits are written back
+        if input.is_added() || input.is_editing() {
+            continue;
+        }
+
+        let value = input.value();
+        let field = field.clone();
+        commands.add(move |world: &mut World| {
+            field.modify(world, |target, _| {
+                let Some((current, kind)) = read_number(target) else {
+                    return false;
+                };
+                // Unedited values keep their precision, even if the field can't show it
+                if current as f32 == value || !kind.is_exact(current) {
+                    return false;
+                }
+
+                write_number(target, value as f64)
+            });
+        });
+    }
+}
+
+fn write_inspector_bools(
+    q_checkboxes: Query<(Ref<Checkbox>, &InspectorField), Changed<Checkbox>>,
+    mut commands: Commands,
+) {
+    for (checkbox, field) in &q_checkboxes {
+        if checkbox.is_added() {
+            continue;
+        }
+
+        field.write(Box::new(checkbox.checked), &mut commands);
+    }
+}
+
+fn write_inspector_variants(
+    q_dropdowns: Query<(Ref<Dropdown>, &InspectorVariants, &InspectorField), Changed<Dropdown>>,
+    mut commands: Commands,
+) {
+    for (dropdown, variants, field) in &q_dropdowns {
+        if dropdown.is_added() {
+            continue;
+        }
+
+        let Some(variant) = dropdown
+            .value()
+            .and_then(|index| variants.0.get(index))
+            .cloned()
+        else {
+            continue;
+        };
+
+        let field = field.clone();
+        commands.add(move |world: &mut World| {
+            let changed = field.modify(world, |target, _| {
+                let ReflectRef::Enum(current) = target.reflect_ref() else {
+                    return false;
+                };
+                if current.variant_name() == variant {
+                    return false;
+                }
+
+                target
+                    .try_apply(&DynamicEnum::new(variant, DynamicVariant::Unit))
+                    .is_ok()
+            });
+
+            // The fields of the new variant need their own editors
+            if changed {
+                field.rebuild_inspector(world);
+            }
+        });
+    }
+}
+
+fn write_inspector_vectors(
+    q_vector_fields: Query<(Ref<VectorField>, &InspectorField), Changed<VectorField>>,
+    mut commands: Commands,
+) {
+    for (vector_field, field) in &q_vector_fields {
+        if vector_field.is_added() {
+            continue;
+        }
+
+        let value: Box<dyn Reflect> = match vector_field.kind() {
+            VectorFieldKind::Vec2 => Box::new(vector_field.vec2()),
+            VectorFieldKind::Vec3 => Box::new(vector_field.vec3()),
+            VectorFieldKind::QuatEuler => Box::new(vector_field.quat()),
+            VectorFieldKind::Color => Box::new(vector_field.color()),
+        };
+        let euler = vector_field.euler();
+        let field = field.clone();
+        commands.add(move |world: &mut World| {
+            field.modify(world, |target, _| {
+                // Rotations and colors are compared as edited, converting them back and forth
+                // would otherwise write values that weren't touched
+                let unchanged = if let Some(rotation) = target.downcast_ref::<Quat>() {
+                    VectorField::euler_degrees(*rotation) == euler
+                } else if let Some(color) = target.downcast_ref::<Color>() {
+                    value
+                        .downcast_ref::<Color>()
+                        .is_some_and(|value| value.to_srgba() == color.to_srgba())
+                } else {
+                    target.reflect_partial_eq(&*value) == Some(true)
+                };
+                if unchanged {
+                    return false;
+                }
+
+                target.try_apply(&*value).is_ok()
+            });
+        });
+    }
+}
+
+fn handle_inspector_list_buttons(
+    q_buttons: Query<
+        (&InspectorListButton, &InspectorField, &FluxInteraction),
+        Changed<FluxInteraction>,
+    >,
+    mut commands: Commands,
+) {
+    for (button, field, interaction) in &q_buttons {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+
+        let action = *button;
+        let field = field.clone();
+        commands.add(move |world: &mut World| {
+            let changed = field.modify(world, |target, registry| {
+                let element = match action {
+                    InspectorListButton::Push => new_list_element(target, registry),
+                    InspectorListButton::Remove(_) => None,
+                };
+
+                match (target.reflect_mut(), action) {
+                    (ReflectMut::List(list), InspectorListButton::Push) => {
+                        let Some(element) = element else {
+                            return false;
+                        };
+
+                        list.push(element);
+                        true
+                    }
+                    (ReflectMut::List(list), InspectorListButton::Remove(index)) => {
+                        if index >= list.len() {
+                            return false;
+                        }
+
+                        list.remove(index);
+                        true
+                    }
+                    (ReflectMut::Map(map), InspectorListButton::Remove(index)) => {
+                        let Some(key) = map.get_at(index).map(|(key, _)| key.clone_value()) else {
+                            return false;
+                        };
+
+                        map.remove(&*key).is_some()
+                    }
+                    _ => false,
+                }
+            });
+
+            if changed {
+                field.rebuild_inspector(world);
+            }
+        });
+    }
+}
+
+/// A copy of the last element, or the default value of the element type for empty lists
+fn new_list_element(list: &dyn Reflect, registry: &TypeRegistry) -> Option<Box<dyn Reflect>> {
+    let ReflectRef::List(elements) = list.reflect_ref() else {
+        return None;
+    };
+    if let Some(last) = elements.get(elements.len().checked_sub(1)?) {
+        return last.clone_value().into();
+    }
+
+    let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
+        return None;
+    };
+
+    registry
+        .get_type_data::<ReflectDefault>(info.item_type_id())
+        .map(|reflect_default| reflect_default.default())
+}
+
+/// Rebuilds inspectors when the inspected entity or its set of components changes,
+/// otherwise refreshes the editors of changed components
+fn update_entity_inspectors(world: &mut World) {
+    let inspectors: Vec<(Entity, Option<Entity>, bool, Option<InspectedArchetype>)> = world
+        .query::<(Entity, Ref<EntityInspector>, Option<&InspectedArchetype>)>()
+        .iter(world)
+        .map(|(entity, inspector, archetype)| {
+            (
+                entity,
+                inspector.entity,
+                inspector.is_changed(),
+                archetype.copied(),
+            )
+        })
+        .collect();
+
+    for (container, inspected_entity, changed, archetype) in inspectors {
+        let current_archetype = InspectedArchetype(
+            inspected_entity
+                .and_then(|entity| world.get_entity(entity))
+                .map(|entity_ref| entity_ref.archetype().id()),
+        );
+
+        if changed
+            || archetype != Some(current_archetype)
+            || !EntityInspector::refresh_fields(container, world)
+        {
+            update_entity_inspector(container, inspected_entity, world);
+            world.entity_mut(container).insert(current_archetype);
+        }
+    }
+}
+
+fn update_entity_inspector(container: Entity, inspected_entity: Option<Entity>, world: &mut World) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, world);
+
+    commands.entity(container).despawn_descendants();
+
+    if let Some(entity_ref) = inspected_entity.and_then(|entity| world.get_entity(entity)) {
+        let mut builder = commands.ui_builder(container);
+
+        for component_info in world.inspect_entity(entity_ref.id()) {
+            let name = UiUtils::simplify_component_name(component_info);
+            let component = component_info
+                .type_id()
+                .and_then(|type_id| registry.get(type_id))
+                .and_then(|registration| {
+                    let reflect_component = registration.data::<ReflectComponent>()?;
+                    let value = reflect_component.reflect(entity_ref)?;
+
+                    Some((registration.type_info().type_path(), value))
+                });
+
+            let Some((type_path, value)) = component else {
+                InspectorRow::spawn(&mut builder, name, |row| {
+                    row.label(LabelConfig {
+                        label: "Not reflected".into(),
+                        ..default()
+                    });
+                });
+                continue;
+            };
+
+            let field = InspectorField {
+                inspector: container,
+                entity: entity_ref.id(),
+                component: type_path.into(),
+                path: Vec::new(),
+            };
+            let empty = match value.reflect_ref() {
+                ReflectRef::Struct(value) => value.field_len() == 0,
+                ReflectRef::TupleStruct(value) => value.field_len() == 0,
+                _ => false,
+            };
+
+            builder.foldable(name, false, empty, |container| {
+                EntityInspector::build_fields(container, value, field, &registry);
+            });
+        }
+    }
+
+    drop(registry);
+    queue.apply(world);
+}
+
+impl NumberKind {
+    /// Checks if the value can be shown and edited without rounding it
+    fn is_exact(&self, value: f64) -> bool {
+        match self {
+            NumberKind::Float => true,
+            _ => value as f32 as f64 == value,
+        }
+    }
+
+    fn config(&self, value: f64) -> NumericFieldConfig {
+        let config = NumericFieldConfig::new(value as f32);
+
+        match self {
+            NumberKind::Float => config.unclamped().with_precision(3),
+            NumberKind::Signed => config.unclamped().with_step(1.).with_precision(0),
+            NumberKind::Unsigned => NumericFieldConfig {
+                min: Some(0.),
+                ..config.with_step(1.).with_precision(0)
+            },
+        }
+    }
+}
+
+/// Builds a custom editor for a reflected type in the [`EntityInspector`]
+///
+/// Register it with `#[reflect(InspectorEditor)]` on the type, or with
+/// `app.register_type_data::<T, ReflectInspectorEditor>()`.
+#[reflect_trait]
+pub trait InspectorEditor {
+    /// Spawns the editor of `self` next to its label.
+    ///
+    /// Changes should be written back with [`InspectorField::write`].
+    fn build_inspector_editor(&self, field: InspectorField, builder: &mut UiBuilder<Entity>);
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum InspectorPathSegment {
+    /// A named field of a struct or struct variant
+    Field(String),
+    /// A field of a tuple, tuple struct or tuple variant, or an element of a list or array
+    Index(usize),
+    /// The value of the map entry at the index
+    MapValue(usize),
+}
+
+/// Locates an edited value: a path into a reflected component of an entity
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct InspectorField {
+    inspector: Entity,
+    entity: Entity,
+    component: String,
+    path: Vec<InspectorPathSegment>,
+}
+
+impl Default for InspectorField {
+    fn default() -> Self {
+        Self {
+            inspector: Entity::PLACEHOLDER,
+            entity: Entity::PLACEHOLDER,
+            component: "".into(),
+            path: Vec::new(),
+        }
+    }
+}
+
+impl InspectorField {
+    /// The inspected entity
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Type path of the edited component
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    pub fn path(&self) -> &[InspectorPathSegment] {
+        &self.path
+    }
+
+    /// Queues applying `value` to the field through reflection.
+    /// Nothing is written if the field already holds an equal value.
+    pub fn write(&self, value: Box<dyn Reflect>, commands: &mut Commands) {
+        let field = self.clone();
+        commands.add(move |world: &mut World| {
+            field.modify(world, |target, _| {
+                if target.reflect_partial_eq(&*value) == Some(true) {
+                    return false;
+                }
+
+                target.try_apply(&*value).is_ok()
+            });
+        });
+    }
+
+    fn child(&self, segment: InspectorPathSegment) -> Self {
+        let mut field = self.clone();
+        field.path.push(segment);
+
+        field
+    }
+
+    /// Calls `modify` with the field, marking the component changed if it returns `true`
+    fn modify(
+        &self,
+        world: &mut World,
+        modify: impl FnOnce(&mut dyn Reflect, &TypeRegistry) -> bool,
+    ) -> bool {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let Some(reflect_component) = registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            return false;
+        };
+        let Some(mut entity) = world.get_entity_mut(self.entity) else {
+            return false;
+        };
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+            return false;
+        };
+        let Some(target) = InspectorField::resolve(component.bypass_change_detection(), &self.path)
+        else {
+            return false;
+        };
+
+        let changed = modify(target, &registry);
+        if changed {
+            component.set_changed();
+        }
+
+        changed
+    }
+
+    /// Id of the edited component, if it is registered in the world
+    fn component_id(&self, world: &World) -> Option<ComponentId> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let type_id = registry.get_with_type_path(&self.component)?.type_id();
+
+        world.components().get_id(type_id)
+    }
+
+    /// Calls `read` with the current value of the field
+    fn read<T>(&self, world: &World, read: impl FnOnce(&dyn Reflect) -> Option<T>) -> Option<T> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let reflect_component = registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())?;
+        let component = reflect_component.reflect(world.get_entity(self.entity)?)?;
+
+        read(InspectorField::resolve_ref(component, &self.path)?)
+    }
+
+    fn resolve_ref<'a>(
+        value: &'a dyn Reflect,
+        path: &[InspectorPathSegment],
+    ) -> Option<&'a dyn Reflect> {
+        let Some((segment, rest)) = path.split_first() else {
+            return Some(value);
+        };
+
+        let next = match (value.reflect_ref(), segment) {
+            (ReflectRef::Struct(value), InspectorPathSegment::Field(name)) => value.field(name)?,
+            (ReflectRef::TupleStruct(value), InspectorPathSegment::Index(index)) => {
+                value.field(*index)?
+            }
+            (ReflectRef::Tuple(value), InspectorPathSegment::Index(index)) => {
+                value.field(*index)?
+            }
+            (ReflectRef::List(value), InspectorPathSegment::Index(index)) => value.get(*index)?,
+            (ReflectRef::Array(value), InspectorPathSegment::Index(index)) => value.get(*index)?,
+            (ReflectRef::Map(value), InspectorPathSegment::MapValue(index)) => {
+                value.get_at(*index)?.1
+            }
+            (ReflectRef::Enum(value), InspectorPathSegment::Field(name)) => value.field(name)?,
+            (ReflectRef::Enum(value), InspectorPathSegment::Index(index)) => {
+                value.field_at(*index)?
+            }
+            _ => return None,
+        };
+
+        InspectorField::resolve_ref(next, rest)
+    }
+
+    fn resolve<'a>(
+        value: &'a mut dyn Reflect,
+        path: &[InspectorPathSegment],
+    ) -> Option<&'a mut dyn Reflect> {
+        let Some((segment, rest)) = path.split_first() else {
+            return Some(value);
+        };
+
+        let next = match (value.reflect_mut(), segment) {
+            (ReflectMut::Struct(value), InspectorPathSegment::Field(name)) => {
+                value.field_mut(name)?
+            }
+            (ReflectMut::TupleStruct(value), InspectorPathSegment::Index(index)) => {
+                value.field_mut(*index)?
+            }
+            (ReflectMut::Tuple(value), InspectorPathSegment::Index(index)) => {
+                value.field_mut(*index)?
+            }
+            (ReflectMut::List(value), InspectorPathSegment::Index(index)) => {
+                value.get_mut(*index)?
+            }
+            (ReflectMut::Array(value), InspectorPathSegment::Index(index)) => {
+                value.get_mut(*index)?
+            }
+            (ReflectMut::Map(value), InspectorPathSegment::MapValue(index)) => {
+                value.get_at_mut(*index)?.1
+            }
+            (ReflectMut::Enum(value), InspectorPathSegment::Field(name)) => {
+                value.field_mut(name)?
+            }
+            (ReflectMut::Enum(value), InspectorPathSegment::Index(index)) => {
+                value.field_at_mut(*index)?
+            }
+            _ => return None,
+        };
+
+        InspectorField::resolve(next, rest)
+    }
+
+    fn rebuild_inspector(&self, world: &mut World) {
+        if let Some(mut inspector) = world.get_mut::<EntityInspector>(self.inspector) {
+            inspector.set_changed();
+        }
+    }
+}
+
+/// Holds the variant names of the enum edited by a dropdown, in option order
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct InspectorVariants(Vec<String>);
+
+/// Holds the number of entries of the list or map shown by a foldable
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct InspectorLength(usize);
+
+/// The archetype of the inspected entity the editors were built for. It changes when
+/// components are added to or removed from the entity.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+struct InspectedArchetype(Option<ArchetypeId>);
+
+/// The change tick of the edited component when the editor last showed its value
+#[derive(Component, Clone, Copy, Debug)]
+struct InspectorFieldTick {
+    component: ComponentId,
+    changed: Tick,
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub enum InspectorListButton {
+    Push,
+    Remove(usize),
+}
+
+/// A labeled editor of a single value
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct InspectorRow {
+    label: Entity,
+}
+
+impl Default for InspectorRow {
+    fn default() -> Self {
+        Self {
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for InspectorRow {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            InspectorRow::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for InspectorRow. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [InspectorRow::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for InspectorRow {
+    fn default_theme() -> Option<Theme<InspectorRow>> {
+        InspectorRow::theme().into()
+    }
+}
+
+impl InspectorRow {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn theme() -> Theme<InspectorRow> {
+        let base_theme = PseudoTheme::deferred(None, InspectorRow::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Small, FontType::Regular);
+
+        style_builder
+            .width(Val::Percent(100.))
+            .align_items(AlignItems::Center)
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.tiny),
+            ));
+
+        style_builder
+            .switch_target(InspectorRow::LABEL)
+            .flex_shrink(0.)
+            .min_width(Val::Px(theme_spacing.areas.extra_large))
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)))
+            .sized_font(font)
+            .font_color(colors.on(OnColor::SurfaceVariant));
+    }
+
+    fn frame(name: &str) -> impl Bundle {
+        (
+            Name::new(format!("Inspector Row [{}]", name)),
+            NodeBundle::default(),
+        )
+    }
+
+    fn spawn(
+        builder: &mut UiBuilder<Entity>,
+        name: impl Into<String>,
+        spawn_editor: impl FnOnce(&mut UiBuilder<Entity>),
+    ) {
+        let name = name.into();
+        builder.container(InspectorRow::frame(&name), |row| {
+            let label = row.label(LabelConfig {
+                label: name.into(),
+                ..default()
+            });
+            let label = label.id();
+
+            spawn_editor(row);
+            row.insert(InspectorRow { label });
+        });
+    }
+}
+
+/// Lists the reflected components of an entity with editors for their fields
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct EntityInspector {
+    pub entity: Option<Entity>,
+}
+
+impl EntityInspector {
+    /// Shows the current values of changed components in the built-in editors
+    ///
+    /// Only editors whose component changed since they were last refreshed are updated, fields
+    /// being typed in are left alone. Returns `false` if the editors no longer match the shape
+    /// of the values and need to be rebuilt.
+    fn refresh_fields(container: Entity, world: &mut World) -> bool {
+        let fields: Vec<(Entity, InspectorField, Option<InspectorFieldTick>)> = world
+            .query::<(Entity, &InspectorField, Option<&InspectorFieldTick>)>()
+            .iter(world)
+            .filter(|(_, field, _)| field.inspector == container)
+            .map(|(entity, field, tick)| (entity, field.clone(), tick.copied()))
+            .collect();
+
+        for (editor, field, synced) in fields {
+            let Some(component) = synced
+                .map(|synced| synced.component)
+                .or_else(|| field.component_id(world))
+            else {
+                continue;
+            };
+            let Some(changed) = world
+                .get_entity(field.entity)
+                .and_then(|entity_ref| entity_ref.get_change_ticks_by_id(component))
+                .map(|ticks| ticks.last_changed_tick())
+            else {
+                return false;
+            };
+            let editing = world
+                .get::<NumericField>(editor)
+                .is_some_and(|input| input.is_editing() || input.is_scrubbing());
+            if editing || synced.is_some_and(|synced| synced.changed == changed) {
+                continue;
+            }
+
+            world
+                .entity_mut(editor)
+                .insert(InspectorFieldTick { component, changed });
+            if !EntityInspector::refresh_field(editor, &field, world) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Shows the current value of the field in its editor, returns `false` if the editor
+    /// needs to be rebuilt
+    fn refresh_field(editor: Entity, field: &InspectorField, world: &mut World) -> bool {
+        if world.get::<NumericField>(editor).is_some() {
+            let Some((number, kind)) = field.read(world, read_number) else {
+                return false;
+            };
+            if !kind.is_exact(number) {
+                return false;
+            }
+
+            let mut input = world.get_mut::<NumericField>(editor).unwrap();
+            if input.value() != input.config().constrain(number as f32) {
+                input.set_value(number as f32);
+            }
+        } else if world.get::<Checkbox>(editor).is_some() {
+            let Some(checked) = field.read(world, |value| value.downcast_ref::<bool>().copied())
+            else {
+                return false;
+            };
+            let mut checkbox = world.get_mut::<Checkbox>(editor).unwrap();
+            if checkbox.checked != checked {
+                checkbox.checked = checked;
+            }
+        } else if let Some(variants) = world.get::<InspectorVariants>(editor) {
+            let Some(variant) = field.read(world, |value| match value.reflect_ref() {
+                ReflectRef::Enum(value) => value.variant_name().to_string().into(),
+                _ => None,
+            }) else {
+                return false;
+            };
+            let selected = variants.0.iter().position(|name| *name == variant);
+            if world
+                .get::<Dropdown>(editor)
+                .map(|dropdown| dropdown.value())
+                != Some(selected)
+            {
+                return false;
+            }
+        } else if let Some(kind) = world.get::<VectorField>(editor).map(|field| field.kind()) {
+            let refreshed = match kind {
+                VectorFieldKind::Vec2 => field
+                    .read(world, |value| value.downcast_ref::<Vec2>().copied())
+                    .map(|value| {
+                        let mut vector_field = world.get_mut::<VectorField>(editor).unwrap();
+                        if vector_field.vec2() != value {
+                            vector_field.set_vec2(value);
+                        }
+                    }),
+                VectorFieldKind::Vec3 => field
+                    .read(world, |value| value.downcast_ref::<Vec3>().copied())
+                    .map(|value| {
+                        let mut vector_field = world.get_mut::<VectorField>(editor).unwrap();
+                        if vector_field.vec3() != value {
+                            vector_field.set_vec3(value);
+                        }
+                    }),
+                VectorFieldKind::QuatEuler => field
+                    .read(world, |value| value.downcast_ref::<Quat>().copied())
+                    .map(|value| {
+                        let mut vector_field = world.get_mut::<VectorField>(editor).unwrap();
+                        if vector_field.euler() != VectorField::euler_degrees(value) {
+                            vector_field.set_quat(value);
+                        }
+                    }),
+                VectorFieldKind::Color => field
+                    .read(world, |value| value.downcast_ref::<Color>().copied())
+                    .map(|value| {
+                        let mut vector_field = world.get_mut::<VectorField>(editor).unwrap();
+                        if vector_field.color().to_srgba() != value.to_srgba() {
+                            vector_field.set_color(value);
+                        }
+                    }),
+            };
+            if refreshed.is_none() {
+                return false;
+            }
+        } else if let Some(length) = world.get::<InspectorLength>(editor).map(|length| length.0) {
+            let current = field.read(world, |value| match value.reflect_ref() {
+                ReflectRef::List(list) => list.len().into(),
+                ReflectRef::Map(map) => map.len().into(),
+                _ => None,
+            });
+            if current != Some(length) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Spawns editors for the fields of structs and tuples, or a single editor for other values
+    fn build_fields(
+        builder: &mut UiBuilder<Entity>,
+        value: &dyn Reflect,
+        field: InspectorField,
+        registry: &TypeRegistry,
+    ) {
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                for (index, field_value) in value.iter_fields().enumerate() {
+                    let name = value.name_at(index).unwrap_or_default();
+                    EntityInspector::build_value(
+                        builder,
+                        name,
+                        field_value,
+                        field.child(InspectorPathSegment::Field(name.into())),
+                        registry,
+                    );
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                for (index, field_value) in value.iter_fields().enumerate() {
+                    EntityInspector::build_value(
+                        builder,
+                        &index.to_string(),
+                        field_value,
+                        field.child(InspectorPathSegment::Index(index)),
+                        registry,
+                    );
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                for (index, field_value) in value.iter_fields().enumerate() {
+                    EntityInspector::build_value(
+                        builder,
+                        &index.to_string(),
+                        field_value,
+                        field.child(InspectorPathSegment::Index(index)),
+                        registry,
+                    );
+                }
+            }
+            _ => EntityInspector::build_value(builder, "Value", value, field, registry),
+        }
+    }
+
+    fn build_value(
+        builder: &mut UiBuilder<Entity>,
+        name: &str,
+        value: &dyn Reflect,
+        field: InspectorField,
+        registry: &TypeRegistry,
+    ) {
+        let custom_editor = value
+            .get_represented_type_info()
+            .and_then(|info| registry.get_type_data::<ReflectInspectorEditor>(info.type_id()))
+            .and_then(|reflect_editor| reflect_editor.get(value));
+        if let Some(editor) = custom_editor {
+            InspectorRow::spawn(builder, name, |row| {
+                editor.build_inspector_editor(field, row);
+            });
+            return;
+        }
+
+        if let Some(checked) = value.downcast_ref::<bool>() {
+            InspectorRow::spawn(builder, name, |row| {
+                row.checkbox(None, *checked).insert(field);
+            });
+        } else if let Some((number, kind)) = read_number(value) {
+            if !kind.is_exact(number) {
+                EntityInspector::build_read_only(builder, name, number.to_string());
+                return;
+            }
+
+            InspectorRow::spawn(builder, name, |row| {
+                row.numeric_field(kind.config(number)).insert(field);
+            });
+        } else if let Some(color) = value.downcast_ref::<Color>() {
+            InspectorRow::spawn(builder, name, |row| {
+                row.color_field(*color, default()).insert(field);
+            });
+        } else if let Some(vector) = value.downcast_ref::<Vec2>() {
+            InspectorRow::spawn(builder, name, |row| {
+                row.vec2_field(*vector, default()).insert(field);
+            });
+        } else if let Some(vector) = value.downcast_ref::<Vec3>() {
+            InspectorRow::spawn(builder, name, |row| {
+                row.vec3_field(*vector, default()).insert(field);
+            });
+        } else if let Some(rotation) = value.downcast_ref::<Quat>() {
+            InspectorRow::spawn(builder, name, |row| {
+                row.quat_euler_field(*rotation, default()).insert(field);
+            });
+        } else if let Some(text) = value.downcast_ref::<String>() {
+            EntityInspector::build_read_only(builder, name, text.clone());
+        } else {
+            match value.reflect_ref() {
+                ReflectRef::Struct(fields) => {
+                    builder.foldable(name, true, fields.field_len() == 0, |container| {
+                        EntityInspector::build_fields(container, value, field, registry);
+                    });
+                }
+                // Newtypes are edited as their inner value
+                ReflectRef::TupleStruct(fields) if fields.field_len() == 1 => {
+                    if let Some(inner) = fields.field(0) {
+                        let field = field.child(InspectorPathSegment::Index(0));
+                        EntityInspector::build_value(builder, name, inner, field, registry);
+                    }
+                }
+                ReflectRef::TupleStruct(fields) => {
+                    builder.foldable(name, true, fields.field_len() == 0, |container| {
+                        EntityInspector::build_fields(container, value, field, registry);
+                    });
+                }
+                ReflectRef::Tuple(fields) => {
+                    builder.foldable(name, true, fields.field_len() == 0, |container| {
+                        EntityInspector::build_fields(container, value, field, registry);
+                    });
+                }
+                ReflectRef::List(list) => {
+                    let label = format!("{} [{}]", name, list.len());
+                    let length = (InspectorLength(list.len()), field.clone());
+                    builder
+                        .foldable(label, true, false, |container| {
+                            for (index, element) in list.iter().enumerate() {
+                                EntityInspector::build_element(
+                                    container, index, element, &field, registry,
+                                );
+                            }
+
+                            container
+                                .menu_item(MenuItemConfig {
+                                    name: "Add".into(),
+                                    ..default()
+                                })
+                                .insert((InspectorListButton::Push, field));
+                        })
+                        .insert(length);
+                }
+                ReflectRef::Array(array) => {
+                    let label = format!("{} [{}]", name, array.len());
+                    builder.foldable(label, true, array.is_empty(), |container| {
+                        for (index, element) in array.iter().enumerate() {
+                            EntityInspector::build_value(
+                                container,
+                                &format!("[{}]", index),
+                                element,
+                                field.child(InspectorPathSegment::Index(index)),
+                                registry,
+                            );
+                        }
+                    });
+                }
+                ReflectRef::Map(map) => {
+                    let label = format!("{} [{}]", name, map.len());
+                    let length = (InspectorLength(map.len()), field.clone());
+                    builder
+                        .foldable(label, true, map.is_empty(), |container| {
+                            for (index, (key, entry)) in map.iter().enumerate() {
+                                EntityInspector::build_value(
+                                    container,
+                                    &EntityInspector::debug_text(key),
+                                    entry,
+                                    field.child(InspectorPathSegment::MapValue(index)),
+                                    registry,
+                                );
+                                EntityInspector::build_remove_button(container, index, &field);
+                            }
+                        })
+                        .insert(length);
+                }
+                ReflectRef::Enum(variant) => {
+                    EntityInspector::build_enum(builder, name, value, variant, field, registry);
+                }
+                ReflectRef::Value(value) => {
+                    EntityInspector::build_read_only(
+                        builder,
+                        name,
+                        EntityInspector::debug_text(value),
+                    );
+                }
+            }
+        }
+    }
+
+    fn build_element(
+        builder: &mut UiBuilder<Entity>,
+        index: usize,
+        element: &dyn Reflect,
+        field: &InspectorField,
+        registry: &TypeRegistry,
+    ) {
+        EntityInspector::build_value(
+            builder,
+            &format!("[{}]", index),
+            element,
+            field.child(InspectorPathSegment::Index(index)),
+            registry,
+        );
+        EntityInspector::build_remove_button(builder, index, field);
+    }
+
+    fn build_remove_button(builder: &mut UiBuilder<Entity>, index: usize, field: &InspectorField) {
+        builder
+            .menu_item(MenuItemConfig {
+                name: format!("Remove [{}]", index).into(),
+                ..default()
+            })
+            .insert((InspectorListButton::Remove(index), field.clone()));
+    }
+
+    fn build_enum(
+        builder: &mut UiBuilder<Entity>,
+        name: &str,
+        value: &dyn Reflect,
+        variant: &dyn Enum,
+        field: InspectorField,
+        registry: &TypeRegistry,
+    ) {
+        let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+            EntityInspector::build_read_only(builder, name, variant.variant_name().into());
+            return;
+        };
+
+        let current = variant.variant_name();
+        let variants: Vec<String> = info.iter().map(|info| info.name().into()).collect();
+        // Only unit variants can be switched to without knowing their field values
+        let options: Vec<DropdownOptionConfig> = info
+            .iter()
+            .map(|info| {
+                let option = DropdownOptionConfig::new(info.name());
+                match info {
+                    VariantInfo::Unit(_) => option,
+                    _ if info.name() == current => option,
+                    _ => option.disabled(),
+                }
+            })
+            .collect();
+        let selected = variants.iter().position(|variant| variant == current);
+
+        if variant.field_len() == 0 {
+            InspectorRow::spawn(builder, name, |row| {
+                row.dropdown(options, selected)
+                    .insert((InspectorVariants(variants), field));
+            });
+            return;
+        }
+
+        builder.foldable(name, true, false, |container| {
+            InspectorRow::spawn(container, "Variant", |row| {
+                row.dropdown(options, selected)
+                    .insert((InspectorVariants(variants), field.clone()));
+            });
+
+            for (index, variant_field) in variant.iter_fields().enumerate() {
+                let (label, segment) = match variant_field.name() {
+                    Some(name) => (name.to_string(), InspectorPathSegment::Field(name.into())),
+                    None => (index.to_string(), InspectorPathSegment::Index(index)),
+                };
+
+                EntityInspector::build_value(
+                    container,
+                    &label,
+                    variant_field.value(),
+                    field.child(segment),
+                    registry,
+                );
+            }
+        });
+    }
+
+    fn build_read_only(builder: &mut UiBuilder<Entity>, name: &str, text: String) {
+        InspectorRow::spawn(builder, name, |row| {
+            row.label(LabelConfig {
+                label: text.into(),
+                ..default()
+            });
+        });
+    }
+
+    fn debug_text(value: &dyn Reflect) -> String {
+        let mut text = format!("{:?}", value);
+        if text.chars().count() > MAX_DEBUG_LENGTH {
+            text = text.chars().take(MAX_DEBUG_LENGTH - 1).collect();
+            text.push('…');
+        }
+
+        text
+    }
+}
+
+pub trait UiEntityInspectorExt {
+    fn entity_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<Entity>;
+}
+
+impl UiEntityInspectorExt for UiBuilder<'_, Entity> {
+    /// Editors for the reflected components of an entity.
+    ///
+    /// Booleans, numbers, enums, colors, vectors, rotations, structs, lists and maps are
+    /// editable, other values are shown read-only. Types can provide their own editor by
+    /// registering [`ReflectInspectorEditor`]. Edits are written back through reflection.
+    ///
+    /// The editors are rebuilt when `EntityInspector::entity` changes or components are added to
+    /// the entity, and show the current values when its components change. Custom editors are
+    /// only rebuilt.
+    fn entity_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<Entity> {
+        self.column(|column| {
+            column
+                .insert((Name::new("Entity Inspector"), EntityInspector { entity }))
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Auto);
+        })
+    }
+}
@@ -448,7 +448,7 @@ impl SceneControls {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -7,10 +7,13 @@ pub mod widgets;
 use bevy::prelude::*;
 
 use assets::BuiltInAssetsPlugin;
+use bidi::BidiPlugin;
 use drag_interaction::DragInteractionPlugin;
 use drop_interaction::DropInteractionPlugin;
 use flux_interaction::FluxInteractionPlugin;
+use localization::LocalizationPlugin;
 use scroll_interaction::ScrollInteractionPlugin;
+use text_overflow::TextOverflowPlugin;
 use theme::ThemePlugin;
 use widgets::WidgetsPlugin;
 
@@ -34,10 +37,13 @@ impl Plugin for SickleUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             BuiltInAssetsPlugin,
+            BidiPlugin,
             DragInteractionPlugin,
             DropInteractionPlugin,
             FluxInteractionPlugin,
+            LocalizationPlugin,
             ScrollInteractionPlugin,
+            TextOverflowPlugin,
             WidgetsPlugin,
             ThemePlugin,
         ));
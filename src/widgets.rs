@@ -4,22 +4,35 @@ pub mod menus;
 
 use bevy::prelude::*;
 
+#[cfg(feature = "observable")]
+use self::inputs::{binding::BindingPlugin, form::FormPlugin, validation::ValidationPlugin};
+
 use self::{
     inputs::checkbox::CheckboxPlugin,
+    inputs::color_picker::ColorPickerPlugin,
     inputs::dropdown::DropdownPlugin,
+    inputs::numeric_field::NumericFieldPlugin,
     inputs::radio_group::RadioGroupPlugin,
+    inputs::range_slider::RangeSliderPlugin,
     inputs::slider::SliderPlugin,
+    inputs::switch::SwitchPlugin,
+    inputs::vector_field::VectorFieldPlugin,
     layout::docking_zone::DockingZonePlugin,
     layout::floating_panel::{FloatingPanelPlugin, FloatingPanelUpdate},
     layout::foldable::FoldablePlugin,
+    layout::label::LabelPlugin,
     layout::resize_handles::ResizeHandlePlugin,
+    layout::rich_text::RichTextPlugin,
     layout::scroll_view::ScrollViewPlugin,
     layout::sized_zone::SizedZonePlugin,
     layout::tab_container::TabContainerPlugin,
+    layout::tooltip::TooltipPlugin,
     menus::context_menu::ContextMenuPlugin,
     menus::menu::MenuPlugin,
     menus::menu_bar::MenuBarPlugin,
     menus::menu_item::MenuItemPlugin,
+    menus::menu_model::MenuModelPlugin,
+    menus::menu_scroll::MenuScrollPlugin,
     menus::menu_separators::MenuSeparatorPlugin,
     menus::shortcut::ShortcutPlugin,
     menus::submenu::SubmenuPlugin,
@@ -28,10 +41,24 @@ use self::{
 
 pub mod prelude {
     pub use super::{
-        inputs::checkbox::{Checkbox, UiCheckboxExt},
-        inputs::dropdown::{Dropdown, UiDropdownExt},
+        inputs::checkbox::{Checkbox, CheckboxState, CheckboxTree, UiCheckboxExt},
+        inputs::color_picker::{
+            ColorPicker, ColorPickerButton, ColorPickerConfig, RecentColors, UiColorPickerExt,
+        },
+        inputs::dropdown::{
+            Dropdown, DropdownChip, DropdownChipValue, DropdownMode, DropdownOptionConfig,
+            DropdownSelectionMode, UiDropdownExt,
+        },
+        inputs::numeric_field::{
+            NumericField, NumericFieldConfig, NumericFieldUpdate, NumericScale, UiNumericFieldExt,
+        },
         inputs::radio_group::{RadioGroup, UiRadioGroupExt},
-        inputs::slider::{Slider, SliderConfig, UiSliderExt},
+        inputs::range_slider::{RangeSlider, RangeSliderConfig, UiRangeSliderExt},
+        inputs::slider::{Slider, SliderBarClick, SliderConfig, SliderTick, UiSliderExt},
+        inputs::switch::{Switch, UiSwitchExt},
+        inputs::vector_field::{
+            UiVectorFieldExt, VectorField, VectorFieldConfig, VectorFieldKind,
+        },
         layout::column::UiColumnExt,
         layout::container::UiContainerExt,
         layout::docking_zone::UiDockingZoneExt,
@@ -43,26 +70,43 @@ pub mod prelude {
         layout::label::{LabelConfig, UiLabelExt},
         layout::panel::UiPanelExt,
         layout::resize_handles::{ResizeHandle, ResizeHandles, UiResizeHandlesExt},
+        layout::rich_text::{RichText, RichTextUpdate, UiRichTextExt},
         layout::row::UiRowExt,
         layout::scroll_view::{ScrollViewLayoutUpdate, UiScrollViewExt},
         layout::sized_zone::{SizedZoneConfig, SizedZonePreUpdate, UiSizedZoneExt},
         layout::tab_container::{TabContainerUpdate, UiTabContainerExt, UiTabContainerSubExt},
+        layout::tooltip::{Tooltip, TooltipAnchor, TooltipContent, TooltipUpdate},
         menus::context_menu::{
-            ContextMenuGenerator, ContextMenuUpdate, ReflectContextMenuGenerator, UiContextMenuExt,
+            ContextMenuConfig, ContextMenuGenerator, ContextMenuUpdate,
+            ReflectContextMenuGenerator, UiContextMenuExt,
         },
         menus::extra_menu::{ExtraMenu, UiExtraMenuExt},
         menus::menu::{MenuConfig, MenuUpdate, UiMenuExt, UiMenuSubExt},
         menus::menu_bar::UiMenuBarExt,
         menus::menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+        menus::menu_model::{MenuModel, MenuModelEntryKind, MenuModelItem, MenuModelUpdate},
+        menus::menu_scroll::MenuScrollUpdate,
         menus::menu_separators::{UiMenuItemSeparatorExt, UiMenuSeparatorExt},
         menus::shortcut::{Shortcut, ShortcutPreUpdate},
         menus::submenu::{SubmenuConfig, SubmenuUpdate, UiSubmenuExt, UiSubmenuSubExt},
         menus::toggle_menu_item::{
-            ToggleMenuItemConfig, ToggleMenuItemUpdate, UiToggleMenuItemExt,
+            ToggleMenuItemConfig, ToggleMenuItemGroup, ToggleMenuItemResourceBinding,
+            ToggleMenuItemResourceBindingPlugin, ToggleMenuItemStateBinding,
+            ToggleMenuItemStateBindingPlugin, ToggleMenuItemUpdate, UiToggleMenuItemExt,
         },
         WidgetLibraryUpdate,
     };
 
+    #[cfg(feature = "observable")]
+    pub use super::{
+        inputs::{
+            binding::{Bind, Bindable, BindablePlugin},
+            form::{Form, FormSubmit, UiFormExt},
+            validation::{Validation, ValidationText, Validator},
+        },
+        layout::rich_text::RichTextLinkClicked,
+    };
+
     // Used with scroll views, floating panels, etc. often
     pub use sickle_ui_scaffold::scroll_interaction::ScrollAxis;
 }
@@ -74,19 +118,28 @@ impl Plugin for WidgetsPlugin {
         app.configure_sets(Update, WidgetLibraryUpdate.after(FloatingPanelUpdate))
             .add_plugins((
                 CheckboxPlugin,
+                ColorPickerPlugin,
                 ContextMenuPlugin,
                 SizedZonePlugin,
                 DockingZonePlugin,
                 DropdownPlugin,
                 FloatingPanelPlugin,
                 FoldablePlugin,
+                LabelPlugin,
                 MenuPlugin,
+                NumericFieldPlugin,
+                RichTextPlugin,
+                SwitchPlugin,
+                VectorFieldPlugin,
             ))
             .add_plugins((
                 MenuBarPlugin,
                 MenuItemPlugin,
+                MenuModelPlugin,
+                MenuScrollPlugin,
                 MenuSeparatorPlugin,
                 RadioGroupPlugin,
+                RangeSliderPlugin,
                 ResizeHandlePlugin,
                 ShortcutPlugin,
                 SliderPlugin,
@@ -94,7 +147,11 @@ impl Plugin for WidgetsPlugin {
                 SubmenuPlugin,
                 TabContainerPlugin,
                 ToggleMenuItemPlugin,
+                TooltipPlugin,
             ));
+
+        #[cfg(feature = "observable")]
+        app.add_plugins((BindingPlugin, FormPlugin, ValidationPlugin));
     }
 }
 
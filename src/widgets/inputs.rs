@@ -1,4 +1,15 @@
+#[cfg(feature = "observable")]
+pub mod binding;
 pub mod checkbox;
+pub mod color_picker;
 pub mod dropdown;
+#[cfg(feature = "observable")]
+pub mod form;
+pub mod numeric_field;
 pub mod radio_group;
+pub mod range_slider;
 pub mod slider;
+pub mod switch;
+#[cfg(feature = "observable")]
+pub mod validation;
+pub mod vector_field;
@@ -0,0 +1,506 @@
+use std::{any::TypeId, marker::PhantomData, sync::Arc};
+
+use bevy::{
+    prelude::*,
+    reflect::{GetPath, TypeRegistry},
+};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::inputs::{
+    checkbox::{Checkbox, CheckboxChanged},
+    color_picker::{ColorChanged, ColorPicker},
+    dropdown::{Dropdown, DropdownChanged},
+    numeric_field::{read_number, write_number, NumericField, NumericFieldChanged},
+    radio_group::{RadioButtonChanged, RadioGroup},
+    range_slider::{RangeSlider, RangeSliderChanged},
+    slider::{Slider, SliderChanged},
+    switch::Switch,
+    validation::{validate_input, Validation, ValidationUpdate},
+};
+
+pub struct BindingPlugin;
+
+impl Plugin for BindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, BindingUpdate.before(FluxInteractionUpdate))
+            .add_plugins((
+                BindablePlugin::<Checkbox>::default(),
+                BindablePlugin::<ColorPicker>::default(),
+                BindablePlugin::<Dropdown>::default(),
+                BindablePlugin::<NumericField>::default(),
+                BindablePlugin::<RadioGroup>::default(),
+                BindablePlugin::<RangeSlider>::default(),
+                BindablePlugin::<Slider>::default(),
+                BindablePlugin::<Switch>::default(),
+            ));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct BindingUpdate;
+
+/// Keeps `W` widgets with a [`Bind`] in sync with their model
+///
+/// Add it for custom widgets implementing [`Bindable`]; the built-in inputs are
+/// covered by [`BindingPlugin`].
+pub struct BindablePlugin<W: Bindable> {
+    context: PhantomData<W>,
+}
+
+impl<W: Bindable> Default for BindablePlugin<W> {
+    fn default() -> Self {
+        Self {
+            context: PhantomData,
+        }
+    }
+}
+
+impl<W: Bindable> Plugin for BindablePlugin<W> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_widget_from_model::<W>.in_set(BindingUpdate),
+                validate_input::<W>.before(ValidationUpdate),
+            ),
+        )
+        .observe(update_model_from_widget::<W>);
+    }
+}
+
+fn update_widget_from_model<W: Bindable>(world: &mut World) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let mut q_bound = world.query_filtered::<(Entity, &Bind), With<W>>();
+    let changed: Vec<(Entity, Box<dyn Reflect>)> = q_bound
+        .iter(world)
+        .filter_map(|(entity, bind)| {
+            let model = bind.source.read(world, &registry)?;
+            match &bind.synced {
+                Some(synced) if synced.reflect_partial_eq(model) == Some(true) => None,
+                _ => Some((entity, model.clone_value())),
+            }
+        })
+        .collect();
+
+    for (entity, model) in changed {
+        let Some(mut bind) = world.get_mut::<Bind>(entity) else {
+            continue;
+        };
+
+        let value = match &bind.to_widget {
+            Some(convert) => convert(model.as_ref()),
+            None => Some(model.clone_value()),
+        };
+
+        // The widget shows the model again, which is valid by definition
+        if !bind.valid {
+            bind.valid = true;
+        }
+        bind.bypass_change_detection().synced = Some(model);
+
+        let Some(value) = value else {
+            continue;
+        };
+        let Some(mut widget) = world.get_mut::<W>(entity) else {
+            continue;
+        };
+
+        let mut bound = widget.bound_value();
+        if apply_bound_value(value.as_ref(), &mut bound) {
+            widget.set_bound_value(bound);
+        }
+    }
+}
+
+fn update_model_from_widget<W: Bindable>(
+    trigger: Trigger<W::Changed>,
+    q_widgets: Query<&W, With<Bind>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let Ok(widget) = q_widgets.get(entity) else {
+        return;
+    };
+
+    let value = widget.bound_value();
+    commands.add(move |world: &mut World| Bind::write_model(entity, &value, world));
+}
+
+/// Writes `source` into `target`, converting between primitive number types
+///
+/// Returns `true` if `target` changed.
+fn apply_bound_value(source: &dyn Reflect, target: &mut dyn Reflect) -> bool {
+    if let (Some((number, _)), Some(_)) = (read_number(source), read_number(target)) {
+        return write_number(target, number);
+    }
+
+    if target.reflect_partial_eq(source) == Some(true) {
+        return false;
+    }
+
+    target.try_apply(source).is_ok()
+}
+
+/// An input widget whose value can be bound to a model with [`Bind`]
+pub trait Bindable: Component {
+    /// The value edited by the widget
+    type Value: Reflect;
+    /// The event fired on the widget entity when the user changes its value
+    type Changed: Event;
+
+    fn bound_value(&self) -> Self::Value;
+    fn set_bound_value(&mut self, value: Self::Value);
+}
+
+impl Bindable for Checkbox {
+    type Value = bool;
+    type Changed = CheckboxChanged;
+
+    fn bound_value(&self) -> bool {
+        self.checked
+    }
+
+    fn set_bound_value(&mut self, value: bool) {
+        self.set_state(value.into());
+    }
+}
+
+impl Bindable for Switch {
+    type Value = bool;
+    type Changed = CheckboxChanged;
+
+    fn bound_value(&self) -> bool {
+        self.checked
+    }
+
+    fn set_bound_value(&mut self, value: bool) {
+        self.checked = value;
+    }
+}
+
+impl Bindable for Slider {
+    type Value = f32;
+    type Changed = SliderChanged;
+
+    fn bound_value(&self) -> f32 {
+        self.value()
+    }
+
+    fn set_bound_value(&mut self, value: f32) {
+        self.set_value(value);
+    }
+}
+
+impl Bindable for RangeSlider {
+    /// The `min` and `max` values as `x` and `y`
+    type Value = Vec2;
+    type Changed = RangeSliderChanged;
+
+    fn bound_value(&self) -> Vec2 {
+        Vec2::new(self.min_value(), self.max_value())
+    }
+
+    fn set_bound_value(&mut self, value: Vec2) {
+        self.set_values(value.x, value.y);
+    }
+}
+
+impl Bindable for NumericField {
+    type Value = f32;
+    type Changed = NumericFieldChanged;
+
+    fn bound_value(&self) -> f32 {
+        self.value()
+    }
+
+    fn set_bound_value(&mut self, value: f32) {
+        self.set_value(value);
+    }
+}
+
+impl Bindable for Dropdown {
+    type Value = Option<usize>;
+    type Changed = DropdownChanged;
+
+    fn bound_value(&self) -> Option<usize> {
+        self.value()
+    }
+
+    fn set_bound_value(&mut self, value: Option<usize>) {
+        self.set_value(value);
+    }
+}
+
+impl Bindable for RadioGroup {
+    type Value = Option<usize>;
+    type Changed = RadioButtonChanged;
+
+    fn bound_value(&self) -> Option<usize> {
+        self.selected()
+    }
+
+    fn set_bound_value(&mut self, value: Option<usize>) {
+        self.select(value);
+    }
+}
+
+impl Bindable for ColorPicker {
+    type Value = Color;
+    type Changed = ColorChanged;
+
+    fn bound_value(&self) -> Color {
+        self.color()
+    }
+
+    fn set_bound_value(&mut self, value: Color) {
+        self.set_color(value);
+    }
+}
+
+type BindConversion = Arc<dyn Fn(&dyn Reflect) -> Option<Box<dyn Reflect>> + Send + Sync>;
+type BindValidation = Arc<dyn Fn(&dyn Reflect) -> bool + Send + Sync>;
+
+#[derive(Clone, Debug)]
+enum BindSource {
+    Resource {
+        type_id: TypeId,
+        path: String,
+    },
+    Component {
+        entity: Entity,
+        component: String,
+        path: String,
+    },
+}
+
+impl BindSource {
+    fn path(&self) -> &str {
+        match self {
+            BindSource::Resource { path, .. } => path,
+            BindSource::Component { path, .. } => path,
+        }
+    }
+
+    fn read<'w>(&self, world: &'w World, registry: &TypeRegistry) -> Option<&'w dyn Reflect> {
+        let root = match self {
+            BindSource::Resource { type_id, .. } => registry
+                .get(*type_id)?
+                .data::<ReflectResource>()?
+                .reflect(world)?,
+            BindSource::Component {
+                entity, component, ..
+            } => registry
+                .get_with_short_type_path(component)?
+                .data::<ReflectComponent>()?
+                .reflect(world.get_entity(*entity)?)?,
+        };
+
+        match self.path() {
+            "" => Some(root),
+            path => root.reflect_path(path).ok(),
+        }
+    }
+
+    /// Calls `modify` with the bound field, marking the model changed if it returns `true`
+    fn modify(
+        &self,
+        world: &mut World,
+        registry: &TypeRegistry,
+        modify: impl FnOnce(&mut dyn Reflect) -> bool,
+    ) {
+        match self {
+            BindSource::Resource { type_id, .. } => {
+                let Some(reflect_resource) = registry
+                    .get(*type_id)
+                    .and_then(|registration| registration.data::<ReflectResource>())
+                else {
+                    return;
+                };
+
+                let root = reflect_resource.reflect_mut(world);
+                BindSource::modify_field(root, self.path(), modify);
+            }
+            BindSource::Component {
+                entity, component, ..
+            } => {
+                let Some(reflect_component) = registry
+                    .get_with_short_type_path(component)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                else {
+                    return;
+                };
+                let Some(mut entity) = world.get_entity_mut(*entity) else {
+                    return;
+                };
+
+                let root = reflect_component.reflect_mut(&mut entity);
+                BindSource::modify_field(root, self.path(), modify);
+            }
+        }
+    }
+
+    fn modify_field(
+        root: Option<Mut<dyn Reflect>>,
+        path: &str,
+        modify: impl FnOnce(&mut dyn Reflect) -> bool,
+    ) {
+        let Some(mut root) = root else {
+            return;
+        };
+
+        let field = match path {
+            "" => Some(root.bypass_change_detection()),
+            path => root.bypass_change_detection().reflect_path_mut(path).ok(),
+        };
+
+        if field.is_some_and(modify) {
+            root.set_changed();
+        }
+    }
+}
+
+/// Binds the value of an input widget to a field of a `Resource` or `Component`
+///
+/// The field is located with a reflection path and both must be registered with
+/// `#[reflect(Resource)]` or `#[reflect(Component)]`. Model changes are pushed into the
+/// widget, while the widget's change events write user edits back into the model.
+/// Primitive number types are converted to and from the widget value automatically.
+/// Values rejected by a [`Validation`] on the widget are not written to the model.
+///
+/// ```ignore
+/// column
+///     .slider(SliderConfig::horizontal("Volume", 0., 1., 0.5, true))
+///     .insert(Bind::resource::<AudioSettings>("volume"));
+/// ```
+#[derive(Component)]
+pub struct Bind {
+    source: BindSource,
+    to_widget: Option<BindConversion>,
+    to_model: Option<BindConversion>,
+    validation: Option<BindValidation>,
+    valid: bool,
+    synced: Option<Box<dyn Reflect>>,
+}
+
+impl Bind {
+    /// Binds to the field at `path` of resource `R`, or to the whole resource if `path` is empty
+    pub fn resource<R: Resource + Reflect>(path: impl Into<String>) -> Self {
+        Self::new(BindSource::Resource {
+            type_id: TypeId::of::<R>(),
+            path: path.into(),
+        })
+    }
+
+    /// Binds to a component field of `entity`
+    ///
+    /// The first segment of `path` is the short type path of the component,
+    /// e.g. `"Transform.translation.x"`.
+    pub fn component(entity: Entity, path: impl Into<String>) -> Self {
+        let path: String = path.into();
+        let (component, path) = match path.split_once('.') {
+            Some((component, path)) => (component.into(), path.into()),
+            None => (path, String::new()),
+        };
+
+        Self::new(BindSource::Component {
+            entity,
+            component,
+            path,
+        })
+    }
+
+    fn new(source: BindSource) -> Self {
+        Self {
+            source,
+            to_widget: None,
+            to_model: None,
+            validation: None,
+            valid: true,
+            synced: None,
+        }
+    }
+
+    /// Converts between a model field of type `M` and a widget value of type `V`
+    pub fn with_conversion<M: FromReflect, V: FromReflect>(
+        self,
+        to_widget: impl Fn(M) -> V + Send + Sync + 'static,
+        to_model: impl Fn(V) -> M + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            to_widget: Some(Arc::new(move |value| {
+                M::from_reflect(value).map(|value| Box::new(to_widget(value)) as Box<dyn Reflect>)
+            })),
+            to_model: Some(Arc::new(move |value| {
+                V::from_reflect(value).map(|value| Box::new(to_model(value)) as Box<dyn Reflect>)
+            })),
+            ..self
+        }
+    }
+
+    /// Only writes widget values of type `V` to the model if `validate` accepts them
+    pub fn with_validation<V: FromReflect>(
+        self,
+        validate: impl Fn(&V) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            validation: Some(Arc::new(move |value| {
+                V::from_reflect(value).is_some_and(|value| validate(&value))
+            })),
+            ..self
+        }
+    }
+
+    /// Returns `false` if the last widget edit was rejected by validation or conversion
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn write_model(entity: Entity, value: &dyn Reflect, world: &mut World) {
+        let Some(bind) = world.get::<Bind>(entity) else {
+            return;
+        };
+
+        let accepted = bind
+            .validation
+            .as_ref()
+            .iter()
+            .all(|validate| validate(value))
+            && world
+                .get::<Validation>(entity)
+                .iter()
+                .all(|validation| validation.check(value).is_none());
+        let model_value = match (&bind.to_model, accepted) {
+            (_, false) => None,
+            (Some(convert), true) => convert(value),
+            (None, true) => Some(value.clone_value()),
+        };
+        let source = bind.source.clone();
+
+        if bind.valid != model_value.is_some() {
+            if let Some(mut bind) = world.get_mut::<Bind>(entity) {
+                bind.valid = model_value.is_some();
+            }
+        }
+
+        let Some(model_value) = model_value else {
+            return;
+        };
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let mut synced = None;
+        source.modify(world, &registry, |target| {
+            let changed = apply_bound_value(model_value.as_ref(), target);
+            synced = Some(target.clone_value());
+            changed
+        });
+
+        if let Some(mut bind) = world.get_mut::<Bind>(entity) {
+            bind.bypass_change_detection().synced = synced;
+        }
+    }
+}
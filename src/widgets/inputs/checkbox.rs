@@ -19,7 +19,7 @@ impl Plugin for CheckboxPlugin {
         app.add_plugins(ComponentThemePlugin::<Checkbox>::default())
             .add_systems(
                 Update,
-                (toggle_checkbox, update_checkbox)
+                (toggle_checkbox, update_checkbox_trees, update_checkbox)
                     .chain()
                     .after(FluxInteractionUpdate),
             );
@@ -35,7 +35,9 @@ fn toggle_checkbox(
 ) {
     for (entity, mut checkbox, interaction) in &mut q_checkboxes {
         if *interaction == FluxInteraction::Released {
-            checkbox.checked = !checkbox.checked;
+            // Indeterminate checkboxes become checked
+            checkbox.checked = checkbox.indeterminate || !checkbox.checked;
+            checkbox.indeterminate = false;
 
             #[cfg(feature = "observable")]
             commands.trigger_targets(CheckboxChanged {
@@ -45,33 +47,174 @@ fn toggle_checkbox(
     }
 }
 
+fn update_checkbox_trees(
+    q_toggled_trees: Query<(Entity, &FluxInteraction), (With<CheckboxTree>, Changed<FluxInteraction>)>,
+    q_trees: Query<(Entity, &CheckboxTree)>,
+    mut q_checkboxes: Query<&mut Checkbox>,
+    mut commands: Commands,
+) {
+    // Toggling a parent checks or unchecks every checkbox below it
+    for (entity, interaction) in &q_toggled_trees {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+        let Ok(state) = q_checkboxes.get(entity).map(Checkbox::state) else {
+            continue;
+        };
+
+        let mut descendants = Vec::new();
+        CheckboxTree::collect_descendants(entity, &q_trees, &mut descendants);
+        for descendant in descendants {
+            let Ok(mut checkbox) = q_checkboxes.get_mut(descendant) else {
+                continue;
+            };
+            if checkbox.state() == state {
+                continue;
+            }
+
+            checkbox.set_state(state);
+
+            #[cfg(feature = "observable")]
+            commands.trigger_targets(CheckboxChanged {
+                value: checkbox.checked
+            }, descendant);
+        }
+    }
+
+    if !q_checkboxes.iter_mut().any(|checkbox| checkbox.is_changed()) {
+        return;
+    }
+
+    // Parents reflect the aggregated state of their children
+    for (entity, _) in &q_trees {
+        let Some(state) = CheckboxTree::aggregate(entity, &q_trees, &q_checkboxes) else {
+            continue;
+        };
+        let Ok(mut checkbox) = q_checkboxes.get_mut(entity) else {
+            continue;
+        };
+
+        if checkbox.state() != state {
+            checkbox.set_state(state);
+        }
+    }
+}
+
 fn update_checkbox(
     q_checkboxes: Query<(Entity, &Checkbox), Changed<Checkbox>>,
     mut commands: Commands,
 ) {
     for (entity, checkbox) in &q_checkboxes {
+        let state = checkbox.state();
         commands
             .style_unchecked(checkbox.checkmark)
-            .visibility(match checkbox.checked {
-                true => Visibility::Inherited,
-                false => Visibility::Hidden,
+            .visibility(match state {
+                CheckboxState::Unchecked => Visibility::Hidden,
+                _ => Visibility::Inherited,
             });
 
-        match checkbox.checked {
-            true => commands
-                .entity(entity)
-                .add_pseudo_state(PseudoState::Checked),
-            false => commands
-                .entity(entity)
-                .remove_pseudo_state(PseudoState::Checked),
+        match state {
+            CheckboxState::Checked => {
+                commands
+                    .entity(entity)
+                    .remove_pseudo_state(PseudoState::Indeterminate)
+                    .add_pseudo_state(PseudoState::Checked);
+            }
+            CheckboxState::Indeterminate => {
+                commands
+                    .entity(entity)
+                    .remove_pseudo_state(PseudoState::Checked)
+                    .add_pseudo_state(PseudoState::Indeterminate);
+            }
+            CheckboxState::Unchecked => {
+                commands
+                    .entity(entity)
+                    .remove_pseudo_state(PseudoState::Checked)
+                    .remove_pseudo_state(PseudoState::Indeterminate);
+            }
         };
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        match checked {
+            true => CheckboxState::Checked,
+            false => CheckboxState::Unchecked,
+        }
+    }
+}
+
+impl CheckboxState {
+    /// Checked if all states are checked, unchecked if none are and indeterminate otherwise.
+    /// Returns `None` for no states.
+    pub fn aggregate(states: impl IntoIterator<Item = CheckboxState>) -> Option<CheckboxState> {
+        states.into_iter().reduce(|aggregate, state| match aggregate == state {
+            true => aggregate,
+            false => CheckboxState::Indeterminate,
+        })
+    }
+}
+
+/// Links a checkbox to the checkboxes of its children, as in a "select all" checkbox.
+///
+/// The parent is checked if all of the children are, unchecked if none are and
+/// indeterminate otherwise. Toggling the parent checks or unchecks all children.
+/// Children can be trees themselves.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CheckboxTree {
+    pub children: Vec<Entity>,
+}
+
+impl CheckboxTree {
+    pub fn new(children: Vec<Entity>) -> Self {
+        Self { children }
+    }
+
+    fn aggregate(
+        entity: Entity,
+        q_trees: &Query<(Entity, &CheckboxTree)>,
+        q_checkboxes: &Query<&mut Checkbox>,
+    ) -> Option<CheckboxState> {
+        let (_, tree) = q_trees.get(entity).ok()?;
+
+        CheckboxState::aggregate(tree.children.iter().filter_map(|child| {
+            CheckboxTree::aggregate(*child, q_trees, q_checkboxes)
+                .or_else(|| q_checkboxes.get(*child).ok().map(Checkbox::state))
+        }))
+    }
+
+    fn collect_descendants(
+        entity: Entity,
+        q_trees: &Query<(Entity, &CheckboxTree)>,
+        descendants: &mut Vec<Entity>,
+    ) {
+        let Ok((_, tree)) = q_trees.get(entity) else {
+            return;
+        };
+
+        for child in tree.children.iter() {
+            descendants.push(*child);
+            CheckboxTree::collect_descendants(*child, q_trees, descendants);
+        }
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct Checkbox {
     pub checked: bool,
+    /// Shown as neither checked nor unchecked, regardless of `checked`
+    pub indeterminate: bool,
     checkmark_background: Entity,
     checkmark: Entity,
     label: Entity,
@@ -81,6 +224,7 @@ impl Default for Checkbox {
     fn default() -> Self {
         Self {
             checked: false,
+            indeterminate: false,
             checkmark_background: Entity::PLACEHOLDER,
             checkmark: Entity::PLACEHOLDER,
             label: Entity::PLACEHOLDER,
@@ -123,19 +267,43 @@ impl Checkbox {
     pub const CHECKMARK: &'static str = "Checkmark";
     pub const LABEL: &'static str = "Label";
 
+    pub fn state(&self) -> CheckboxState {
+        match (self.indeterminate, self.checked) {
+            (true, _) => CheckboxState::Indeterminate,
+            (false, checked) => checked.into(),
+        }
+    }
+
+    pub fn set_state(&mut self, state: CheckboxState) {
+        self.checked = state == CheckboxState::Checked;
+        self.indeterminate = state == CheckboxState::Indeterminate;
+    }
+
     pub fn theme() -> Theme<Checkbox> {
         let base_theme = PseudoTheme::deferred(None, Checkbox::primary_style);
         let checked_theme =
             PseudoTheme::deferred(vec![PseudoState::Checked], Checkbox::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+        let indeterminate_theme = PseudoTheme::deferred(
+            vec![PseudoState::Indeterminate],
+            Checkbox::indeterminate_style,
+        );
+        let error_theme = PseudoTheme::deferred(vec![PseudoState::Error], Checkbox::error_style);
+        Theme::new(vec![
+            base_theme,
+            checked_theme,
+            indeterminate_theme,
+            error_theme,
+        ])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
+            .flex_direction(theme_data.layout_direction.row())
             .height(Val::Px(theme_spacing.inputs.checkbox.line_height))
+            .min_height(Val::Px(theme_data.min_hit_target()))
             .justify_content(JustifyContent::Start)
             .align_items(AlignItems::Center)
             .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
@@ -173,7 +341,8 @@ impl Checkbox {
                 hover: colors.accent(Accent::Primary).into(),
                 ..default()
             })
-            .copy_from(theme_data.interaction_animation);
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
 
         style_builder
             .switch_target(Checkbox::CHECKMARK)
@@ -183,9 +352,7 @@ impl Checkbox {
                 theme_spacing.inputs.checkbox.checkmark_size,
             ));
 
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
         style_builder
             .switch_target(Checkbox::LABEL)
             .margin(UiRect::px(
@@ -205,7 +372,7 @@ impl Checkbox {
     }
 
     fn checked_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -220,13 +387,9 @@ impl Checkbox {
 
         style_builder
             .switch_target(Checkbox::CHECKMARK_BACKGROUND)
-            .animated()
-            .background_color(AnimatedVals {
-                idle: colors.accent(Accent::Primary),
-                enter_from: Some(Color::NONE),
-                ..default()
-            })
-            .copy_from(theme_data.enter_animation);
+            .switch_transition(theme_data.style_transition)
+            .background_color(colors.accent(Accent::Primary))
+            .reset_transition();
 
         style_builder
             .switch_target(Checkbox::CHECKMARK)
@@ -249,6 +412,38 @@ impl Checkbox {
             .copy_from(theme_data.enter_animation);
     }
 
+    fn indeterminate_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        Checkbox::checked_style(style_builder, theme_data);
+
+        style_builder
+            .switch_target(Checkbox::CHECKMARK)
+            .icon(theme_data.icons.indeterminate.with(
+                colors.on(OnColor::Primary),
+                theme_spacing.inputs.checkbox.checkmark_size,
+            ));
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(Checkbox::CHECKMARK_BACKGROUND)
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::Error),
+                hover: colors.container(Container::Error).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(Checkbox::LABEL)
+            .font_color(colors.accent(Accent::Error));
+    }
+
     fn checkbox_container(name: String) -> impl Bundle {
         (
             Name::new(name),
@@ -293,6 +488,8 @@ impl UiCheckboxExt for UiBuilder<'_, Entity> {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked`, when the checkbox is in a checked state
+    /// - `PseudoState::Indeterminate`, when the checkbox is neither checked nor unchecked
+    /// - `PseudoState::Error`, when a `Validation` on it fails
     fn checkbox(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity> {
         let mut checkbox = Checkbox {
             checked,
@@ -317,7 +514,7 @@ impl UiCheckboxExt for UiBuilder<'_, Entity> {
                 .id();
 
             checkbox.label = container
-                .label(LabelConfig { label, ..default() })
+                .label(LabelConfig::from(label))
                 .style()
                 .render(has_label)
                 .id();
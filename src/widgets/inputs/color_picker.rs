@@ -0,0 +1,1601 @@
+use bevy::{
+    color::{ColorToPacked, Hue, Mix},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+    ui::{FocusPolicy, RelativeCursorPosition},
+};
+
+use sickle_ui_scaffold::{
+    prelude::*,
+    theme::theme_colors::{ColorPalette, ColorPalettes},
+    ui_commands::UpdateTextExt,
+};
+
+use crate::widgets::{
+    inputs::numeric_field::{
+        NumericField, NumericFieldConfig, NumericFieldUpdate, UiNumericFieldExt,
+    },
+    layout::{
+        column::UiColumnExt,
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+    },
+};
+
+const COLOR_PICKER_POPOVER_Z_INDEX: usize = 11000;
+
+/// Number of colors kept in [`RecentColors`]
+const RECENT_COLORS_CAPACITY: usize = 8;
+
+/// Tones of the theme palettes offered as swatches
+const PALETTE_TONES: [u8; 8] = [10, 20, 30, 40, 60, 80, 90, 95];
+
+const SATURATION_VALUE_IMAGE_SIZE: u32 = 64;
+const STRIP_IMAGE_WIDTH: u32 = 128;
+const ALPHA_IMAGE_HEIGHT: u32 = 8;
+/// Size of a checkerboard cell of the alpha strip, in texels
+const CHECKER_SIZE: u32 = 4;
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct ColorChanged {
+    pub color: Color,
+}
+
+pub struct ColorPickerPlugin;
+
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecentColors>()
+            .add_plugins((
+                ComponentThemePlugin::<ColorPicker>::default(),
+                ComponentThemePlugin::<ColorPickerInput>::default(),
+                ComponentThemePlugin::<ColorPickerSwatch>::default(),
+                ComponentThemePlugin::<ColorPickerButton>::default(),
+            ))
+            .add_systems(
+                Update,
+                (
+                    create_color_picker_images,
+                    toggle_color_picker_popover,
+                    close_color_picker_popover_on_outside_press,
+                    update_color_picker_on_area_press,
+                    commit_color_picker_on_area_release,
+                    update_color_picker_from_inputs,
+                    handle_color_picker_hex_press,
+                    handle_color_picker_hex_keyboard,
+                    commit_color_picker_hex_on_outside_press,
+                    handle_color_picker_swatch_press,
+                    update_color_picker,
+                    update_recent_color_swatches,
+                    update_color_picker_popover,
+                )
+                    .chain()
+                    .after(FluxInteractionUpdate)
+                    .after(NumericFieldUpdate),
+            );
+
+        #[cfg(feature = "observable")]
+        app.add_event::<ColorChanged>();
+    }
+}
+
+fn create_color_picker_images(
+    mut q_pickers: Query<&mut ColorPicker, Added<ColorPicker>>,
+    mut images: ResMut<Assets<Image>>,
+    mut commands: Commands,
+) {
+    for mut picker in &mut q_pickers {
+        let mut hue_image = ColorPicker::new_image(STRIP_IMAGE_WIDTH, 1);
+        ColorPicker::fill_image(&mut hue_image, |x, _| {
+            Hsva::new(x * 360., 1., 1., 1.).into()
+        });
+
+        picker.saturation_value_image = images.add(ColorPicker::new_image(
+            SATURATION_VALUE_IMAGE_SIZE,
+            SATURATION_VALUE_IMAGE_SIZE,
+        ));
+        picker.hue_image = images.add(hue_image);
+        picker.alpha_image = images.add(ColorPicker::new_image(
+            STRIP_IMAGE_WIDTH,
+            ALPHA_IMAGE_HEIGHT,
+        ));
+
+        commands
+            .entity(picker.saturation_value)
+            .insert(UiImage::new(picker.saturation_value_image.clone()));
+        commands
+            .entity(picker.hue)
+            .insert(UiImage::new(picker.hue_image.clone()));
+        commands
+            .entity(picker.alpha)
+            .insert(UiImage::new(picker.alpha_image.clone()));
+    }
+}
+
+fn toggle_color_picker_popover(
+    mut q_buttons: Query<(&mut ColorPickerButton, &FluxInteraction), Changed<FluxInteraction>>,
+) {
+    for (mut button, interaction) in &mut q_buttons {
+        if *interaction == FluxInteraction::Released {
+            button.is_open = !button.is_open;
+        }
+    }
+}
+
+fn close_color_picker_popover_on_outside_press(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    mut q_buttons: Query<(&mut ColorPickerButton, &Interaction)>,
+    q_panels: Query<&RelativeCursorPosition>,
+) {
+    if !(r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
+        || r_touches.any_just_pressed())
+    {
+        return;
+    }
+
+    for (mut button, interaction) in &mut q_buttons {
+        if !button.is_open || *interaction != Interaction::None {
+            continue;
+        }
+
+        let pressed_in_panel = q_panels
+            .get(button.panel)
+            .is_ok_and(|cursor| cursor.mouse_over());
+        if !pressed_in_panel {
+            button.is_open = false;
+        }
+    }
+}
+
+fn update_color_picker_on_area_press(
+    q_areas: Query<
+        (&ColorPickerArea, &Interaction, &RelativeCursorPosition),
+        Or<(Changed<Interaction>, Changed<RelativeCursorPosition>)>,
+    >,
+    mut q_pickers: Query<(Entity, &mut ColorPicker)>,
+    mut commands: Commands,
+) {
+    for (area, interaction, cursor) in &q_areas {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+        let Ok((entity, mut picker)) = q_pickers.get_mut(area.picker) else {
+            continue;
+        };
+
+        let position = position.clamp(Vec2::ZERO, Vec2::ONE);
+        let mut color = picker.color;
+        match area.kind {
+            ColorPickerAreaKind::SaturationValue => {
+                color.saturation = position.x;
+                color.value = 1. - position.y;
+            }
+            ColorPickerAreaKind::Hue => color.hue = position.x * 360.,
+            ColorPickerAreaKind::Alpha => color.alpha = position.x,
+        }
+
+        if picker.color != color {
+            picker.color = color;
+
+            #[cfg(feature = "observable")]
+            picker.changed(entity, &mut commands);
+        }
+    }
+}
+
+fn commit_color_picker_on_area_release(
+    q_areas: Query<(&ColorPickerArea, &FluxInteraction), Changed<FluxInteraction>>,
+    q_pickers: Query<&ColorPicker>,
+    mut r_recent_colors: ResMut<RecentColors>,
+) {
+    for (area, interaction) in &q_areas {
+        if !matches!(
+            *interaction,
+            FluxInteraction::Released | FluxInteraction::PressCanceled
+        ) {
+            continue;
+        }
+        let Ok(picker) = q_pickers.get(area.picker) else {
+            continue;
+        };
+
+        r_recent_colors.push(picker.color());
+    }
+}
+
+fn update_color_picker_from_inputs(
+    q_inputs: Query<&ColorPickerInput>,
+    q_fields: Query<Ref<NumericField>>,
+    mut q_pickers: Query<(Entity, &mut ColorPicker)>,
+    mut r_recent_colors: ResMut<RecentColors>,
+    mut commands: Commands,
+) {
+    for input in &q_inputs {
+        let Ok(field) = q_fields.get(input.field) else {
+            continue;
+        };
+        if !field.is_changed() || field.is_editing() {
+            continue;
+        }
+        let Ok((entity, mut picker)) = q_pickers.get_mut(input.picker) else {
+            continue;
+        };
+
+        let current = field.config().constrain(input.channel.value(picker.color));
+        if current == field.value() {
+            continue;
+        }
+
+        let color = input.channel.with_value(picker.color, field.value());
+        picker.set_color(color);
+        if !field.is_scrubbing() {
+            r_recent_colors.push(picker.color());
+        }
+
+        #[cfg(feature = "observable")]
+        picker.changed(entity, &mut commands);
+    }
+}
+
+fn handle_color_picker_hex_press(
+    q_hex_fields: Query<(&ColorPickerHexField, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_pickers: Query<&mut ColorPicker>,
+    mut commands: Commands,
+) {
+    for (hex_field, interaction) in &q_hex_fields {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+        let Ok(mut picker) = q_pickers.get_mut(hex_field.picker) else {
+            continue;
+        };
+        if picker.hex_editing {
+            continue;
+        }
+
+        picker.hex_editing = true;
+        picker.hex_buffer = "#".into();
+        commands
+            .entity(hex_field.picker)
+            .add_pseudo_state(PseudoState::Selected);
+    }
+}
+
+fn handle_color_picker_hex_keyboard(
+    mut ev_keyboard: EventReader<KeyboardInput>,
+    mut q_pickers: Query<(Entity, &mut ColorPicker)>,
+    mut r_recent_colors: ResMut<RecentColors>,
+    mut commands: Commands,
+) {
+    let pressed: Vec<&KeyboardInput> = ev_keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .collect();
+    if pressed.is_empty() {
+        return;
+    }
+
+    for (entity, mut picker) in &mut q_pickers {
+        if !picker.hex_editing {
+            continue;
+        }
+
+        for input in &pressed {
+            match &input.logical_key {
+                Key::Enter | Key::Tab => {
+                    if picker.stop_hex_editing(entity, true, &mut commands) {
+                        r_recent_colors.push(picker.color());
+                    }
+                    break;
+                }
+                Key::Escape => {
+                    picker.stop_hex_editing(entity, false, &mut commands);
+                    break;
+                }
+                Key::Backspace => {
+                    picker.hex_buffer.pop();
+                }
+                Key::Character(chars) => {
+                    // `#` followed by at most eight digits
+                    for c in chars.chars().filter(char::is_ascii_hexdigit) {
+                        if picker.hex_buffer.len() < 9 {
+                            picker.hex_buffer.push(c.to_ascii_uppercase());
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+fn commit_color_picker_hex_on_outside_press(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    q_hex_fields: Query<&Interaction, With<ColorPickerHexField>>,
+    mut q_pickers: Query<(Entity, &mut ColorPicker)>,
+    mut r_recent_colors: ResMut<RecentColors>,
+    mut commands: Commands,
+) {
+    if !(r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
+        || r_touches.any_just_pressed())
+    {
+        return;
+    }
+
+    for (entity, mut picker) in &mut q_pickers {
+        if !picker.hex_editing {
+            continue;
+        }
+        let Ok(interaction) = q_hex_fields.get(picker.hex_field) else {
+            continue;
+        };
+
+        if *interaction == Interaction::None && picker.stop_hex_editing(entity, true, &mut commands)
+        {
+            r_recent_colors.push(picker.color());
+        }
+    }
+}
+
+fn handle_color_picker_swatch_press(
+    q_swatches: Query<(&ColorPickerSwatch, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_pickers: Query<(Entity, &mut ColorPicker)>,
+    r_recent_colors: Res<RecentColors>,
+    r_theme_data: Res<ThemeData>,
+    mut commands: Commands,
+) {
+    for (swatch, interaction) in &q_swatches {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+        let Some(color) = swatch
+            .source
+            .color(&r_recent_colors, &r_theme_data.colors.palettes)
+        else {
+            continue;
+        };
+        let Ok((entity, mut picker)) = q_pickers.get_mut(swatch.picker) else {
+            continue;
+        };
+
+        picker.set_color(color);
+
+        #[cfg(feature = "observable")]
+        picker.changed(entity, &mut commands);
+    }
+}
+
+fn update_color_picker(
+    q_pickers: Query<&ColorPicker, Changed<ColorPicker>>,
+    q_inputs: Query<&ColorPickerInput>,
+    mut q_fields: Query<&mut NumericField>,
+    q_buttons: Query<&ColorPickerButton>,
+    mut images: ResMut<Assets<Image>>,
+    mut commands: Commands,
+) {
+    for picker in &q_pickers {
+        let color = picker.color;
+
+        if let Some(image) = images.get_mut(&picker.saturation_value_image) {
+            ColorPicker::fill_image(image, |x, y| Hsva::new(color.hue, x, 1. - y, 1.).into());
+        }
+        if let Some(image) = images.get_mut(&picker.alpha_image) {
+            let opaque = Srgba::from(color.with_alpha(1.));
+            ColorPicker::fill_image(image, |x, y| {
+                let cell_x = (x * (STRIP_IMAGE_WIDTH - 1) as f32) as u32 / CHECKER_SIZE;
+                let cell_y = (y * (ALPHA_IMAGE_HEIGHT - 1) as f32) as u32 / CHECKER_SIZE;
+                let checker = match (cell_x + cell_y) % 2 {
+                    0 => Srgba::rgb(0.8, 0.8, 0.8),
+                    _ => Srgba::rgb(0.5, 0.5, 0.5),
+                };
+
+                checker.mix(&opaque, x)
+            });
+        }
+
+        commands
+            .style_unchecked(picker.saturation_value_handle)
+            .left(Val::Percent(color.saturation * 100.))
+            .top(Val::Percent((1. - color.value) * 100.));
+        commands
+            .style_unchecked(picker.hue_handle)
+            .left(Val::Percent(color.hue / 360. * 100.));
+        commands
+            .style_unchecked(picker.alpha_handle)
+            .left(Val::Percent(color.alpha * 100.));
+        commands
+            .style_unchecked(picker.preview)
+            .background_color(picker.color());
+
+        let hex = match picker.hex_editing {
+            true => format!("{}|", picker.hex_buffer),
+            false => Srgba::from(color).to_hex(),
+        };
+        commands.entity(picker.hex_label).update_text(hex);
+
+        if let Some(button) = picker.button.and_then(|button| q_buttons.get(button).ok()) {
+            commands
+                .style_unchecked(button.swatch)
+                .background_color(picker.color());
+        }
+    }
+
+    for input in &q_inputs {
+        let Ok(picker) = q_pickers.get(input.picker) else {
+            continue;
+        };
+        let Ok(mut field) = q_fields.get_mut(input.field) else {
+            continue;
+        };
+        if field.is_editing() || field.is_scrubbing() {
+            continue;
+        }
+
+        let value = field.config().constrain(input.channel.value(picker.color));
+        if field.value() != value {
+            field.set_value(value);
+        }
+    }
+}
+
+fn update_recent_color_swatches(
+    r_recent_colors: Res<RecentColors>,
+    q_swatches: Query<(Entity, &ColorPickerSwatch)>,
+    q_added_swatches: Query<Entity, Added<ColorPickerSwatch>>,
+    mut commands: Commands,
+) {
+    if !r_recent_colors.is_changed() && q_added_swatches.is_empty() {
+        return;
+    }
+
+    for (entity, swatch) in &q_swatches {
+        let ColorPickerSwatchSource::Recent(index) = swatch.source else {
+            continue;
+        };
+
+        match r_recent_colors.colors.get(index) {
+            Some(color) => commands
+                .style_unchecked(entity)
+                .display(Display::Flex)
+                .background_color(*color),
+            None => commands.style_unchecked(entity).display(Display::None),
+        };
+    }
+}
+
+fn update_color_picker_popover(
+    q_buttons: Query<(Entity, &ColorPickerButton), Changed<ColorPickerButton>>,
+    mut commands: Commands,
+) {
+    for (entity, button) in &q_buttons {
+        if button.is_open {
+            commands
+                .style_unchecked(button.panel)
+                .display(Display::Flex)
+                .visibility(Visibility::Inherited);
+            commands.entity(entity).add_pseudo_state(PseudoState::Open);
+        } else {
+            commands
+                .style_unchecked(button.panel)
+                .display(Display::None)
+                .visibility(Visibility::Hidden);
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Open);
+        }
+    }
+}
+
+/// Colors recently picked with any [`ColorPicker`], most recent first
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct RecentColors {
+    colors: Vec<Color>,
+}
+
+impl RecentColors {
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Moves the color to the front, dropping the oldest color when full
+    pub fn push(&mut self, color: Color) {
+        if self.colors.first() == Some(&color) {
+            return;
+        }
+
+        self.colors.retain(|recent| *recent != color);
+        self.colors.insert(0, color);
+        self.colors.truncate(RECENT_COLORS_CAPACITY);
+    }
+
+    pub fn clear(&mut self) {
+        self.colors.clear();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ColorPickerAreaKind {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPickerArea {
+    picker: Entity,
+    kind: ColorPickerAreaKind,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPickerHexField {
+    picker: Entity,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ColorPickerChannel {
+    /// sRGB red, 0-255
+    Red,
+    /// sRGB green, 0-255
+    Green,
+    /// sRGB blue, 0-255
+    Blue,
+    /// Hue in degrees
+    Hue,
+    /// HSL saturation, in percent
+    Saturation,
+    /// HSL lightness, in percent
+    Lightness,
+}
+
+impl ColorPickerChannel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorPickerChannel::Red => "R",
+            ColorPickerChannel::Green => "G",
+            ColorPickerChannel::Blue => "B",
+            ColorPickerChannel::Hue => "H",
+            ColorPickerChannel::Saturation => "S",
+            ColorPickerChannel::Lightness => "L",
+        }
+    }
+
+    pub fn value(&self, color: Hsva) -> f32 {
+        match self {
+            ColorPickerChannel::Red => Srgba::from(color).red * 255.,
+            ColorPickerChannel::Green => Srgba::from(color).green * 255.,
+            ColorPickerChannel::Blue => Srgba::from(color).blue * 255.,
+            ColorPickerChannel::Hue => color.hue,
+            ColorPickerChannel::Saturation => Hsla::from(color).saturation * 100.,
+            ColorPickerChannel::Lightness => Hsla::from(color).lightness * 100.,
+        }
+    }
+
+    /// The color with this channel set to `value`
+    pub fn with_value(&self, color: Hsva, value: f32) -> Color {
+        match self {
+            ColorPickerChannel::Red => Srgba::from(color).with_red(value / 255.).into(),
+            ColorPickerChannel::Green => Srgba::from(color).with_green(value / 255.).into(),
+            ColorPickerChannel::Blue => Srgba::from(color).with_blue(value / 255.).into(),
+            ColorPickerChannel::Hue => color.with_hue(value).into(),
+            ColorPickerChannel::Saturation => {
+                Hsla::from(color).with_saturation(value / 100.).into()
+            }
+            ColorPickerChannel::Lightness => Hsla::from(color).with_lightness(value / 100.).into(),
+        }
+    }
+
+    fn config(&self) -> NumericFieldConfig {
+        let config = NumericFieldConfig::default()
+            .with_step(1.)
+            .with_precision(0)
+            .with_drag_speed(1.);
+
+        match self {
+            ColorPickerChannel::Red | ColorPickerChannel::Green | ColorPickerChannel::Blue => {
+                config.with_range(0., 255.)
+            }
+            ColorPickerChannel::Hue => config.with_range(0., 360.).with_suffix("°"),
+            ColorPickerChannel::Saturation | ColorPickerChannel::Lightness => {
+                config.with_range(0., 100.).with_suffix("%")
+            }
+        }
+    }
+}
+
+/// A labeled numeric field editing a single channel of a [`ColorPicker`]
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPickerInput {
+    picker: Entity,
+    channel: ColorPickerChannel,
+    label: Entity,
+    field: Entity,
+}
+
+impl Default for ColorPickerInput {
+    fn default() -> Self {
+        Self {
+            picker: Entity::PLACEHOLDER,
+            channel: ColorPickerChannel::Red,
+            label: Entity::PLACEHOLDER,
+            field: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for ColorPickerInput {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ColorPickerInput::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for ColorPickerInput. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [ColorPickerInput::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for ColorPickerInput {
+    fn default_theme() -> Option<Theme<ColorPickerInput>> {
+        ColorPickerInput::theme().into()
+    }
+}
+
+impl ColorPickerInput {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn channel(&self) -> ColorPickerChannel {
+        self.channel
+    }
+
+    pub fn theme() -> Theme<ColorPickerInput> {
+        let base_theme = PseudoTheme::deferred(None, ColorPickerInput::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Label, FontScale::Medium, FontType::Bold);
+
+        style_builder
+            .flex_grow(1.)
+            .flex_basis(Val::Px(0.))
+            .align_items(AlignItems::Center);
+
+        style_builder
+            .switch_target(ColorPickerInput::LABEL)
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.small)))
+            .sized_font(font)
+            .font_color(colors.on(OnColor::SurfaceVariant));
+    }
+
+    fn frame(channel: ColorPickerChannel) -> impl Bundle {
+        (
+            Name::new(format!("Channel [{}]", channel.name())),
+            NodeBundle::default(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ColorPickerPalette {
+    Primary,
+    Secondary,
+    Tertiary,
+    Neutral,
+    NeutralVariant,
+}
+
+impl ColorPickerPalette {
+    pub fn all() -> [ColorPickerPalette; 5] {
+        [
+            ColorPickerPalette::Primary,
+            ColorPickerPalette::Secondary,
+            ColorPickerPalette::Tertiary,
+            ColorPickerPalette::Neutral,
+            ColorPickerPalette::NeutralVariant,
+        ]
+    }
+
+    pub fn of<'a>(&self, palettes: &'a ColorPalettes) -> &'a ColorPalette {
+        match self {
+            ColorPickerPalette::Primary => &palettes.primary,
+            ColorPickerPalette::Secondary => &palettes.secondary,
+            ColorPickerPalette::Tertiary => &palettes.tertiary,
+            ColorPickerPalette::Neutral => &palettes.neutral,
+            ColorPickerPalette::NeutralVariant => &palettes.neutral_variant,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ColorPickerSwatchSource {
+    /// Index into [`RecentColors`]
+    Recent(usize),
+    /// A tone of a palette of the active theme
+    Palette(ColorPickerPalette, u8),
+}
+
+impl ColorPickerSwatchSource {
+    pub fn color(&self, recent_colors: &RecentColors, palettes: &ColorPalettes) -> Option<Color> {
+        match self {
+            ColorPickerSwatchSource::Recent(index) => recent_colors.colors.get(*index).copied(),
+            ColorPickerSwatchSource::Palette(palette, tone) => palette.of(palettes).tone(*tone),
+        }
+    }
+}
+
+/// A color that can be picked with a single press
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPickerSwatch {
+    picker: Entity,
+    source: ColorPickerSwatchSource,
+}
+
+impl Default for ColorPickerSwatch {
+    fn default() -> Self {
+        Self {
+            picker: Entity::PLACEHOLDER,
+            source: ColorPickerSwatchSource::Recent(0),
+        }
+    }
+}
+
+impl UiContext for ColorPickerSwatch {}
+
+impl DefaultTheme for ColorPickerSwatch {
+    fn default_theme() -> Option<Theme<ColorPickerSwatch>> {
+        ColorPickerSwatch::theme().into()
+    }
+}
+
+impl ColorPickerSwatch {
+    pub fn source(&self) -> ColorPickerSwatchSource {
+        self.source
+    }
+
+    pub fn theme() -> Theme<ColorPickerSwatch> {
+        let base_theme = PseudoTheme::deferred_context(None, ColorPickerSwatch::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        swatch: &ColorPickerSwatch,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .flex_grow(1.)
+            .flex_basis(Val::Px(0.))
+            .height(Val::Px(theme_spacing.areas.extra_small))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.tiny)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.tiny)))
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::OutlineVariant),
+                hover: colors.on(OnColor::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        if let ColorPickerSwatchSource::Palette(palette, tone) = swatch.source {
+            if let Some(color) = palette.of(&theme_data.colors.palettes).tone(tone) {
+                style_builder.background_color(color);
+            }
+        }
+    }
+
+    fn frame(source: ColorPickerSwatchSource) -> impl Bundle {
+        let name = match source {
+            ColorPickerSwatchSource::Recent(index) => format!("Recent Color {}", index),
+            ColorPickerSwatchSource::Palette(palette, tone) => {
+                format!("Palette Color [{:?} {}]", palette, tone)
+            }
+        };
+
+        (
+            Name::new(name),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ColorPickerConfig {
+    pub color: Color,
+    /// Offer the tones of the active theme's palettes as swatches
+    pub palette: bool,
+}
+
+impl Default for ColorPickerConfig {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            palette: false,
+        }
+    }
+}
+
+impl ColorPickerConfig {
+    pub fn new(color: Color) -> Self {
+        Self { color, ..default() }
+    }
+
+    pub fn with_palette(self) -> Self {
+        Self {
+            palette: true,
+            ..self
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPicker {
+    color: Hsva,
+    button: Option<Entity>,
+    saturation_value: Entity,
+    saturation_value_handle: Entity,
+    hue: Entity,
+    hue_handle: Entity,
+    alpha: Entity,
+    alpha_handle: Entity,
+    preview: Entity,
+    hex_field: Entity,
+    hex_label: Entity,
+    recent_colors: Entity,
+    palette: Entity,
+    saturation_value_image: Handle<Image>,
+    hue_image: Handle<Image>,
+    alpha_image: Handle<Image>,
+    hex_editing: bool,
+    hex_buffer: String,
+}
+
+impl Default for ColorPicker {
+    fn default() -> Self {
+        Self {
+            color: Hsva::new(0., 0., 1., 1.),
+            button: None,
+            saturation_value: Entity::PLACEHOLDER,
+            saturation_value_handle: Entity::PLACEHOLDER,
+            hue: Entity::PLACEHOLDER,
+            hue_handle: Entity::PLACEHOLDER,
+            alpha: Entity::PLACEHOLDER,
+            alpha_handle: Entity::PLACEHOLDER,
+            preview: Entity::PLACEHOLDER,
+            hex_field: Entity::PLACEHOLDER,
+            hex_label: Entity::PLACEHOLDER,
+            recent_colors: Entity::PLACEHOLDER,
+            palette: Entity::PLACEHOLDER,
+            saturation_value_image: Handle::default(),
+            hue_image: Handle::default(),
+            alpha_image: Handle::default(),
+            hex_editing: false,
+            hex_buffer: "".into(),
+        }
+    }
+}
+
+impl UiContext for ColorPicker {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ColorPicker::SATURATION_VALUE => Ok(self.saturation_value),
+            ColorPicker::SATURATION_VALUE_HANDLE => Ok(self.saturation_value_handle),
+            ColorPicker::HUE => Ok(self.hue),
+            ColorPicker::HUE_HANDLE => Ok(self.hue_handle),
+            ColorPicker::ALPHA => Ok(self.alpha),
+            ColorPicker::ALPHA_HANDLE => Ok(self.alpha_handle),
+            ColorPicker::PREVIEW => Ok(self.preview),
+            ColorPicker::HEX_FIELD => Ok(self.hex_field),
+            ColorPicker::HEX_LABEL => Ok(self.hex_label),
+            ColorPicker::RECENT_COLORS => Ok(self.recent_colors),
+            ColorPicker::PALETTE => Ok(self.palette),
+            _ => Err(format!(
+                "{} doesn't exist for ColorPicker. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            ColorPicker::SATURATION_VALUE,
+            ColorPicker::SATURATION_VALUE_HANDLE,
+            ColorPicker::HUE,
+            ColorPicker::HUE_HANDLE,
+            ColorPicker::ALPHA,
+            ColorPicker::ALPHA_HANDLE,
+            ColorPicker::PREVIEW,
+            ColorPicker::HEX_FIELD,
+            ColorPicker::HEX_LABEL,
+            ColorPicker::RECENT_COLORS,
+            ColorPicker::PALETTE,
+        ]
+        .into_iter()
+    }
+}
+
+impl DefaultTheme for ColorPicker {
+    fn default_theme() -> Option<Theme<ColorPicker>> {
+        ColorPicker::theme().into()
+    }
+}
+
+impl ColorPicker {
+    pub const SATURATION_VALUE: &'static str = "SaturationValue";
+    pub const SATURATION_VALUE_HANDLE: &'static str = "SaturationValueHandle";
+    pub const HUE: &'static str = "Hue";
+    pub const HUE_HANDLE: &'static str = "HueHandle";
+    pub const ALPHA: &'static str = "Alpha";
+    pub const ALPHA_HANDLE: &'static str = "AlphaHandle";
+    pub const PREVIEW: &'static str = "Preview";
+    pub const HEX_FIELD: &'static str = "HexField";
+    pub const HEX_LABEL: &'static str = "HexLabel";
+    pub const RECENT_COLORS: &'static str = "RecentColors";
+    pub const PALETTE: &'static str = "Palette";
+
+    pub fn color(&self) -> Color {
+        self.color.into()
+    }
+
+    /// Sets the color, keeping the hue (and saturation) when the color is gray (or black)
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        let mut color = Hsva::from(color.into());
+        if color.value == 0. {
+            color.hue = self.color.hue;
+            color.saturation = self.color.saturation;
+        } else if color.saturation == 0. {
+            color.hue = self.color.hue;
+        }
+
+        if self.color != color {
+            self.color = color;
+        }
+    }
+
+    /// The color picker button this picker is shown by, if any
+    pub fn button(&self) -> Option<Entity> {
+        self.button
+    }
+
+    #[cfg(feature = "observable")]
+    fn changed(&self, entity: Entity, commands: &mut Commands) {
+        let event = ColorChanged {
+            color: self.color(),
+        };
+
+        commands.trigger_targets(event, entity);
+        if let Some(button) = self.button {
+            commands.trigger_targets(event, button);
+        }
+    }
+
+    /// Returns `true` if the typed color was applied
+    fn stop_hex_editing(&mut self, entity: Entity, commit: bool, commands: &mut Commands) -> bool {
+        self.hex_editing = false;
+        commands
+            .entity(entity)
+            .remove_pseudo_state(PseudoState::Selected);
+
+        let buffer = std::mem::take(&mut self.hex_buffer);
+        if !commit {
+            return false;
+        }
+        let Ok(color) = Srgba::hex(&buffer) else {
+            return false;
+        };
+
+        let previous = self.color;
+        self.set_color(color);
+        if self.color == previous {
+            return false;
+        }
+
+        #[cfg(feature = "observable")]
+        self.changed(entity, commands);
+
+        true
+    }
+
+    fn new_image(width: u32, height: u32) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 255, 255, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    /// Fills the image with colors by normalized texel position
+    fn fill_image(image: &mut Image, color_at: impl Fn(f32, f32) -> Srgba) {
+        let size = image.size();
+        let max_x = (size.x.max(2) - 1) as f32;
+        let max_y = (size.y.max(2) - 1) as f32;
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let index = ((y * size.x + x) * 4) as usize;
+                let color = color_at(x as f32 / max_x, y as f32 / max_y);
+                image.data[index..index + 4].copy_from_slice(&color.to_u8_array());
+            }
+        }
+    }
+
+    pub fn theme() -> Theme<ColorPicker> {
+        let base_theme = PseudoTheme::deferred(None, ColorPicker::primary_style);
+        let hex_editing_theme =
+            PseudoTheme::deferred(vec![PseudoState::Selected], ColorPicker::hex_editing_style);
+
+        let error_theme =
+            PseudoTheme::deferred(vec![PseudoState::Error], ColorPicker::error_style);
+
+        Theme::new(vec![base_theme, hex_editing_theme, error_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let handle_size = theme_spacing.areas.tiny;
+        let handle_border = theme_spacing.borders.small;
+
+        style_builder
+            .flex_direction(FlexDirection::Column)
+            .width(Val::Px(
+                theme_spacing.areas.extra_large + theme_spacing.areas.custom_4,
+            ))
+            .row_gap(Val::Px(theme_spacing.gaps.small))
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.medium)));
+
+        style_builder
+            .switch_target(ColorPicker::SATURATION_VALUE)
+            .width(Val::Percent(100.))
+            .height(Val::Px(theme_spacing.areas.extra_large))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )));
+
+        style_builder
+            .switch_target(ColorPicker::SATURATION_VALUE_HANDLE)
+            .position_type(PositionType::Absolute)
+            .width(Val::Px(handle_size))
+            .height(Val::Px(handle_size))
+            .margin(UiRect {
+                left: Val::Px(-handle_size / 2.),
+                top: Val::Px(-handle_size / 2.),
+                ..default()
+            })
+            .border(UiRect::all(Val::Px(handle_border)))
+            .border_radius(BorderRadius::MAX)
+            .border_color(Color::WHITE);
+
+        for (strip, handle) in [
+            (ColorPicker::HUE, ColorPicker::HUE_HANDLE),
+            (ColorPicker::ALPHA, ColorPicker::ALPHA_HANDLE),
+        ] {
+            style_builder
+                .switch_target(strip)
+                .width(Val::Percent(100.))
+                .height(Val::Px(theme_spacing.areas.tiny + theme_spacing.gaps.small))
+                .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.tiny)));
+
+            style_builder
+                .switch_target(handle)
+                .position_type(PositionType::Absolute)
+                .width(Val::Px(handle_border * 2.))
+                .height(Val::Percent(100.))
+                .margin(UiRect::left(Val::Px(-handle_border)))
+                .background_color(Color::WHITE);
+        }
+
+        style_builder
+            .switch_target(ColorPicker::HUE)
+            .margin(UiRect::bottom(Val::Px(theme_spacing.gaps.small)));
+
+        style_builder
+            .switch_target(ColorPicker::PREVIEW)
+            .flex_shrink(0.)
+            .width(Val::Px(theme_spacing.areas.medium))
+            .height(Val::Px(theme_spacing.areas.medium))
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .border_color(colors.accent(Accent::OutlineVariant));
+
+        style_builder
+            .switch_target(ColorPicker::HEX_FIELD)
+            .justify_content(JustifyContent::Center)
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.small),
+                Val::Px(theme_spacing.gaps.extra_small),
+            ))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .border_color(colors.accent(Accent::OutlineVariant))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(ColorPicker::HEX_LABEL)
+            .sized_font(font)
+            .font_color(colors.on(OnColor::Surface));
+
+        style_builder
+            .switch_target(ColorPicker::RECENT_COLORS)
+            .width(Val::Percent(100.));
+
+        style_builder
+            .switch_target(ColorPicker::PALETTE)
+            .width(Val::Percent(100.));
+    }
+
+    fn hex_editing_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(ColorPicker::HEX_FIELD)
+            .border_color(colors.accent(Accent::Primary))
+            .background_color(colors.container(Container::SurfaceHighest));
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(ColorPicker::HEX_FIELD)
+            .border_color(colors.accent(Accent::Error));
+
+        style_builder
+            .switch_target(ColorPicker::PREVIEW)
+            .border_color(colors.accent(Accent::Error));
+    }
+
+    fn frame() -> impl Bundle {
+        (Name::new("Color Picker"), NodeBundle::default())
+    }
+
+    fn area(picker: Entity, kind: ColorPickerAreaKind) -> impl Bundle {
+        (
+            Name::new(format!("{:?}", kind)),
+            ImageBundle {
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
+            ColorPickerArea { picker, kind },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn handle() -> impl Bundle {
+        (
+            Name::new("Handle"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            BorderColor::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn preview(color: Color) -> impl Bundle {
+        (
+            Name::new("Preview"),
+            NodeBundle {
+                background_color: color.into(),
+                ..default()
+            },
+            BorderColor::default(),
+        )
+    }
+
+    fn hex_field(picker: Entity) -> impl Bundle {
+        (
+            Name::new("Hex Field"),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            ColorPickerHexField { picker },
+        )
+    }
+
+    fn scaffold<'a>(
+        builder: &'a mut UiBuilder<'_, Entity>,
+        config: ColorPickerConfig,
+        button: Option<Entity>,
+    ) -> UiBuilder<'a, Entity> {
+        let mut picker = ColorPicker {
+            button,
+            ..default()
+        };
+        picker.set_color(config.color);
+
+        let mut color_picker = builder.container(ColorPicker::frame(), |container| {
+            let picker_id = container.id();
+
+            picker.saturation_value = container
+                .container(
+                    ColorPicker::area(picker_id, ColorPickerAreaKind::SaturationValue),
+                    |area| {
+                        picker.saturation_value_handle = area.spawn(ColorPicker::handle()).id();
+                    },
+                )
+                .id();
+
+            container.row(|row| {
+                picker.preview = row.spawn(ColorPicker::preview(config.color)).id();
+                row.column(|column| {
+                    picker.hue = column
+                        .container(
+                            ColorPicker::area(picker_id, ColorPickerAreaKind::Hue),
+                            |area| {
+                                picker.hue_handle = area.spawn(ColorPicker::handle()).id();
+                            },
+                        )
+                        .id();
+                    picker.alpha = column
+                        .container(
+                            ColorPicker::area(picker_id, ColorPickerAreaKind::Alpha),
+                            |area| {
+                                picker.alpha_handle = area.spawn(ColorPicker::handle()).id();
+                            },
+                        )
+                        .id();
+                });
+            });
+
+            picker.hex_field = container
+                .container(ColorPicker::hex_field(picker_id), |field| {
+                    picker.hex_label = field
+                        .label(LabelConfig {
+                            label: Srgba::from(config.color).to_hex().into(),
+                            ..default()
+                        })
+                        .id();
+                })
+                .id();
+
+            for channels in [
+                [
+                    ColorPickerChannel::Red,
+                    ColorPickerChannel::Green,
+                    ColorPickerChannel::Blue,
+                ],
+                [
+                    ColorPickerChannel::Hue,
+                    ColorPickerChannel::Saturation,
+                    ColorPickerChannel::Lightness,
+                ],
+            ] {
+                container.row(|row| {
+                    for channel in channels {
+                        let mut label = Entity::PLACEHOLDER;
+                        let mut field = Entity::PLACEHOLDER;
+                        row.container(ColorPickerInput::frame(channel), |input| {
+                            label = input
+                                .label(LabelConfig {
+                                    label: channel.name().into(),
+                                    ..default()
+                                })
+                                .id();
+                            field = input
+                                .numeric_field(NumericFieldConfig {
+                                    value: channel.value(picker.color),
+                                    ..channel.config()
+                                })
+                                .id();
+
+                            input.insert(ColorPickerInput {
+                                picker: picker_id,
+                                channel,
+                                label,
+                                field,
+                            });
+                        });
+                    }
+                });
+            }
+
+            picker.recent_colors = container
+                .row(|row| {
+                    for index in 0..RECENT_COLORS_CAPACITY {
+                        let source = ColorPickerSwatchSource::Recent(index);
+                        row.spawn((
+                            ColorPickerSwatch::frame(source),
+                            ColorPickerSwatch {
+                                picker: picker_id,
+                                source,
+                            },
+                        ));
+                    }
+                })
+                .insert(Name::new("Recent Colors"))
+                .id();
+
+            if config.palette {
+                picker.palette = container
+                    .column(|column| {
+                        for palette in ColorPickerPalette::all() {
+                            column.row(|row| {
+                                for tone in PALETTE_TONES {
+                                    let source = ColorPickerSwatchSource::Palette(palette, tone);
+                                    row.spawn((
+                                        ColorPickerSwatch::frame(source),
+                                        ColorPickerSwatch {
+                                            picker: picker_id,
+                                            source,
+                                        },
+                                    ));
+                                }
+                            });
+                        }
+                    })
+                    .insert(Name::new("Palette"))
+                    .id();
+            }
+        });
+
+        color_picker.insert(picker);
+
+        color_picker
+    }
+}
+
+/// A swatch of the picked color, showing a [`ColorPicker`] in a popover when pressed
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ColorPickerButton {
+    picker: Entity,
+    swatch: Entity,
+    panel: Entity,
+    is_open: bool,
+}
+
+impl Default for ColorPickerButton {
+    fn default() -> Self {
+        Self {
+            picker: Entity::PLACEHOLDER,
+            swatch: Entity::PLACEHOLDER,
+            panel: Entity::PLACEHOLDER,
+            is_open: false,
+        }
+    }
+}
+
+impl UiContext for ColorPickerButton {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ColorPickerButton::SWATCH => Ok(self.swatch),
+            ColorPickerButton::PANEL => Ok(self.panel),
+            _ => Err(format!(
+                "{} doesn't exist for ColorPickerButton. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [ColorPickerButton::SWATCH, ColorPickerButton::PANEL].into_iter()
+    }
+}
+
+impl DefaultTheme for ColorPickerButton {
+    fn default_theme() -> Option<Theme<ColorPickerButton>> {
+        ColorPickerButton::theme().into()
+    }
+}
+
+impl ColorPickerButton {
+    pub const SWATCH: &'static str = "Swatch";
+    pub const PANEL: &'static str = "Panel";
+
+    /// The [`ColorPicker`] shown in the popover
+    pub fn picker(&self) -> Entity {
+        self.picker
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn set_open(&mut self, open: bool) {
+        if self.is_open != open {
+            self.is_open = open;
+        }
+    }
+
+    pub fn theme() -> Theme<ColorPickerButton> {
+        let base_theme = PseudoTheme::deferred(None, ColorPickerButton::primary_style);
+        let open_theme =
+            PseudoTheme::deferred(vec![PseudoState::Open], ColorPickerButton::open_style);
+
+        Theme::new(vec![base_theme, open_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Px(theme_spacing.areas.large))
+            .height(Val::Px(theme_spacing.areas.small))
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.extra_small)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .border_color(colors.accent(Accent::OutlineVariant))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(ColorPickerButton::SWATCH)
+            .width(Val::Percent(100.))
+            .height(Val::Percent(100.))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.tiny)));
+
+        style_builder
+            .switch_target(ColorPickerButton::PANEL)
+            .position_type(PositionType::Absolute)
+            .top(Val::Percent(100.))
+            .left(Val::Px(0.))
+            .margin(UiRect::top(Val::Px(theme_spacing.gaps.small)))
+            .z_index(ZIndex::Global(COLOR_PICKER_POPOVER_Z_INDEX as i32))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.small)))
+            .border_color(colors.accent(Accent::OutlineVariant))
+            .background_color(colors.container(Container::SurfaceLow));
+    }
+
+    fn open_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.border_color(colors.accent(Accent::Primary));
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Color Picker Button"),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn swatch(color: Color) -> impl Bundle {
+        (
+            Name::new("Swatch"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                background_color: color.into(),
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn panel() -> impl Bundle {
+        (
+            Name::new("Color Picker Popover"),
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            BorderColor::default(),
+            LockedStyleAttributes::from_vec(vec![
+                LockableStyleAttribute::FocusPolicy,
+                LockableStyleAttribute::Display,
+                LockableStyleAttribute::Visibility,
+            ]),
+        )
+    }
+}
+
+pub trait UiColorPickerExt {
+    fn color_picker(&mut self, config: ColorPickerConfig) -> UiBuilder<Entity>;
+
+    fn color_picker_button(&mut self, config: ColorPickerConfig) -> UiBuilder<Entity>;
+}
+
+impl UiColorPickerExt for UiBuilder<'_, Entity> {
+    /// A color picker with a saturation / value area, hue and alpha strips, hex, RGB and
+    /// HSL entry, recently picked colors and optionally the active theme's palettes.
+    ///
+    /// Triggers `ColorChanged` when the color is changed by the user.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Selected`, while the hex value is being typed
+    /// - `PseudoState::Error`, when a `Validation` on it fails
+    fn color_picker(&mut self, config: ColorPickerConfig) -> UiBuilder<Entity> {
+        ColorPicker::scaffold(self, config, None)
+    }
+
+    /// A compact swatch button that shows a [`ColorPicker`] in a popover when pressed.
+    ///
+    /// Pressing outside the popover closes it. `ColorChanged` is triggered on both the
+    /// button and the picker.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Open`, while the popover is shown
+    fn color_picker_button(&mut self, config: ColorPickerConfig) -> UiBuilder<Entity> {
+        let mut color_picker_button = ColorPickerButton::default();
+
+        let mut button = self.container(ColorPickerButton::frame(), |container| {
+            let button_id = container.id();
+            color_picker_button.swatch = container
+                .spawn(ColorPickerButton::swatch(config.color))
+                .id();
+            color_picker_button.panel = container
+                .container(ColorPickerButton::panel(), |panel| {
+                    color_picker_button.picker =
+                        ColorPicker::scaffold(panel, config, button_id.into()).id();
+                })
+                .id();
+        });
+
+        button.insert(color_picker_button);
+
+        button
+    }
+}
@@ -1,6 +1,11 @@
-use std::collections::VecDeque;
-
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    ui::FocusPolicy,
+};
 
 use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
 
@@ -14,9 +19,23 @@ use crate::widgets::layout::{
 const DROPDOWN_PANEL_Z_INDEX: usize = 11000;
 
 #[cfg(feature = "observable")]
-#[derive(Event, Copy, Clone, Debug)]
+#[derive(Event, Clone, Debug)]
 pub struct DropdownChanged {
+    /// Index of the selected option, `None` if the value was cleared or free text was entered
     pub value: Option<usize>,
+    /// The user value of the selected option, or the text entered in a combobox
+    pub user_value: Option<String>,
+}
+
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct DropdownSelectionChanged {
+    /// Indices of the selected options, in option order
+    pub selection: Vec<usize>,
+    /// Tags entered in a multi-select combobox, in the order they were added
+    pub tags: Vec<String>,
+    /// User values of the selected options, followed by the tags
+    pub user_values: Vec<String>,
 }
 
 pub struct DropdownPlugin;
@@ -26,13 +45,20 @@ impl Plugin for DropdownPlugin {
         app.add_plugins((
             ComponentThemePlugin::<Dropdown>::default(),
             ComponentThemePlugin::<DropdownOption>::default(),
+            ComponentThemePlugin::<DropdownOptionGroup>::default(),
+            ComponentThemePlugin::<DropdownChip>::default(),
         ))
         .add_systems(
             Update,
             (
                 handle_option_press,
-                update_dropdown_label,
+                handle_chip_close_press,
                 handle_click_or_touch,
+                handle_dropdown_keyboard,
+                update_dropdown_label,
+                update_dropdown_chips,
+                update_dropdown_options,
+                scroll_to_highlighted_option,
                 update_drowdown_pseudo_state,
                 update_dropdown_panel_visibility,
             )
@@ -42,7 +68,8 @@ impl Plugin for DropdownPlugin {
         );
 
         #[cfg(feature = "observable")]
-        app.add_event::<DropdownChanged>();
+        app.add_event::<DropdownChanged>()
+            .add_event::<DropdownSelectionChanged>();
     }
 }
 
@@ -56,14 +83,45 @@ fn update_dropdown_label(
                 dropdown.value = None;
             }
         }
+        if dropdown
+            .selection
+            .iter()
+            .any(|option| *option >= options.0.len())
+        {
+            dropdown
+                .selection
+                .retain(|option| *option < options.0.len());
+        }
+
+        if dropdown.selection_mode == DropdownSelectionMode::Multiple {
+            // Chips replace the label once anything is selected
+            let display = match dropdown.selection.is_empty() && dropdown.tags.is_empty() {
+                true => Display::Flex,
+                false => Display::None,
+            };
+            commands.style_unchecked(dropdown.label).display(display);
+        }
 
         let text = if let Some(value) = dropdown.value {
-            options.0[value].clone()
+            options.0[value].label.clone()
+        } else if let Some(custom_value) = &dropdown.custom_value {
+            custom_value.into()
         } else {
-            String::from("---")
+            "---".into()
         };
 
         commands.entity(dropdown.label).update_text(text);
+
+        if dropdown.filter_label != Entity::PLACEHOLDER {
+            let filter_text = match dropdown.filter.is_empty() {
+                true => dropdown.mode.placeholder(),
+                false => dropdown.filter.clone().into(),
+            };
+
+            commands
+                .entity(dropdown.filter_label)
+                .update_text(filter_text);
+        }
     }
 }
 
@@ -71,6 +129,7 @@ fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
     r_touches: Res<Touches>,
     mut q_dropdowns: Query<(Entity, &mut Dropdown, &FluxInteraction)>,
+    q_panels: Query<&FluxInteraction, (With<DropdownPanel>, Without<Dropdown>)>,
 ) {
     if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
         || r_touches.any_just_released()
@@ -86,12 +145,24 @@ fn handle_click_or_touch(
         for (entity, mut dropdown, _) in &mut q_dropdowns {
             if let Some(open_dropdown) = open {
                 if entity == open_dropdown {
-                    dropdown.is_open = !dropdown.is_open;
+                    if dropdown.is_open {
+                        dropdown.is_open = false;
+                    } else {
+                        dropdown.open();
+                    }
                 } else if dropdown.is_open {
                     dropdown.is_open = false;
                 }
             } else if dropdown.is_open {
-                dropdown.is_open = false;
+                // Releases inside the panel (filter field, disabled options, scroll bars)
+                // keep it open. Selecting an option closes it in `handle_option_press`.
+                let released_in_panel = q_panels
+                    .get(dropdown.panel)
+                    .is_ok_and(|interaction| *interaction == FluxInteraction::Released);
+
+                if !released_in_panel {
+                    dropdown.is_open = false;
+                }
             }
         }
     }
@@ -99,21 +170,387 @@ fn handle_click_or_touch(
 
 fn handle_option_press(
     q_options: Query<(&DropdownOption, &FluxInteraction), Changed<FluxInteraction>>,
-    mut q_dropdown: Query<&mut Dropdown>,
-    mut commands: Commands
+    mut q_dropdown: Query<(&mut Dropdown, &DropdownOptions)>,
+    mut commands: Commands,
 ) {
     for (option, interaction) in &q_options {
         if *interaction == FluxInteraction::Released {
-            let Ok(mut dropdown) = q_dropdown.get_mut(option.dropdown) else {
+            let Ok((mut dropdown, options)) = q_dropdown.get_mut(option.dropdown) else {
+                continue;
+            };
+            let Some(config) = options.get(option.option) else {
                 continue;
             };
+            if !config.enabled {
+                continue;
+            }
+
+            if dropdown.selection_mode == DropdownSelectionMode::Multiple {
+                dropdown.toggle(option.option);
 
-            dropdown.value = option.option.into();
+                #[cfg(feature = "observable")]
+                commands.trigger_targets(dropdown.selection_changed(options), option.dropdown);
 
+                continue;
+            }
+
+            dropdown.select(option.option);
+            dropdown.is_open = false;
+
+            #[cfg(feature = "observable")]
+            commands.trigger_targets(
+                DropdownChanged {
+                    value: dropdown.value,
+                    user_value: config.user_value.clone().into(),
+                },
+                option.dropdown,
+            );
+        }
+    }
+}
+
+fn handle_chip_close_press(
+    q_chips: Query<&DropdownChip>,
+    q_close_buttons: Query<&FluxInteraction, Changed<FluxInteraction>>,
+    mut q_dropdowns: Query<(&mut Dropdown, &DropdownOptions)>,
+    mut commands: Commands,
+) {
+    for chip in &q_chips {
+        let Ok(interaction) = q_close_buttons.get(chip.close) else {
+            continue;
+        };
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+        let Ok((mut dropdown, options)) = q_dropdowns.get_mut(chip.dropdown) else {
+            continue;
+        };
+
+        if dropdown.remove(&chip.value) {
             #[cfg(feature = "observable")]
-            commands.trigger_targets(DropdownChanged {
-                value: dropdown.value
-            }, option.dropdown);
+            commands.trigger_targets(dropdown.selection_changed(options), chip.dropdown);
+        }
+    }
+}
+
+fn handle_dropdown_keyboard(
+    mut ev_keyboard: EventReader<KeyboardInput>,
+    mut q_dropdowns: Query<(Entity, &mut Dropdown, &DropdownOptions)>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    let pressed: Vec<&KeyboardInput> = ev_keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .collect();
+    if pressed.is_empty() {
+        return;
+    }
+
+    for (entity, mut dropdown, options) in &mut q_dropdowns {
+        if !dropdown.is_open {
+            continue;
+        }
+
+        let has_filter = dropdown.mode.has_filter();
+        let multiple = dropdown.selection_mode == DropdownSelectionMode::Multiple;
+        let mut filter_changed = false;
+        for input in &pressed {
+            match &input.logical_key {
+                Key::Enter if multiple => {
+                    if let Some(highlighted) = dropdown.highlighted {
+                        dropdown.toggle(highlighted);
+                    } else if dropdown.mode == DropdownMode::Combobox
+                        && !dropdown.filter.trim().is_empty()
+                    {
+                        let tag = dropdown.filter.trim().to_string();
+                        dropdown.filter.clear();
+                        filter_changed = true;
+
+                        if !dropdown.add_tag(tag) {
+                            continue;
+                        }
+                    } else {
+                        continue;
+                    }
+
+                    #[cfg(feature = "observable")]
+                    commands.trigger_targets(dropdown.selection_changed(options), entity);
+                }
+                Key::Backspace if multiple && dropdown.filter.is_empty() => {
+                    let removed = dropdown.pop_selection();
+
+                    #[cfg(feature = "observable")]
+                    if removed {
+                        commands.trigger_targets(dropdown.selection_changed(options), entity);
+                    }
+                }
+                Key::ArrowDown => {
+                    dropdown.highlighted =
+                        options.step(&dropdown.filter, &locale, dropdown.highlighted, 1);
+                }
+                Key::ArrowUp => {
+                    dropdown.highlighted =
+                        options.step(&dropdown.filter, &locale, dropdown.highlighted, -1);
+                }
+                Key::PageDown => {
+                    dropdown.highlighted =
+                        options.step(&dropdown.filter, &locale, dropdown.highlighted, 5);
+                }
+                Key::PageUp => {
+                    dropdown.highlighted =
+                        options.step(&dropdown.filter, &locale, dropdown.highlighted, -5);
+                }
+                Key::Home => {
+                    dropdown.highlighted = options
+                        .navigable(&dropdown.filter, &locale)
+                        .first()
+                        .copied();
+                }
+                Key::End => {
+                    dropdown.highlighted =
+                        options.navigable(&dropdown.filter, &locale).last().copied();
+                }
+                Key::Enter => {
+                    let user_value = if let Some(highlighted) = dropdown.highlighted {
+                        dropdown.select(highlighted);
+                        options
+                            .get(highlighted)
+                            .map(|option| option.user_value.clone())
+                    } else if dropdown.mode == DropdownMode::Combobox && !dropdown.filter.is_empty()
+                    {
+                        let text = dropdown.filter.clone();
+                        dropdown.value = None;
+                        dropdown.custom_value = text.clone().into();
+                        text.into()
+                    } else {
+                        dropdown.is_open = false;
+                        break;
+                    };
+
+                    dropdown.is_open = false;
+
+                    #[cfg(feature = "observable")]
+                    commands.trigger_targets(
+                        DropdownChanged {
+                            value: dropdown.value,
+                            user_value,
+                        },
+                        entity,
+                    );
+                    break;
+                }
+                Key::Escape => {
+                    dropdown.is_open = false;
+                    break;
+                }
+                Key::Backspace if has_filter => {
+                    filter_changed |= dropdown.filter.pop().is_some();
+                }
+                Key::Space if has_filter => {
+                    dropdown.filter.push(' ');
+                    filter_changed = true;
+                }
+                Key::Character(chars) if has_filter => {
+                    let len = dropdown.filter.len();
+                    dropdown
+                        .filter
+                        .extend(chars.chars().filter(|c| !c.is_control()));
+                    filter_changed |= dropdown.filter.len() != len;
+                }
+                _ => (),
+            }
+        }
+
+        if filter_changed && dropdown.is_open {
+            // Typed text is a value on its own in a combobox, only arrows pick options
+            let navigable = options.navigable(&dropdown.filter, &locale);
+            dropdown.highlighted = match dropdown.mode {
+                DropdownMode::Combobox => None,
+                _ => dropdown
+                    .highlighted
+                    .filter(|highlighted| navigable.contains(highlighted))
+                    .or(navigable.first().copied()),
+            };
+        }
+    }
+}
+
+fn update_dropdown_options(
+    q_dropdowns: Query<(&Dropdown, &DropdownOptions), Changed<Dropdown>>,
+    q_children: Query<&Children>,
+    q_options: Query<(&DropdownOption, &Style, Option<&PseudoStates>)>,
+    q_groups: Query<(&DropdownOptionGroup, &Style)>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    for (dropdown, options) in &q_dropdowns {
+        let Ok(children) = q_children.get(dropdown.scroll_view_content) else {
+            continue;
+        };
+
+        let matching: Vec<bool> = options
+            .0
+            .iter()
+            .map(|option| option.matches(&dropdown.filter, &locale))
+            .collect();
+
+        for child in children.iter() {
+            let (visible, style) = if let Ok((option, style, pseudo_states)) = q_options.get(*child)
+            {
+                let checked = dropdown.selection.contains(&option.option);
+                let was_checked =
+                    pseudo_states.is_some_and(|states| states.has(&PseudoState::Checked));
+                if checked && !was_checked {
+                    commands
+                        .entity(*child)
+                        .add_pseudo_state(PseudoState::Checked);
+                } else if !checked && was_checked {
+                    commands
+                        .entity(*child)
+                        .remove_pseudo_state(PseudoState::Checked);
+                }
+
+                let highlighted = dropdown.highlighted == Some(option.option);
+                let was_highlighted =
+                    pseudo_states.is_some_and(|states| states.has(&PseudoState::Selected));
+                if highlighted && !was_highlighted {
+                    commands
+                        .entity(*child)
+                        .add_pseudo_state(PseudoState::Selected);
+                } else if !highlighted && was_highlighted {
+                    commands
+                        .entity(*child)
+                        .remove_pseudo_state(PseudoState::Selected);
+                }
+
+                (matching.get(option.option).copied().unwrap_or(false), style)
+            } else if let Ok((group, style)) = q_groups.get(*child) {
+                let last = group.last_option.min(matching.len().saturating_sub(1));
+                let visible = group.first_option <= last
+                    && matching[group.first_option..=last].iter().any(|m| *m);
+
+                (visible, style)
+            } else {
+                continue;
+            };
+
+            let display = match visible {
+                true => Display::Flex,
+                false => Display::None,
+            };
+            if style.display != display {
+                commands.style_unchecked(*child).display(display);
+            }
+        }
+    }
+}
+
+fn update_dropdown_chips(
+    q_dropdowns: Query<(Entity, &Dropdown, &DropdownOptions), Changed<Dropdown>>,
+    q_children: Query<&Children>,
+    q_chips: Query<&DropdownChip>,
+    mut commands: Commands,
+) {
+    for (entity, dropdown, options) in &q_dropdowns {
+        if dropdown.selection_mode != DropdownSelectionMode::Multiple {
+            continue;
+        }
+
+        let chip_values: Vec<DropdownChipValue> = dropdown
+            .selection
+            .iter()
+            .map(|option| DropdownChipValue::Option(*option))
+            .chain(dropdown.tags.iter().cloned().map(DropdownChipValue::Tag))
+            .collect();
+
+        let mut existing: Vec<(Entity, &DropdownChip)> = match q_children.get(dropdown.chips) {
+            Ok(children) => children
+                .iter()
+                .filter_map(|child| q_chips.get(*child).ok().map(|chip| (*child, chip)))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        if existing
+            .iter()
+            .map(|(_, chip)| &chip.value)
+            .eq(chip_values.iter())
+        {
+            continue;
+        }
+
+        let mut ordered: Vec<Entity> = Vec::with_capacity(chip_values.len());
+        for value in chip_values {
+            if let Some(index) = existing.iter().position(|(_, chip)| chip.value == value) {
+                ordered.push(existing.swap_remove(index).0);
+                continue;
+            }
+
+            let label = match &value {
+                DropdownChipValue::Option(option) => options
+                    .get(*option)
+                    .map(|option| option.label.clone())
+                    .unwrap_or_default(),
+                DropdownChipValue::Tag(tag) => tag.into(),
+            };
+            let chip = DropdownChip::spawn(
+                &mut commands.ui_builder(dropdown.chips),
+                entity,
+                value,
+                label,
+            );
+            ordered.push(chip);
+        }
+
+        for (chip, _) in existing {
+            commands.entity(chip).despawn_recursive();
+        }
+
+        commands.entity(dropdown.chips).replace_children(&ordered);
+    }
+}
+
+fn scroll_to_highlighted_option(
+    q_dropdowns: Query<&Dropdown, Changed<Dropdown>>,
+    q_children: Query<&Children>,
+    q_options: Query<(&DropdownOption, &Node, &GlobalTransform)>,
+    q_nodes: Query<(&Node, &GlobalTransform), Without<DropdownOption>>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+) {
+    for dropdown in &q_dropdowns {
+        if !dropdown.is_open {
+            continue;
+        }
+        let Some(highlighted) = dropdown.highlighted else {
+            continue;
+        };
+        let Ok(children) = q_children.get(dropdown.scroll_view_content) else {
+            continue;
+        };
+        let Some((_, node, transform)) = children
+            .iter()
+            .filter_map(|child| q_options.get(*child).ok())
+            .find(|(option, _, _)| option.option == highlighted)
+        else {
+            continue;
+        };
+        let Ok(mut scroll_view) = q_scroll_view.get_mut(dropdown.scroll_view) else {
+            continue;
+        };
+        let Ok((viewport_node, viewport_transform)) = q_nodes.get(scroll_view.viewport_id()) else {
+            continue;
+        };
+
+        let option_size = node.size().y;
+        let viewport_size = viewport_node.size().y;
+        let top = (transform.translation().y - option_size / 2.)
+            - (viewport_transform.translation().y - viewport_size / 2.);
+        let bottom = top + option_size;
+
+        if top < 0. {
+            scroll_view.scroll_offset.y += top;
+        } else if bottom > viewport_size {
+            scroll_view.scroll_offset.y += bottom - viewport_size;
         }
     }
 }
@@ -185,30 +622,194 @@ pub struct DropdownPanelPlacement {
     pub wider_than_button: bool,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum DropdownMode {
+    /// Options can only be picked from the list
+    #[default]
+    Select,
+    /// A filter field is added to the top of the options panel
+    Searchable,
+    /// Like `Searchable`, but the typed text can also be picked as the value
+    Combobox,
+}
+
+impl DropdownMode {
+    pub fn has_filter(&self) -> bool {
+        !matches!(self, DropdownMode::Select)
+    }
+
+    fn placeholder(&self) -> LocalizedText {
+        match self {
+            DropdownMode::Combobox => {
+                LocalizedText::new("sickle-dropdown-combobox").with_fallback("Type a value...")
+            }
+            _ => LocalizedText::new("sickle-dropdown-filter").with_fallback("Type to filter..."),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum DropdownSelectionMode {
+    /// A single option (or free text in a combobox) is the value
+    #[default]
+    Single,
+    /// Any number of options can be selected, the selection is shown as chips
+    Multiple,
+}
+
+/// A single option of a dropdown
+///
+/// Options with the same `group` that follow each other are listed under a shared header.
+/// The `user_value` is reported in `DropdownChanged` and defaults to the label
+/// (the key of localized labels).
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct DropdownOptionConfig {
+    pub label: LocalizedText,
+    pub icon: IconData,
+    pub group: Option<String>,
+    pub enabled: bool,
+    pub user_value: String,
+}
+
+impl Default for DropdownOptionConfig {
+    fn default() -> Self {
+        Self {
+            label: default(),
+            icon: IconData::None,
+            group: None,
+            enabled: true,
+            user_value: "".into(),
+        }
+    }
+}
+
+impl DropdownOptionConfig {
+    pub fn new(label: impl Into<LocalizedText>) -> Self {
+        let label = label.into();
+
+        Self {
+            user_value: label.key().map_or_else(|| label.to_string(), String::from),
+            label,
+            ..default()
+        }
+    }
+
+    pub fn with_icon(self, icon: IconData) -> Self {
+        Self { icon, ..self }
+    }
+
+    pub fn with_group(self, group: impl Into<String>) -> Self {
+        Self {
+            group: Some(group.into()),
+            ..self
+        }
+    }
+
+    pub fn with_user_value(self, user_value: impl Into<String>) -> Self {
+        Self {
+            user_value: user_value.into(),
+            ..self
+        }
+    }
+
+    pub fn disabled(self) -> Self {
+        Self {
+            enabled: false,
+            ..self
+        }
+    }
+
+    /// Case insensitive match of the label, in the active language, against the filter text
+    pub fn matches(&self, filter: &str, locale: &Locale) -> bool {
+        filter.is_empty()
+            || locale
+                .text(&self.label)
+                .to_lowercase()
+                .contains(&filter.to_lowercase())
+    }
+}
+
+impl From<&str> for DropdownOptionConfig {
+    fn from(value: &str) -> Self {
+        DropdownOptionConfig::new(value)
+    }
+}
+
+impl From<String> for DropdownOptionConfig {
+    fn from(value: String) -> Self {
+        DropdownOptionConfig::new(value)
+    }
+}
+
 #[derive(Component, Clone, Debug, Default, Reflect)]
 #[reflect(Component)]
-pub struct DropdownOptions(Vec<String>);
+pub struct DropdownOptions(Vec<DropdownOptionConfig>);
 
 impl DropdownOptions {
-    pub fn labels(&self) -> &Vec<String> {
+    pub fn options(&self) -> &Vec<DropdownOptionConfig> {
         &self.0
     }
+
+    /// Returns the untranslated label of each option
+    #[deprecated(note = "options are no longer plain labels, please use `options` instead")]
+    pub fn labels(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|option| option.label.to_string())
+            .collect()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&DropdownOptionConfig> {
+        self.0.get(index)
+    }
+
+    /// Indices of the enabled options matching the filter, in display order
+    pub fn navigable(&self, filter: &str, locale: &Locale) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.enabled && option.matches(filter, locale))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn step(&self, filter: &str, locale: &Locale, from: Option<usize>, by: isize) -> Option<usize> {
+        let navigable = self.navigable(filter, locale);
+        if navigable.is_empty() {
+            return None;
+        }
+
+        let last = navigable.len() - 1;
+        let position = match from.and_then(|from| navigable.iter().position(|i| *i == from)) {
+            Some(position) => position.saturating_add_signed(by).min(last),
+            None if by > 0 => 0,
+            None => last,
+        };
+
+        navigable.get(position).copied()
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct DropdownOption {
     dropdown: Entity,
+    icon: Entity,
     label: Entity,
+    checkmark: Entity,
     option: usize,
+    option_icon: IconData,
 }
 
 impl Default for DropdownOption {
     fn default() -> Self {
         Self {
             dropdown: Entity::PLACEHOLDER,
+            icon: Entity::PLACEHOLDER,
             label: Entity::PLACEHOLDER,
+            checkmark: Entity::PLACEHOLDER,
             option: Default::default(),
+            option_icon: IconData::None,
         }
     }
 }
@@ -216,7 +817,9 @@ impl Default for DropdownOption {
 impl UiContext for DropdownOption {
     fn get(&self, target: &str) -> Result<Entity, String> {
         match target {
+            DropdownOption::ICON => Ok(self.icon),
             DropdownOption::LABEL => Ok(self.label),
+            DropdownOption::CHECKMARK => Ok(self.checkmark),
             _ => Err(format!(
                 "{} doesn't exist for DropdownOption. Possible contexts: {:?}",
                 target,
@@ -226,7 +829,12 @@ impl UiContext for DropdownOption {
     }
 
     fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
-        [DropdownOption::LABEL].into_iter()
+        [
+            DropdownOption::ICON,
+            DropdownOption::LABEL,
+            DropdownOption::CHECKMARK,
+        ]
+        .into_iter()
     }
 }
 
@@ -236,57 +844,431 @@ impl DefaultTheme for DropdownOption {
     }
 }
 
-impl DropdownOption {
+impl DropdownOption {
+    pub const ICON: &'static str = "Icon";
+    pub const LABEL: &'static str = "Label";
+    pub const CHECKMARK: &'static str = "Checkmark";
+
+    pub fn dropdown(&self) -> Entity {
+        self.dropdown
+    }
+
+    pub fn option(&self) -> usize {
+        self.option
+    }
+
+    pub fn theme() -> Theme<DropdownOption> {
+        let base_theme = PseudoTheme::deferred_context(None, DropdownOption::primary_style);
+        let selected_theme =
+            PseudoTheme::deferred(vec![PseudoState::Selected], DropdownOption::selected_style);
+        let checked_theme =
+            PseudoTheme::deferred(vec![PseudoState::Checked], DropdownOption::checked_style);
+        let disabled_theme =
+            PseudoTheme::deferred(vec![PseudoState::Disabled], DropdownOption::disabled_style);
+
+        Theme::new(vec![
+            base_theme,
+            selected_theme,
+            checked_theme,
+            disabled_theme,
+        ])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        option: &DropdownOption,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let direction = theme_data.layout_direction;
+
+        style_builder
+            .flex_direction(direction.row())
+            .align_items(AlignItems::Center)
+            .min_width(Val::Percent(100.))
+            .min_height(Val::Px(theme_data.min_hit_target()))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.medium),
+            ))
+            .margin(UiRect::bottom(Val::Px(theme_spacing.gaps.tiny)))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::Primary),
+                hover: colors.accent(Accent::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        if option.option_icon.is_none() {
+            style_builder
+                .switch_target(DropdownOption::ICON)
+                .display(Display::None);
+        } else {
+            let icon = theme_data.directional_icon(&theme_data.icons.resolve(&option.option_icon));
+            let icon = match icon.is_codepoint() {
+                true => icon.with(
+                    colors.on(OnColor::PrimaryContainer),
+                    theme_spacing.icons.small,
+                ),
+                false => icon,
+            };
+
+            style_builder
+                .switch_target(DropdownOption::ICON)
+                .aspect_ratio(1.)
+                .size(Val::Px(theme_spacing.icons.small))
+                .margin(direction.rect(UiRect::right(Val::Px(theme_spacing.gaps.small))))
+                .icon(icon)
+                .animated()
+                .font_color(AnimatedVals {
+                    idle: colors.on(OnColor::PrimaryContainer),
+                    hover: colors.on(OnColor::Primary).into(),
+                    ..default()
+                })
+                .copy_from(theme_data.interaction_animation);
+        }
+
+        style_builder
+            .switch_target(DropdownOption::LABEL)
+            .flex_grow(1.)
+            .sized_font(font)
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(OnColor::PrimaryContainer),
+                hover: colors.on(OnColor::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(DropdownOption::CHECKMARK)
+            .display(Display::None);
+    }
+
+    fn checked_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(DropdownOption::CHECKMARK)
+            .display(Display::Flex)
+            .aspect_ratio(1.)
+            .size(Val::Px(theme_spacing.icons.small))
+            .margin(
+                theme_data
+                    .layout_direction
+                    .rect(UiRect::left(Val::Px(theme_spacing.gaps.large))),
+            )
+            .icon(theme_data.icons.checkmark.with(
+                colors.on(OnColor::PrimaryContainer),
+                theme_spacing.icons.small,
+            ))
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(OnColor::PrimaryContainer),
+                hover: colors.on(OnColor::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn selected_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.accent(Accent::Primary),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(DropdownOption::ICON)
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(OnColor::Primary),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(DropdownOption::LABEL)
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(OnColor::Primary),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn disabled_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+        let disabled_color = colors.on(OnColor::PrimaryContainer).with_alpha(0.38);
+
+        style_builder.background_color(colors.container(Container::Primary));
+
+        style_builder
+            .switch_target(DropdownOption::ICON)
+            .font_color(disabled_color);
+
+        style_builder
+            .switch_target(DropdownOption::LABEL)
+            .font_color(disabled_color);
+    }
+}
+
+/// Header listing the name of a group of consecutive options
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DropdownOptionGroup {
+    dropdown: Entity,
+    label: Entity,
+    group: String,
+    first_option: usize,
+    last_option: usize,
+}
+
+impl Default for DropdownOptionGroup {
+    fn default() -> Self {
+        Self {
+            dropdown: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            group: "".into(),
+            first_option: 0,
+            last_option: 0,
+        }
+    }
+}
+
+impl UiContext for DropdownOptionGroup {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            DropdownOptionGroup::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for DropdownOptionGroup. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [DropdownOptionGroup::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for DropdownOptionGroup {
+    fn default_theme() -> Option<Theme<DropdownOptionGroup>> {
+        DropdownOptionGroup::theme().into()
+    }
+}
+
+impl DropdownOptionGroup {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn dropdown(&self) -> Entity {
+        self.dropdown
+    }
+
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    pub fn theme() -> Theme<DropdownOptionGroup> {
+        let base_theme = PseudoTheme::deferred(None, DropdownOptionGroup::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Label, FontScale::Small, FontType::Bold);
+
+        style_builder
+            .min_width(Val::Percent(100.))
+            .padding(UiRect::px(
+                theme_spacing.gaps.medium,
+                theme_spacing.gaps.medium,
+                theme_spacing.gaps.medium,
+                theme_spacing.gaps.small,
+            ));
+
+        style_builder
+            .switch_target(DropdownOptionGroup::LABEL)
+            .sized_font(font)
+            .font_color(colors.on(OnColor::PrimaryContainer).with_alpha(0.7));
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum DropdownChipValue {
+    Option(usize),
+    Tag(String),
+}
+
+/// A selected option or tag of a multi-select dropdown, removable with its close button
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DropdownChip {
+    dropdown: Entity,
+    label: Entity,
+    close: Entity,
+    value: DropdownChipValue,
+}
+
+impl Default for DropdownChip {
+    fn default() -> Self {
+        Self {
+            dropdown: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            close: Entity::PLACEHOLDER,
+            value: DropdownChipValue::Option(0),
+        }
+    }
+}
+
+impl UiContext for DropdownChip {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            DropdownChip::LABEL => Ok(self.label),
+            DropdownChip::CLOSE => Ok(self.close),
+            _ => Err(format!(
+                "{} doesn't exist for DropdownChip. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [DropdownChip::LABEL, DropdownChip::CLOSE].into_iter()
+    }
+}
+
+impl DefaultTheme for DropdownChip {
+    fn default_theme() -> Option<Theme<DropdownChip>> {
+        DropdownChip::theme().into()
+    }
+}
+
+impl DropdownChip {
     pub const LABEL: &'static str = "Label";
+    pub const CLOSE: &'static str = "Close";
 
     pub fn dropdown(&self) -> Entity {
         self.dropdown
     }
 
-    pub fn option(&self) -> usize {
-        self.option
+    pub fn value(&self) -> &DropdownChipValue {
+        &self.value
     }
 
-    pub fn theme() -> Theme<DropdownOption> {
-        let base_theme = PseudoTheme::deferred(None, DropdownOption::primary_style);
+    pub fn theme() -> Theme<DropdownChip> {
+        let base_theme = PseudoTheme::deferred(None, DropdownChip::primary_style);
 
         Theme::new(vec![base_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Label, FontScale::Medium, FontType::Regular);
+        let direction = theme_data.layout_direction;
 
         style_builder
+            .flex_direction(direction.row())
             .align_items(AlignItems::Center)
-            .min_width(Val::Percent(100.))
-            .padding(UiRect::axes(
-                Val::Px(theme_spacing.gaps.medium),
-                Val::Px(theme_spacing.gaps.medium),
-            ))
-            .margin(UiRect::bottom(Val::Px(theme_spacing.gaps.tiny)))
-            .animated()
-            .background_color(AnimatedVals {
-                idle: colors.container(Container::Primary),
-                hover: colors.accent(Accent::Primary).into(),
-                ..default()
-            })
-            .copy_from(theme_data.interaction_animation);
+            .padding(direction.rect(UiRect::px(
+                theme_spacing.gaps.small,
+                theme_spacing.gaps.extra_small,
+                theme_spacing.gaps.tiny,
+                theme_spacing.gaps.tiny,
+            )))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.tiny)))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.small)))
+            .background_color(colors.container(Container::Primary));
 
         style_builder
-            .switch_target(DropdownOption::LABEL)
+            .switch_target(DropdownChip::LABEL)
             .sized_font(font)
+            .font_color(colors.on(OnColor::PrimaryContainer));
+
+        style_builder
+            .switch_target(DropdownChip::CLOSE)
+            .aspect_ratio(1.)
+            .size(Val::Px(theme_spacing.icons.extra_small))
+            .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.extra_small))))
+            .icon(theme_data.icons.close.with(
+                colors.on(OnColor::PrimaryContainer),
+                theme_spacing.icons.extra_small,
+            ))
             .animated()
             .font_color(AnimatedVals {
                 idle: colors.on(OnColor::PrimaryContainer),
-                hover: colors.on(OnColor::Primary).into(),
+                hover: colors.accent(Accent::Error).into(),
                 ..default()
             })
             .copy_from(theme_data.interaction_animation);
     }
+
+    fn frame(label: &LocalizedText) -> impl Bundle {
+        (
+            Name::new(format!("Chip [{}]", label)),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn close_button() -> impl Bundle {
+        (
+            Name::new("Remove Chip"),
+            ImageBundle {
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            BorderColor::default(),
+            Interaction::default(),
+            TrackedInteraction::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn spawn(
+        builder: &mut UiBuilder<Entity>,
+        dropdown: Entity,
+        value: DropdownChipValue,
+        label: LocalizedText,
+    ) -> Entity {
+        let mut label_id = Entity::PLACEHOLDER;
+        let mut close_id = Entity::PLACEHOLDER;
+
+        let mut chip = builder.container(DropdownChip::frame(&label), |container| {
+            label_id = container
+                .label(LabelConfig {
+                    label: label.clone(),
+                    ..default()
+                })
+                .id();
+            close_id = container.spawn(DropdownChip::close_button()).id();
+        });
+
+        chip.insert(DropdownChip {
+            dropdown,
+            label: label_id,
+            close: close_id,
+            value,
+        });
+
+        chip.id()
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -307,9 +1289,19 @@ impl Default for DropdownPanel {
 #[reflect(Component)]
 pub struct Dropdown {
     value: Option<usize>,
+    custom_value: Option<String>,
+    selection: Vec<usize>,
+    tags: Vec<String>,
+    mode: DropdownMode,
+    selection_mode: DropdownSelectionMode,
+    filter: String,
+    highlighted: Option<usize>,
     label: Entity,
+    chips: Entity,
     icon: Entity,
     panel: Entity,
+    filter_field: Entity,
+    filter_label: Entity,
     scroll_view: Entity,
     scroll_view_content: Entity,
     is_open: bool,
@@ -319,9 +1311,19 @@ impl Default for Dropdown {
     fn default() -> Self {
         Self {
             value: Default::default(),
+            custom_value: None,
+            selection: Vec::new(),
+            tags: Vec::new(),
+            mode: DropdownMode::Select,
+            selection_mode: DropdownSelectionMode::Single,
+            filter: "".into(),
+            highlighted: None,
             label: Entity::PLACEHOLDER,
+            chips: Entity::PLACEHOLDER,
             icon: Entity::PLACEHOLDER,
             panel: Entity::PLACEHOLDER,
+            filter_field: Entity::PLACEHOLDER,
+            filter_label: Entity::PLACEHOLDER,
             scroll_view: Entity::PLACEHOLDER,
             scroll_view_content: Entity::PLACEHOLDER,
             is_open: false,
@@ -333,8 +1335,11 @@ impl UiContext for Dropdown {
     fn get(&self, target: &str) -> Result<Entity, String> {
         match target {
             Dropdown::LABEL => Ok(self.label),
+            Dropdown::CHIPS => Ok(self.chips),
             Dropdown::ICON => Ok(self.icon),
             Dropdown::PANEL => Ok(self.panel),
+            Dropdown::FILTER_FIELD => Ok(self.filter_field),
+            Dropdown::FILTER_LABEL => Ok(self.filter_label),
             Dropdown::SCROLL_VIEW => Ok(self.scroll_view),
             Dropdown::SCROLL_VIEW_CONTENT => Ok(self.scroll_view_content),
             _ => Err(format!(
@@ -348,8 +1353,11 @@ impl UiContext for Dropdown {
     fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
         [
             Dropdown::LABEL,
+            Dropdown::CHIPS,
             Dropdown::ICON,
             Dropdown::PANEL,
+            Dropdown::FILTER_FIELD,
+            Dropdown::FILTER_LABEL,
             Dropdown::SCROLL_VIEW,
             Dropdown::SCROLL_VIEW_CONTENT,
         ]
@@ -365,8 +1373,11 @@ impl DefaultTheme for Dropdown {
 
 impl Dropdown {
     pub const LABEL: &'static str = "Label";
+    pub const CHIPS: &'static str = "Chips";
     pub const ICON: &'static str = "Icon";
     pub const PANEL: &'static str = "Panel";
+    pub const FILTER_FIELD: &'static str = "FilterField";
+    pub const FILTER_LABEL: &'static str = "FilterLabel";
     pub const SCROLL_VIEW: &'static str = "ScrollView";
     pub const SCROLL_VIEW_CONTENT: &'static str = "ScrollViewContent";
 
@@ -376,8 +1387,133 @@ impl Dropdown {
 
     pub fn set_value(&mut self, value: impl Into<Option<usize>>) {
         let value = value.into();
-        if self.value != value {
+        if self.value != value || self.custom_value.is_some() {
             self.value = value;
+            self.custom_value = None;
+        }
+    }
+
+    /// The free text value of a combobox, set when no option is selected
+    pub fn custom_value(&self) -> Option<&String> {
+        self.custom_value.as_ref()
+    }
+
+    pub fn set_custom_value(&mut self, custom_value: impl Into<String>) {
+        let custom_value = custom_value.into();
+        if self.value.is_some() || self.custom_value.as_ref() != Some(&custom_value) {
+            self.value = None;
+            self.custom_value = custom_value.into();
+        }
+    }
+
+    /// Indices of the selected options of a multi-select dropdown, in option order
+    pub fn selection(&self) -> &Vec<usize> {
+        &self.selection
+    }
+
+    pub fn set_selection(&mut self, selection: Vec<usize>) {
+        let mut selection = selection;
+        selection.sort_unstable();
+        selection.dedup();
+
+        if self.selection != selection {
+            self.selection = selection;
+        }
+    }
+
+    /// Free text entries of a multi-select combobox
+    pub fn tags(&self) -> &Vec<String> {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        if self.tags != tags {
+            self.tags = tags;
+        }
+    }
+
+    pub fn mode(&self) -> DropdownMode {
+        self.mode
+    }
+
+    pub fn selection_mode(&self) -> DropdownSelectionMode {
+        self.selection_mode
+    }
+
+    /// The text typed into the filter field while the panel is open
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// The option highlighted for keyboard selection
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    fn open(&mut self) {
+        self.is_open = true;
+        self.filter.clear();
+        self.highlighted = self.value;
+    }
+
+    fn select(&mut self, option: usize) {
+        self.value = option.into();
+        self.custom_value = None;
+    }
+
+    fn toggle(&mut self, option: usize) {
+        match self.selection.binary_search(&option) {
+            Ok(index) => {
+                self.selection.remove(index);
+            }
+            Err(index) => self.selection.insert(index, option),
+        }
+    }
+
+    fn add_tag(&mut self, tag: String) -> bool {
+        if self.tags.contains(&tag) {
+            return false;
+        }
+
+        self.tags.push(tag);
+        true
+    }
+
+    fn remove(&mut self, value: &DropdownChipValue) -> bool {
+        match value {
+            DropdownChipValue::Option(option) => match self.selection.binary_search(option) {
+                Ok(index) => {
+                    self.selection.remove(index);
+                    true
+                }
+                Err(_) => false,
+            },
+            DropdownChipValue::Tag(tag) => match self.tags.iter().position(|t| t == tag) {
+                Some(index) => {
+                    self.tags.remove(index);
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+
+    fn pop_selection(&mut self) -> bool {
+        self.tags.pop().is_some() || self.selection.pop().is_some()
+    }
+
+    #[cfg(feature = "observable")]
+    fn selection_changed(&self, options: &DropdownOptions) -> DropdownSelectionChanged {
+        DropdownSelectionChanged {
+            selection: self.selection.clone(),
+            tags: self.tags.clone(),
+            user_values: self
+                .selection
+                .iter()
+                .filter_map(|option| options.get(*option))
+                .map(|option| option.user_value.clone())
+                .chain(self.tags.iter().cloned())
+                .collect(),
         }
     }
 
@@ -386,24 +1522,51 @@ impl Dropdown {
     }
 
     pub fn theme() -> Theme<Dropdown> {
-        let base_theme = PseudoTheme::deferred(None, Dropdown::primary_style);
+        let base_theme = PseudoTheme::deferred_context(None, Dropdown::primary_style);
         let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Dropdown::open_style);
+        let error_theme = PseudoTheme::deferred(vec![PseudoState::Error], Dropdown::error_style);
 
-        Theme::new(vec![base_theme, open_theme])
+        Theme::new(vec![base_theme, open_theme, error_theme])
     }
 
-    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
         style_builder
+            .border(UiRect::all(Val::Px(theme_spacing.borders.small)))
+            .border_color(colors.accent(Accent::Error));
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        dropdown: &Dropdown,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let direction = theme_data.layout_direction;
+
+        // Chips wrap to new lines, so multi-select dropdowns grow with the selection
+        match dropdown.selection_mode {
+            DropdownSelectionMode::Single => {
+                style_builder
+                    .height(Val::Px(theme_spacing.areas.small))
+                    .min_height(Val::Px(theme_data.min_hit_target()));
+            }
+            DropdownSelectionMode::Multiple => {
+                style_builder.height(Val::Auto).min_height(Val::Px(
+                    theme_spacing.areas.small.max(theme_data.min_hit_target()),
+                ));
+            }
+        }
+
+        style_builder
+            .flex_direction(direction.row())
             .align_self(AlignSelf::Start)
             .align_items(AlignItems::Center)
             .justify_content(JustifyContent::SpaceBetween)
-            .height(Val::Px(theme_spacing.areas.small))
             .padding(UiRect::axes(
                 Val::Px(theme_spacing.gaps.medium),
                 Val::Px(theme_spacing.gaps.extra_small),
@@ -432,10 +1595,17 @@ impl Dropdown {
             })
             .copy_from(theme_data.interaction_animation);
 
+        style_builder
+            .switch_target(Dropdown::CHIPS)
+            .flex_direction(direction.row())
+            .flex_wrap(FlexWrap::Wrap)
+            .flex_grow(1.)
+            .align_items(AlignItems::Center);
+
         style_builder
             .switch_target(Dropdown::ICON)
             .size(Val::Px(theme_spacing.icons.small))
-            .margin(UiRect::left(Val::Px(theme_spacing.gaps.large)))
+            .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.large))))
             .icon(
                 theme_data
                     .icons
@@ -461,6 +1631,22 @@ impl Dropdown {
             .border_color(Color::NONE)
             .background_color(colors.container(Container::Primary));
 
+        style_builder
+            .switch_target(Dropdown::FILTER_FIELD)
+            .flex_shrink(0.)
+            .min_width(Val::Percent(100.))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.small),
+            ))
+            .border(UiRect::bottom(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::OutlineVariant));
+
+        style_builder
+            .switch_target(Dropdown::FILTER_LABEL)
+            .sized_font(theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Italic))
+            .font_color(colors.on(OnColor::PrimaryContainer));
+
         style_builder
             .switch_target(Dropdown::SCROLL_VIEW_CONTENT)
             .margin(UiRect::px(
@@ -481,7 +1667,7 @@ impl Dropdown {
         };
 
         let theme_data = world.resource::<ThemeData>();
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
         let enter_animation = theme_data.enter_animation.clone();
         let corner_from_width =
@@ -633,6 +1819,7 @@ impl Dropdown {
             return Err("Entity has no Dropdown component".into());
         };
         let dropdown_panel = dropdown.panel;
+        let filter_field = dropdown.filter_field;
         let scroll_view_content = dropdown.scroll_view_content;
 
         // Unsafe unwrap: If a UI element doesn't have a Node, we should panic!
@@ -648,11 +1835,21 @@ impl Dropdown {
 
         let option_list: Vec<Entity> = option_list.iter().map(|child| *child).collect();
         let mut five_children_height = panel_borders.x + panel_borders.z;
+        if let Some(filter_node) = world.get::<Node>(filter_field) {
+            five_children_height += filter_node.unrounded_size().y;
+        }
+
         let mut counted = 0;
         for child in option_list {
             let Some(option_node) = world.get::<Node>(child) else {
                 continue;
             };
+            if world
+                .get::<Style>(child)
+                .is_some_and(|style| style.display == Display::None)
+            {
+                continue;
+            }
 
             if counted < 5 {
                 five_children_height += option_node.unrounded_size().y;
@@ -664,21 +1861,18 @@ impl Dropdown {
         }
 
         let (container_size, tl_corner) = UiUtils::container_size_and_offset(entity, world);
-        let halfway_point = container_size / 2.;
-        let space_below = (container_size - tl_corner - dropdown_size).y;
+        let (anchor_right, anchor_top) = UiUtils::flip_to_fit(
+            Rect::from_corners(tl_corner, tl_corner + dropdown_size),
+            five_children_height,
+            container_size,
+            world.resource::<ThemeData>().layout_direction,
+        );
 
-        let anchor = if tl_corner.x > halfway_point.x {
-            if space_below < five_children_height {
-                DropdownPanelAnchor::TopRight
-            } else {
-                DropdownPanelAnchor::BottomRight
-            }
-        } else {
-            if space_below < five_children_height {
-                DropdownPanelAnchor::TopLeft
-            } else {
-                DropdownPanelAnchor::BottomLeft
-            }
+        let anchor = match (anchor_right, anchor_top) {
+            (false, true) => DropdownPanelAnchor::TopLeft,
+            (true, true) => DropdownPanelAnchor::TopRight,
+            (false, false) => DropdownPanelAnchor::BottomLeft,
+            (true, false) => DropdownPanelAnchor::BottomRight,
         };
 
         let panel_size_limit = match anchor {
@@ -747,22 +1941,18 @@ impl Dropdown {
         })
     }
 
-    fn button(options: Vec<String>) -> impl Bundle {
+    fn button(options: Vec<DropdownOptionConfig>) -> impl Bundle {
         (
             Name::new("Dropdown"),
             ButtonBundle {
                 style: Style {
-                    flex_direction: FlexDirection::Row,
                     overflow: Overflow::visible(),
                     ..default()
                 },
                 ..default()
             },
             TrackedInteraction::default(),
-            LockedStyleAttributes::from_vec(vec![
-                LockableStyleAttribute::FlexDirection,
-                LockableStyleAttribute::Overflow,
-            ]),
+            LockedStyleAttributes::lock(LockableStyleAttribute::Overflow),
             DropdownOptions(options),
         )
     }
@@ -779,109 +1969,356 @@ impl Dropdown {
         )
     }
 
-    fn option_bundle(option: usize) -> impl Bundle {
+    fn filter_field() -> impl Bundle {
+        (
+            Name::new("Dropdown Filter"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn group_bundle(group: &str) -> impl Bundle {
+        (
+            Name::new(format!("Option Group [{}]", group)),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::from_vec(vec![
+                LockableStyleAttribute::FocusPolicy,
+                LockableStyleAttribute::Display,
+            ]),
+        )
+    }
+
+    fn option_bundle(option: usize, enabled: bool) -> impl Bundle {
+        let (interaction, pseudo_states) = match enabled {
+            true => (FluxInteraction::None, PseudoStates::default()),
+            false => (
+                FluxInteraction::Disabled,
+                PseudoStates::from(vec![PseudoState::Disabled]),
+            ),
+        };
+
         (
             Name::new(format!("Option {}", option)),
             ButtonBundle {
                 focus_policy: FocusPolicy::Pass,
                 ..default()
             },
-            TrackedInteraction::default(),
+            TrackedInteraction {
+                interaction,
+                ..default()
+            },
+            LockedStyleAttributes::from_vec(vec![
+                LockableStyleAttribute::FocusPolicy,
+                LockableStyleAttribute::Display,
+            ]),
+            pseudo_states,
+        )
+    }
+
+    fn chips_container() -> impl Bundle {
+        (
+            Name::new("Dropdown Chips"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn option_icon(name: &str) -> impl Bundle {
+        (
+            Name::new(name.to_string()),
+            ImageBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            BorderColor::default(),
             LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
         )
     }
+
+    fn scaffold<'a>(
+        builder: &'a mut UiBuilder<'_, Entity>,
+        options: Vec<DropdownOptionConfig>,
+        dropdown: Dropdown,
+    ) -> UiBuilder<'a, Entity> {
+        let mode = dropdown.mode;
+        let mut chips_id = Entity::PLACEHOLDER;
+        let mut label_id = Entity::PLACEHOLDER;
+        let mut icon_id = Entity::PLACEHOLDER;
+        let mut panel_id = Entity::PLACEHOLDER;
+        let mut filter_field_id = Entity::PLACEHOLDER;
+        let mut filter_label_id = Entity::PLACEHOLDER;
+        let mut scroll_view_id = Entity::PLACEHOLDER;
+        let mut scroll_view_content_id = Entity::PLACEHOLDER;
+
+        let mut dropdown_builder =
+            builder.container(Dropdown::button(options.clone()), |builder| {
+                let dropdown_id = builder.id();
+                if dropdown.selection_mode == DropdownSelectionMode::Multiple {
+                    chips_id = builder.spawn(Dropdown::chips_container()).id();
+                }
+                label_id = builder
+                    .label(LabelConfig {
+                        overflow: TextOverflow::Ellipsis,
+                        tooltip_on_truncate: true,
+                        ..default()
+                    })
+                    .id();
+                icon_id = builder.spawn(Dropdown::button_icon()).id();
+                panel_id = builder
+                    .panel("Dropdown Options".into(), |container| {
+                        if mode.has_filter() {
+                            filter_field_id = container
+                                .container(Dropdown::filter_field(), |field| {
+                                    filter_label_id = field
+                                        .label(LabelConfig {
+                                            label: mode.placeholder(),
+                                            ..default()
+                                        })
+                                        .id();
+                                })
+                                .id();
+                        }
+
+                        scroll_view_id = container
+                            .scroll_view(None, |scroll_view| {
+                                scroll_view_content_id = scroll_view.id();
+
+                                for (index, option) in options.iter().enumerate() {
+                                    if let Some(group) = option.group.as_ref().filter(|_| {
+                                        index == 0 || options[index - 1].group != option.group
+                                    }) {
+                                        let group_size = options[index..]
+                                            .iter()
+                                            .take_while(|other| other.group == option.group)
+                                            .count();
+                                        let mut group_label_id = Entity::PLACEHOLDER;
+                                        scroll_view.container(
+                                            Dropdown::group_bundle(group),
+                                            |header| {
+                                                group_label_id = header
+                                                    .label(LabelConfig {
+                                                        label: group.clone().into(),
+                                                        ..default()
+                                                    })
+                                                    .id();
+
+                                                header.insert(DropdownOptionGroup {
+                                                    dropdown: dropdown_id,
+                                                    label: group_label_id,
+                                                    group: group.clone(),
+                                                    first_option: index,
+                                                    last_option: index + group_size - 1,
+                                                });
+                                            },
+                                        );
+                                    }
+
+                                    let mut option_icon_id = Entity::PLACEHOLDER;
+                                    let mut label_id = Entity::PLACEHOLDER;
+                                    let mut checkmark_id = Entity::PLACEHOLDER;
+                                    scroll_view.container(
+                                        Dropdown::option_bundle(index, option.enabled),
+                                        |container| {
+                                            option_icon_id = container
+                                                .spawn(Dropdown::option_icon("Option Icon"))
+                                                .id();
+                                            label_id = container
+                                                .label(LabelConfig {
+                                                    label: option.label.clone(),
+                                                    ..default()
+                                                })
+                                                .id();
+                                            checkmark_id = container
+                                                .spawn(Dropdown::option_icon("Option Checkmark"))
+                                                .id();
+
+                                            container.insert(DropdownOption {
+                                                dropdown: dropdown_id,
+                                                icon: option_icon_id,
+                                                label: label_id,
+                                                checkmark: checkmark_id,
+                                                option: index,
+                                                option_icon: option.icon.clone(),
+                                            });
+                                        },
+                                    );
+                                }
+                            })
+                            .insert(TrackedStyleState::default())
+                            .id();
+                    })
+                    .insert((
+                        DropdownPanel {
+                            dropdown: dropdown_id,
+                        },
+                        Interaction::default(),
+                        TrackedInteraction::default(),
+                        LockedStyleAttributes::from_vec(vec![
+                            LockableStyleAttribute::Visibility,
+                            LockableStyleAttribute::Display,
+                            LockableStyleAttribute::FocusPolicy,
+                        ]),
+                        PseudoStates::default(),
+                        VisibilityToPseudoState,
+                    ))
+                    .style_unchecked()
+                    .focus_policy(bevy::ui::FocusPolicy::Block)
+                    .id();
+            });
+
+        dropdown_builder.insert(Dropdown {
+            label: label_id,
+            chips: chips_id,
+            icon: icon_id,
+            panel: panel_id,
+            filter_field: filter_field_id,
+            filter_label: filter_label_id,
+            scroll_view: scroll_view_id,
+            scroll_view_content: scroll_view_content_id,
+            ..dropdown
+        });
+
+        dropdown_builder
+    }
 }
 
 pub trait UiDropdownExt {
     fn dropdown(
         &mut self,
-        options: Vec<impl Into<String>>,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        value: impl Into<Option<usize>>,
+    ) -> UiBuilder<Entity>;
+
+    fn searchable_dropdown(
+        &mut self,
+        options: Vec<impl Into<DropdownOptionConfig>>,
         value: impl Into<Option<usize>>,
     ) -> UiBuilder<Entity>;
+
+    fn combobox(
+        &mut self,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        text: impl Into<String>,
+    ) -> UiBuilder<Entity>;
+
+    fn multi_dropdown(
+        &mut self,
+        mode: DropdownMode,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        selection: Vec<usize>,
+    ) -> UiBuilder<Entity>;
 }
 
 impl UiDropdownExt for UiBuilder<'_, Entity> {
     /// A simple dropdown with options.
     ///
+    /// Options can be plain labels or [`DropdownOptionConfig`]s with icons, groups,
+    /// disabled state and a user value. Arrow keys, Home, End, Page Up and Page Down
+    /// move the highlight while the panel is open, Enter selects and Escape closes it.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Open`, when the options panel should be visible
+    /// - `PseudoState::Selected`, on the option highlighted by the keyboard
+    /// - `PseudoState::Disabled`, on disabled options
+    /// - `PseudoState::Error`, when a `Validation` on it fails
     fn dropdown(
         &mut self,
-        options: Vec<impl Into<String>>,
+        options: Vec<impl Into<DropdownOptionConfig>>,
         value: impl Into<Option<usize>>,
     ) -> UiBuilder<Entity> {
-        let mut label_id = Entity::PLACEHOLDER;
-        let mut icon_id = Entity::PLACEHOLDER;
-        let mut panel_id = Entity::PLACEHOLDER;
-        let mut scroll_view_id = Entity::PLACEHOLDER;
-        let mut scroll_view_content_id = Entity::PLACEHOLDER;
+        let options = options.into_iter().map(Into::into).collect();
 
-        let option_count = options.len();
-        let mut string_options: Vec<String> = Vec::with_capacity(option_count);
-        let mut queue = VecDeque::from(options);
-        for _ in 0..option_count {
-            let label: String = queue.pop_front().unwrap().into();
-            string_options.push(label);
-        }
-
-        let mut dropdown = self.container(Dropdown::button(string_options.clone()), |builder| {
-            let dropdown_id = builder.id();
-            label_id = builder.label(LabelConfig::default()).id();
-            icon_id = builder.spawn(Dropdown::button_icon()).id();
-            panel_id = builder
-                .panel("Dropdown Options".into(), |container| {
-                    scroll_view_id = container
-                        .scroll_view(None, |scroll_view| {
-                            scroll_view_content_id = scroll_view.id();
-
-                            for (index, label) in string_options.iter().enumerate() {
-                                let mut label_id = Entity::PLACEHOLDER;
-                                scroll_view.container(Dropdown::option_bundle(index), |option| {
-                                    label_id = option
-                                        .label(LabelConfig {
-                                            label: label.clone(),
-                                            ..default()
-                                        })
-                                        .id();
+        Dropdown::scaffold(
+            self,
+            options,
+            Dropdown {
+                value: value.into(),
+                ..default()
+            },
+        )
+    }
 
-                                    option.insert(DropdownOption {
-                                        dropdown: dropdown_id,
-                                        option: index,
-                                        label: label_id,
-                                    });
-                                });
-                            }
-                        })
-                        .insert(TrackedStyleState::default())
-                        .id();
-                })
-                .insert((
-                    DropdownPanel {
-                        dropdown: dropdown_id,
-                    },
-                    LockedStyleAttributes::from_vec(vec![
-                        LockableStyleAttribute::Visibility,
-                        LockableStyleAttribute::Display,
-                        LockableStyleAttribute::FocusPolicy,
-                    ]),
-                    PseudoStates::default(),
-                    VisibilityToPseudoState,
-                ))
-                .style_unchecked()
-                .focus_policy(bevy::ui::FocusPolicy::Block)
-                .id();
-        });
+    /// A dropdown with a filter field at the top of the options panel.
+    ///
+    /// Typing while the panel is open filters the options by label,
+    /// keyboard navigation only visits the matching, enabled options.
+    fn searchable_dropdown(
+        &mut self,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        value: impl Into<Option<usize>>,
+    ) -> UiBuilder<Entity> {
+        let options = options.into_iter().map(Into::into).collect();
+
+        Dropdown::scaffold(
+            self,
+            options,
+            Dropdown {
+                value: value.into(),
+                mode: DropdownMode::Searchable,
+                ..default()
+            },
+        )
+    }
 
-        dropdown.insert(Dropdown {
-            value: value.into(),
-            label: label_id,
-            icon: icon_id,
-            panel: panel_id,
-            scroll_view: scroll_view_id,
-            scroll_view_content: scroll_view_content_id,
+    /// A searchable dropdown that also accepts free text.
+    ///
+    /// Pressing Enter without a highlighted option sets the typed text as the
+    /// custom value of the dropdown.
+    fn combobox(
+        &mut self,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        text: impl Into<String>,
+    ) -> UiBuilder<Entity> {
+        let options = options.into_iter().map(Into::into).collect();
+        let text = text.into();
+        let custom_value = match text.is_empty() {
+            true => None,
+            false => text.into(),
+        };
+
+        Dropdown::scaffold(
+            self,
+            options,
+            Dropdown {
+                custom_value,
+                mode: DropdownMode::Combobox,
+                ..default()
+            },
+        )
+    }
+
+    /// A dropdown that keeps a set of selected options, shown as removable chips.
+    ///
+    /// Pressing an option toggles it and the panel stays open until a press outside of it.
+    /// With `DropdownMode::Combobox`, Enter adds the typed text as a tag,
+    /// and Backspace in an empty filter field removes the last chip.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked`, on the selected options
+    fn multi_dropdown(
+        &mut self,
+        mode: DropdownMode,
+        options: Vec<impl Into<DropdownOptionConfig>>,
+        selection: Vec<usize>,
+    ) -> UiBuilder<Entity> {
+        let options: Vec<DropdownOptionConfig> = options.into_iter().map(Into::into).collect();
+        let mut dropdown = Dropdown {
+            mode,
+            selection_mode: DropdownSelectionMode::Multiple,
             ..default()
-        });
+        };
+        dropdown.set_selection(selection);
+        dropdown.selection.retain(|option| *option < options.len());
 
-        dropdown
+        Dropdown::scaffold(self, options, dropdown)
     }
 }
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    inputs::{binding::Bind, validation::Validation},
+    layout::container::UiContainerExt,
+};
+
+#[derive(Event, Copy, Clone, Debug)]
+pub struct FormSubmitted;
+
+pub struct FormPlugin;
+
+impl Plugin for FormPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_form_validity, submit_form)
+                .chain()
+                .after(FluxInteractionUpdate),
+        )
+        .add_event::<FormSubmitted>();
+    }
+}
+
+fn update_form_validity(
+    mut q_forms: Query<(Entity, &mut Form)>,
+    q_children: Query<&Children>,
+    q_validations: Query<&Validation>,
+    q_binds: Query<&Bind>,
+    q_submits: Query<Entity, With<FormSubmit>>,
+    mut commands: Commands,
+) {
+    for (entity, mut form) in &mut q_forms {
+        let valid = !q_children.iter_descendants(entity).any(|child| {
+            q_validations
+                .get(child)
+                .is_ok_and(|validation| !validation.is_valid())
+                || q_binds.get(child).is_ok_and(|bind| !bind.is_valid())
+        });
+
+        if form.valid == valid && !form.is_added() {
+            continue;
+        }
+
+        form.valid = valid;
+        for submit in q_children
+            .iter_descendants(entity)
+            .filter(|child| q_submits.contains(*child))
+        {
+            match valid {
+                true => commands
+                    .entity(submit)
+                    .remove_pseudo_state(PseudoState::Disabled),
+                false => commands
+                    .entity(submit)
+                    .add_pseudo_state(PseudoState::Disabled),
+            };
+        }
+
+        match valid {
+            true => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Error),
+            false => commands.entity(entity).add_pseudo_state(PseudoState::Error),
+        };
+    }
+}
+
+fn submit_form(
+    q_submits: Query<(Entity, &FluxInteraction), (With<FormSubmit>, Changed<FluxInteraction>)>,
+    q_parents: Query<&Parent>,
+    q_forms: Query<&Form>,
+    mut commands: Commands,
+) {
+    for (entity, interaction) in &q_submits {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+
+        let Some(form) = q_parents
+            .iter_ancestors(entity)
+            .find(|ancestor| q_forms.contains(*ancestor))
+        else {
+            warn!("Form submit {} is not inside a Form", entity);
+            continue;
+        };
+
+        if q_forms.get(form).is_ok_and(|form| form.is_valid()) {
+            commands.trigger_targets(FormSubmitted, form);
+        }
+    }
+}
+
+/// Aggregates the validity of the [`Validation`] and [`Bind`] inputs placed in it
+///
+/// [`FormSubmit`] buttons in the form fire [`FormSubmitted`] on the form, unless
+/// one of its inputs is invalid.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Form {
+    valid: bool,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self { valid: true }
+    }
+}
+
+impl Form {
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Form"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FlexDirection),
+        )
+    }
+}
+
+/// Marks an interactive entity in a [`Form`] as its submit button
+///
+/// It gets `PseudoState::Disabled` while the form is invalid.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct FormSubmit;
+
+pub trait UiFormExt {
+    fn form(&mut self, spawn_children: impl FnOnce(&mut UiBuilder<Entity>)) -> UiBuilder<Entity>;
+}
+
+impl UiFormExt for UiBuilder<'_, Entity> {
+    /// A column that aggregates the validity of the inputs spawned in it.
+    /// Fires [`FormSubmitted`] when a valid form is submitted with a [`FormSubmit`] button.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Error`, when any of the inputs is invalid
+    fn form(&mut self, spawn_children: impl FnOnce(&mut UiBuilder<Entity>)) -> UiBuilder<Entity> {
+        self.container((Form::frame(), Form::default()), spawn_children)
+    }
+}
@@ -0,0 +1,691 @@
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
+
+use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+
+use crate::{
+    input_extension::SymmetricKeysExt,
+    widgets::layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+    },
+};
+
+/// Drags shorter than this (in logical pixels) are treated as clicks and start editing
+const CLICK_TOLERANCE: f32 = 3.;
+
+/// Relative change of the value per pixel when scrubbing on a logarithmic scale
+const LOG_SCRUB_RATE: f32 = 0.01;
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct NumericFieldChanged {
+    pub value: f32,
+}
+
+pub struct NumericFieldPlugin;
+
+impl Plugin for NumericFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            NumericFieldUpdate
+                .after(DraggableUpdate)
+                .after(FluxInteractionUpdate),
+        )
+        .add_plugins(ComponentThemePlugin::<NumericField>::default())
+        .add_systems(
+            Update,
+            (
+                update_numeric_field_on_drag,
+                update_numeric_field_editing,
+                handle_numeric_field_keyboard,
+                commit_numeric_field_on_outside_press,
+                update_numeric_field_text,
+            )
+                .chain()
+                .in_set(NumericFieldUpdate),
+        );
+
+        #[cfg(feature = "observable")]
+        app.add_event::<NumericFieldChanged>();
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct NumericFieldUpdate;
+
+fn update_numeric_field_on_drag(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    mut q_fields: Query<(Entity, &Draggable, &mut NumericField), Changed<Draggable>>,
+    mut commands: Commands,
+) {
+    for (entity, draggable, mut field) in &mut q_fields {
+        if field.editing {
+            continue;
+        }
+
+        match draggable.state {
+            DragState::DragStart => {
+                field.scrub_base = field.value.into();
+                field.scrub_value = field.value;
+            }
+            DragState::Dragging => (),
+            DragState::DragEnd | DragState::DragCanceled => {
+                let Some(base) = field.scrub_base.take() else {
+                    continue;
+                };
+
+                // Jitter while clicking or a canceled drag should not change the value
+                if (draggable.state == DragState::DragCanceled
+                    || field.scrub_distance < CLICK_TOLERANCE)
+                    && field.value != base
+                {
+                    field.value = base;
+
+                    #[cfg(feature = "observable")]
+                    commands.trigger_targets(NumericFieldChanged { value: base }, entity);
+                }
+
+                continue;
+            }
+            _ => continue,
+        }
+
+        let Some(diff) = draggable.diff else {
+            continue;
+        };
+        if diff.x == 0. {
+            continue;
+        }
+
+        field.scrub_distance += diff.length();
+
+        let modifier = if r_keys.symmetry_pressed(KeyCode::ShiftLeft) {
+            0.1
+        } else if r_keys.symmetry_pressed(KeyCode::ControlLeft) {
+            10.
+        } else {
+            1.
+        };
+
+        field.scrub_value = field.config.scrub(field.scrub_value, diff.x * modifier);
+        let value = field.config.constrain(field.scrub_value);
+        if field.scrub_distance >= CLICK_TOLERANCE && field.value != value {
+            field.value = value;
+
+            #[cfg(feature = "observable")]
+            commands.trigger_targets(NumericFieldChanged { value }, entity);
+        }
+    }
+}
+
+fn update_numeric_field_editing(
+    q_interactions: Query<
+        (Entity, &FluxInteraction),
+        (With<NumericField>, Changed<FluxInteraction>),
+    >,
+    mut q_fields: Query<(Entity, &mut NumericField)>,
+    mut commands: Commands,
+) {
+    for (entity, interaction) in &q_interactions {
+        match *interaction {
+            FluxInteraction::Pressed => {
+                let Ok((_, mut field)) = q_fields.get_mut(entity) else {
+                    continue;
+                };
+
+                field.scrub_distance = 0.;
+            }
+            FluxInteraction::Released => {
+                let Ok((_, field)) = q_fields.get(entity) else {
+                    continue;
+                };
+                if field.editing || field.scrub_distance >= CLICK_TOLERANCE {
+                    continue;
+                }
+
+                // Only one field is edited at a time
+                for (other, mut field) in &mut q_fields {
+                    if other != entity && field.editing {
+                        NumericField::stop_editing(other, &mut field, true, &mut commands);
+                    }
+                }
+
+                let Ok((_, mut field)) = q_fields.get_mut(entity) else {
+                    continue;
+                };
+
+                field.buffer = format!("{:.*}", field.config.precision, field.value);
+                field.editing = true;
+                commands
+                    .entity(entity)
+                    .add_pseudo_state(PseudoState::Selected);
+            }
+            _ => (),
+        }
+    }
+}
+
+fn handle_numeric_field_keyboard(
+    mut ev_keyboard: EventReader<KeyboardInput>,
+    mut q_fields: Query<(Entity, &mut NumericField)>,
+    mut commands: Commands,
+) {
+    let pressed: Vec<&KeyboardInput> = ev_keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .collect();
+    if pressed.is_empty() {
+        return;
+    }
+
+    for (entity, mut field) in &mut q_fields {
+        if !field.editing {
+            continue;
+        }
+
+        for input in &pressed {
+            match &input.logical_key {
+                Key::Enter | Key::Tab => {
+                    NumericField::stop_editing(entity, &mut field, true, &mut commands);
+                    break;
+                }
+                Key::Escape => {
+                    NumericField::stop_editing(entity, &mut field, false, &mut commands);
+                    break;
+                }
+                Key::Backspace => {
+                    field.buffer.pop();
+                }
+                Key::Character(chars) => {
+                    field.buffer.extend(chars.chars().filter(|c| {
+                        c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+' | 'e' | 'E')
+                    }));
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+fn commit_numeric_field_on_outside_press(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    mut q_fields: Query<(Entity, &mut NumericField, &Interaction)>,
+    mut commands: Commands,
+) {
+    if !(r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
+        || r_touches.any_just_pressed())
+    {
+        return;
+    }
+
+    for (entity, mut field, interaction) in &mut q_fields {
+        if field.editing && *interaction == Interaction::None {
+            NumericField::stop_editing(entity, &mut field, true, &mut commands);
+        }
+    }
+}
+
+fn update_numeric_field_text(
+    q_fields: Query<&NumericField, Changed<NumericField>>,
+    mut commands: Commands,
+) {
+    for field in &q_fields {
+        let text = match field.editing {
+            true => format!("{}|", field.buffer),
+            false => field.config.format(field.value),
+        };
+
+        commands.entity(field.text).update_text(text);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum NumericScale {
+    #[default]
+    Linear,
+    /// Values are spread evenly by magnitude. Requires a positive range, falls back to
+    /// linear otherwise.
+    Logarithmic,
+}
+
+impl NumericScale {
+    /// The value at `ratio` (0-1) of the range
+    pub fn lerp(&self, min: f32, max: f32, ratio: f32) -> f32 {
+        match self {
+            NumericScale::Logarithmic if min > 0. && max > 0. => min * (max / min).powf(ratio),
+            _ => min.lerp(max, ratio),
+        }
+    }
+
+    /// The ratio (0-1) of the range at `value`
+    pub fn ratio(&self, min: f32, max: f32, value: f32) -> f32 {
+        if max == min {
+            return 0.;
+        }
+
+        let ratio = match self {
+            NumericScale::Logarithmic if min > 0. && max > 0. => {
+                (value.max(min) / min).ln() / (max / min).ln()
+            }
+            _ => (value - min) / (max - min),
+        };
+
+        ratio.clamp(0., 1.)
+    }
+}
+
+/// Rounds `value` to the closest multiple of `step` counted from `origin`
+///
+/// A `step` of zero (or less) disables snapping.
+pub(crate) fn snap_to_step(value: f32, origin: f32, step: f32) -> f32 {
+    if step <= 0. {
+        return value;
+    }
+
+    origin + ((value - origin) / step).round() * step
+}
+
+/// Reads and writes any primitive number behind a `dyn Reflect` as an `f64`
+macro_rules! reflect_numbers {
+    ($($number:ty => $kind:expr),* $(,)?) => {
+        pub(crate) fn read_number(value: &dyn Reflect) -> Option<(f64, NumberKind)> {
+            $(
+                if let Some(number) = value.downcast_ref::<$number>() {
+                    return Some((*number as f64, $kind));
+                }
+            )*
+
+            None
+        }
+
+        /// Returns `true` if the number was changed
+        pub(crate) fn write_number(target: &mut dyn Reflect, value: f64) -> bool {
+            $(
+                if let Some(number) = target.downcast_mut::<$number>() {
+                    let value = value as $number;
+                    if *number == value {
+                        return false;
+                    }
+
+                    *number = value;
+                    return true;
+                }
+            )*
+
+            false
+        }
+    };
+}
+
+reflect_numbers!(
+    f32 => NumberKind::Float,
+    f64 => NumberKind::Float,
+    i8 => NumberKind::Signed,
+    i16 => NumberKind::Signed,
+    i32 => NumberKind::Signed,
+    i64 => NumberKind::Signed,
+    isize => NumberKind::Signed,
+    u8 => NumberKind::Unsigned,
+    u16 => NumberKind::Unsigned,
+    u32 => NumberKind::Unsigned,
+    u64 => NumberKind::Unsigned,
+    usize => NumberKind::Unsigned,
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum NumberKind {
+    Float,
+    Signed,
+    Unsigned,
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct NumericFieldConfig {
+    pub value: f32,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    /// Keep values within `min` and `max`. Unclamped fields accept any typed or scrubbed value.
+    pub clamped: bool,
+    /// Values snap to multiples of the step from `min` (or zero). Zero disables snapping.
+    pub step: f32,
+    /// Number of decimals displayed
+    pub precision: usize,
+    pub scale: NumericScale,
+    /// Change of the value per dragged pixel on a linear scale
+    pub drag_speed: f32,
+    /// Displayed after the value, i.e. " px" or "%"
+    pub suffix: String,
+}
+
+impl Default for NumericFieldConfig {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            min: None,
+            max: None,
+            clamped: true,
+            step: 0.,
+            precision: 2,
+            scale: NumericScale::Linear,
+            drag_speed: 0.1,
+            suffix: "".into(),
+        }
+    }
+}
+
+impl NumericFieldConfig {
+    pub fn new(value: f32) -> Self {
+        Self { value, ..default() }
+    }
+
+    pub fn with_range(self, min: f32, max: f32) -> Self {
+        if max <= min {
+            panic!("Invalid numeric field range! Min: {}, Max: {}", min, max);
+        }
+
+        Self {
+            min: min.into(),
+            max: max.into(),
+            ..self
+        }
+    }
+
+    pub fn unclamped(self) -> Self {
+        Self {
+            clamped: false,
+            ..self
+        }
+    }
+
+    pub fn with_step(self, step: f32) -> Self {
+        Self { step, ..self }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    pub fn with_scale(self, scale: NumericScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_drag_speed(self, drag_speed: f32) -> Self {
+        Self { drag_speed, ..self }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
+    /// Snaps the value to the step and clamps it to the range, if the field is clamped
+    pub fn constrain(&self, value: f32) -> f32 {
+        let value = snap_to_step(value, self.min.unwrap_or(0.), self.step);
+
+        self.clamp(value)
+    }
+
+    pub fn format(&self, value: f32) -> String {
+        format!("{:.*}{}", self.precision, value, self.suffix)
+    }
+
+    /// Parses typed text, with or without the suffix
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        let text = match self.suffix.trim().is_empty() {
+            true => text,
+            false => text.trim_end_matches(self.suffix.trim()).trim_end(),
+        };
+
+        text.replace(',', ".")
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        if !self.clamped {
+            return value;
+        }
+
+        let value = match self.min {
+            Some(min) => value.max(min),
+            None => value,
+        };
+
+        match self.max {
+            Some(max) => value.min(max),
+            None => value,
+        }
+    }
+
+    fn scrub(&self, value: f32, pixels: f32) -> f32 {
+        let value = match self.scale {
+            NumericScale::Linear => value + pixels * self.drag_speed,
+            NumericScale::Logarithmic => {
+                value.max(f32::MIN_POSITIVE) * (pixels * LOG_SCRUB_RATE).exp()
+            }
+        };
+
+        self.clamp(value)
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct NumericField {
+    value: f32,
+    config: NumericFieldConfig,
+    text: Entity,
+    editing: bool,
+    buffer: String,
+    scrub_base: Option<f32>,
+    scrub_value: f32,
+    scrub_distance: f32,
+}
+
+impl Default for NumericField {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            config: NumericFieldConfig::default(),
+            text: Entity::PLACEHOLDER,
+            editing: false,
+            buffer: "".into(),
+            scrub_base: None,
+            scrub_value: 0.,
+            scrub_distance: 0.,
+        }
+    }
+}
+
+impl UiContext for NumericField {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            NumericField::TEXT => Ok(self.text),
+            _ => Err(format!(
+                "{} doesn't exist for NumericField. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [NumericField::TEXT].into_iter()
+    }
+}
+
+impl DefaultTheme for NumericField {
+    fn default_theme() -> Option<Theme<NumericField>> {
+        NumericField::theme().into()
+    }
+}
+
+impl NumericField {
+    pub const TEXT: &'static str = "Text";
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the value, snapped and clamped as configured
+    pub fn set_value(&mut self, value: f32) {
+        let value = self.config.constrain(value);
+        if self.value != value {
+            self.value = value;
+        }
+    }
+
+    pub fn config(&self) -> &NumericFieldConfig {
+        &self.config
+    }
+
+    /// Checks if the value is being typed
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Checks if the value is being changed by dragging
+    pub fn is_scrubbing(&self) -> bool {
+        self.scrub_base.is_some()
+    }
+
+    pub fn theme() -> Theme<NumericField> {
+        let base_theme = PseudoTheme::deferred(None, NumericField::primary_style);
+        let editing_theme =
+            PseudoTheme::deferred(vec![PseudoState::Selected], NumericField::editing_style);
+
+        let error_theme =
+            PseudoTheme::deferred(vec![PseudoState::Error], NumericField::error_style);
+        Theme::new(vec![base_theme, editing_theme, error_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+
+        style_builder
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+            .min_width(Val::Px(theme_spacing.areas.medium))
+            .min_height(Val::Px(theme_data.min_hit_target()))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.small),
+                Val::Px(theme_spacing.gaps.extra_small),
+            ))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .border_color(colors.accent(Accent::OutlineVariant))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(NumericField::TEXT)
+            .sized_font(font)
+            .font_color(colors.on(OnColor::Surface));
+    }
+
+    fn editing_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .border_color(colors.accent(Accent::Primary))
+            .background_color(colors.container(Container::SurfaceHighest));
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.border_color(colors.accent(Accent::Error));
+    }
+
+    fn stop_editing(
+        entity: Entity,
+        field: &mut NumericField,
+        commit: bool,
+        commands: &mut Commands,
+    ) {
+        field.editing = false;
+        commands
+            .entity(entity)
+            .remove_pseudo_state(PseudoState::Selected);
+
+        let buffer = std::mem::take(&mut field.buffer);
+        if !commit {
+            return;
+        }
+
+        let Some(value) = field.config.parse(&buffer) else {
+            return;
+        };
+        let value = field.config.constrain(value);
+        if field.value != value {
+            field.value = value;
+
+            #[cfg(feature = "observable")]
+            commands.trigger_targets(NumericFieldChanged { value }, entity);
+        }
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Numeric Field"),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            Draggable::default(),
+            RelativeCursorPosition::default(),
+        )
+    }
+}
+
+pub trait UiNumericFieldExt {
+    fn numeric_field(&mut self, config: NumericFieldConfig) -> UiBuilder<Entity>;
+}
+
+impl UiNumericFieldExt for UiBuilder<'_, Entity> {
+    /// A numeric value that can be typed or scrubbed.
+    ///
+    /// Click to type a value, Enter or a press elsewhere commits and Escape cancels it.
+    /// Drag horizontally to scrub, hold Shift for fine and Ctrl for coarse changes.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Selected`, while the value is being typed
+    /// - `PseudoState::Error`, when a `Validation` on it fails
+    fn numeric_field(&mut self, config: NumericFieldConfig) -> UiBuilder<Entity> {
+        let mut text = Entity::PLACEHOLDER;
+        let label = config.format(config.constrain(config.value));
+
+        let mut field = self.container(NumericField::frame(), |container| {
+            text = container.label(LabelConfig::from(label)).id();
+        });
+
+        field.insert(NumericField {
+            value: config.constrain(config.value),
+            config,
+            text,
+            ..default()
+        });
+
+        field
+    }
+}
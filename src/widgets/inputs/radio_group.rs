@@ -26,6 +26,7 @@ impl Plugin for RadioGroupPlugin {
             (
                 toggle_radio_button,
                 update_radio_group_buttons,
+                update_radio_group_errors,
                 update_radio_button,
             )
                 .chain()
@@ -97,6 +98,25 @@ fn update_radio_group_buttons(
     }
 }
 
+// Radio buttons show the error of their group
+fn update_radio_group_errors(
+    q_radio_groups: Query<(&PseudoStates, &Children), (With<RadioGroup>, Changed<PseudoStates>)>,
+    q_radio_buttons: Query<(), With<RadioButton>>,
+    mut commands: Commands,
+) {
+    for (pseudo_states, children) in &q_radio_groups {
+        let error = pseudo_states.has(&PseudoState::Error);
+        for child in children.iter().filter(|child| q_radio_buttons.contains(**child)) {
+            match error {
+                true => commands.entity(*child).add_pseudo_state(PseudoState::Error),
+                false => commands
+                    .entity(*child)
+                    .remove_pseudo_state(PseudoState::Error),
+            };
+        }
+    }
+}
+
 fn update_radio_button(
     q_radio_buttons: Query<(Entity, &RadioButton), Changed<RadioButton>>,
     mut commands: Commands,
@@ -230,15 +250,19 @@ impl RadioButton {
         let base_theme = PseudoTheme::deferred(None, RadioButton::primary_style);
         let checked_theme =
             PseudoTheme::deferred(vec![PseudoState::Checked], RadioButton::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+        let error_theme =
+            PseudoTheme::deferred(vec![PseudoState::Error], RadioButton::error_style);
+        Theme::new(vec![base_theme, checked_theme, error_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
+            .flex_direction(theme_data.layout_direction.row())
             .height(Val::Px(theme_spacing.areas.small))
+            .min_height(Val::Px(theme_data.min_hit_target()))
             .justify_content(JustifyContent::Start)
             .align_items(AlignItems::Center)
             .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
@@ -276,7 +300,8 @@ impl RadioButton {
                 hover: colors.accent(Accent::Primary).into(),
                 ..default()
             })
-            .copy_from(theme_data.interaction_animation);
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
 
         style_builder
             .switch_target(RadioButton::RADIOMARK)
@@ -286,9 +311,7 @@ impl RadioButton {
                 theme_spacing.inputs.radio_button.radiomark_size,
             )));
 
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
         style_builder
             .switch_target(RadioButton::LABEL)
             .margin(UiRect::px(
@@ -308,12 +331,14 @@ impl RadioButton {
     }
 
     fn checked_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
             .switch_target(RadioButton::RADIOMARK_BACKGROUND)
+            .switch_transition(theme_data.style_transition)
             .background_color(colors.accent(Accent::Primary))
+            .reset_transition()
             .border(UiRect::all(Val::Px(0.)));
 
         style_builder
@@ -341,6 +366,24 @@ impl RadioButton {
             .font_color(colors.on(OnColor::SurfaceVariant));
     }
 
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(RadioButton::RADIOMARK_BACKGROUND)
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::Error),
+                hover: colors.container(Container::Error).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(RadioButton::LABEL)
+            .font_color(colors.accent(Accent::Error));
+    }
+
     fn button(name: String) -> impl Bundle {
         (
             Name::new(name),
@@ -389,6 +432,7 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is added to the currently selected `RadioButton` entity
+    /// - `PseudoState::Error` is mirrored from the group to its `RadioButton` entities
     fn radio_group(
         &mut self,
         options: Vec<impl Into<String>>,
@@ -423,7 +467,7 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
                                 radio_mark_bg.spawn(RadioButton::radio_mark()).id();
                         })
                         .id();
-                    radio_button.label = button.label(LabelConfig { label, ..default() }).id();
+                    radio_button.label = button.label(LabelConfig::from(label)).id();
                 })
                 .insert(radio_button);
 
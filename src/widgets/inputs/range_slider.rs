@@ -0,0 +1,1093 @@
+use std::ops::DerefMut;
+
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    inputs::{
+        numeric_field::{
+            snap_to_step, NumericField, NumericFieldConfig, NumericFieldUpdate, NumericScale,
+            UiNumericFieldExt,
+        },
+        slider::{
+            position_slider_handle, slider_bar_press_ratio, slider_drag_fraction,
+            slider_scroll_fraction, snap_to_ticks, Slider, SliderAxis, SliderTick, SliderTickMark,
+        },
+    },
+    layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+    },
+};
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct RangeSliderChanged {
+    pub min: f32,
+    pub max: f32,
+}
+
+pub struct RangeSliderPlugin;
+
+impl Plugin for RangeSliderPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ComponentThemePlugin<SliderTickMark>>() {
+            app.add_plugins(ComponentThemePlugin::<SliderTickMark>::default());
+        }
+
+        app.add_plugins(ComponentThemePlugin::<RangeSlider>::default())
+            .add_systems(
+                Update,
+                (
+                    update_range_slider_on_scroll.after(ScrollableUpdate),
+                    update_range_slider_on_drag.after(DraggableUpdate),
+                    update_range_slider_on_bar_press.after(FluxInteractionUpdate),
+                    update_range_slider_from_readouts.after(NumericFieldUpdate),
+                    update_range_slider_on_bar_change,
+                    update_range_slider_handles,
+                    update_range_slider_readouts,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "observable")]
+        app.add_event::<RangeSliderChanged>();
+    }
+}
+
+fn update_range_slider_on_scroll(
+    q_scrollables: Query<
+        (
+            AnyOf<(&RangeSliderBar, &RangeSliderDragHandle)>,
+            &Scrollable,
+        ),
+        Changed<Scrollable>,
+    >,
+    mut q_slider: Query<&mut RangeSlider>,
+    mut commands: Commands,
+) {
+    for ((slider_bar, handle), scrollable) in &q_scrollables {
+        let Some(fraction) = slider_scroll_fraction(scrollable) else {
+            continue;
+        };
+
+        // Scrolling a handle moves it, scrolling the bar moves the whole range
+        let (slider_id, handle) = if let Some(handle) = handle {
+            (handle.slider, Some(handle.handle))
+        } else if let Some(slider_bar) = slider_bar {
+            (slider_bar.slider, None)
+        } else {
+            continue;
+        };
+
+        let Ok(mut slider) = q_slider.get_mut(slider_id) else {
+            continue;
+        };
+
+        match handle {
+            Some(handle) => {
+                let ratio = slider.ratio(handle) + fraction;
+                slider.set_ratio(handle, ratio);
+            }
+            None => {
+                let shift = fraction.clamp(-slider.min_ratio, 1. - slider.max_ratio);
+                slider.min_ratio += shift;
+                slider.max_ratio += shift;
+            }
+        }
+
+        #[cfg(feature = "observable")]
+        slider.trigger_changed(slider_id, &mut commands);
+    }
+}
+
+fn update_range_slider_on_drag(
+    q_draggable: Query<(&Draggable, &RangeSliderDragHandle, &Node), Changed<Draggable>>,
+    q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
+    mut q_slider: Query<&mut RangeSlider>,
+    mut commands: Commands,
+) {
+    for (draggable, handle, node) in &q_draggable {
+        let Ok(mut slider) = q_slider.get_mut(handle.slider) else {
+            continue;
+        };
+
+        if draggable.state == DragState::Inactive || draggable.state == DragState::MaybeDragged {
+            continue;
+        }
+
+        if draggable.state == DragState::DragCanceled {
+            if let Some((min_ratio, max_ratio)) = slider.base_ratios {
+                slider.min_ratio = min_ratio;
+                slider.max_ratio = max_ratio;
+                continue;
+            }
+        }
+
+        if draggable.state == DragState::DragStart {
+            slider.base_ratios = (slider.min_ratio, slider.max_ratio).into();
+        }
+
+        let Ok(slider_bar) = q_node.get(slider.bar_container) else {
+            continue;
+        };
+        let Some(diff) = draggable.diff else {
+            continue;
+        };
+        let Some(fraction) = slider_drag_fraction(
+            slider.config.axis,
+            theme_data.layout_direction,
+            diff,
+            slider_bar,
+            node,
+        ) else {
+            continue;
+        };
+
+        let ratio = slider.ratio(handle.handle) + fraction;
+        slider.set_ratio(handle.handle, ratio);
+
+        #[cfg(feature = "observable")]
+        slider.trigger_changed(handle.slider, &mut commands);
+    }
+}
+
+fn update_range_slider_on_bar_press(
+    q_slider_bars: Query<
+        (&RangeSliderBar, &FluxInteraction, &RelativeCursorPosition),
+        Changed<FluxInteraction>,
+    >,
+    q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
+    mut q_slider: Query<&mut RangeSlider>,
+    mut commands: Commands,
+) {
+    for (bar, interaction, cursor_position) in &q_slider_bars {
+        if *interaction != FluxInteraction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor_position.normalized else {
+            continue;
+        };
+        let Ok(mut slider) = q_slider.get_mut(bar.slider) else {
+            continue;
+        };
+        let (Ok(slider_bar), Ok(handle)) = (
+            q_node.get(slider.bar_container),
+            q_node.get(slider.min_handle),
+        ) else {
+            continue;
+        };
+        let Some(target) = slider_bar_press_ratio(
+            slider.config.axis,
+            theme_data.layout_direction,
+            position,
+            slider_bar,
+            handle,
+        ) else {
+            continue;
+        };
+
+        // The closest handle jumps to the pressed position
+        let handle = if target > slider.max_ratio
+            || (target - slider.max_ratio).abs() < (target - slider.min_ratio).abs()
+        {
+            RangeSliderHandle::Max
+        } else {
+            RangeSliderHandle::Min
+        };
+        if slider.ratio(handle) == target {
+            continue;
+        }
+
+        slider.set_ratio(handle, target);
+
+        #[cfg(feature = "observable")]
+        slider.trigger_changed(bar.slider, &mut commands);
+    }
+}
+
+fn update_range_slider_from_readouts(
+    q_readouts: Query<(&NumericField, &RangeSliderReadout), Changed<NumericField>>,
+    mut q_slider: Query<&mut RangeSlider>,
+    mut commands: Commands,
+) {
+    for (field, readout) in &q_readouts {
+        if field.is_editing() {
+            continue;
+        }
+        let Ok(mut slider) = q_slider.get_mut(readout.slider) else {
+            continue;
+        };
+        if slider.value(readout.handle) == field.value() {
+            continue;
+        }
+
+        let ratio = slider.ratio_of(field.value());
+        slider.set_ratio(readout.handle, ratio);
+
+        #[cfg(feature = "observable")]
+        slider.trigger_changed(readout.slider, &mut commands);
+    }
+}
+
+fn update_range_slider_on_bar_change(
+    q_slider_bars: Query<&RangeSliderBar, Changed<Node>>,
+    mut q_slider: Query<&mut RangeSlider>,
+) {
+    for bar in &q_slider_bars {
+        let Ok(mut slider) = q_slider.get_mut(bar.slider) else {
+            continue;
+        };
+
+        slider.deref_mut();
+    }
+}
+
+fn update_range_slider_handles(
+    q_slider: Query<(Ref<RangeSlider>, Ref<Node>)>,
+    q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
+    mut q_style: Query<&mut Style>,
+) {
+    let direction = theme_data.layout_direction;
+    for (slider, slider_node) in &q_slider {
+        if !slider.is_changed() && !slider_node.is_changed() && !theme_data.is_changed() {
+            continue;
+        }
+
+        let Ok(slider_bar) = q_node.get(slider.bar_container) else {
+            continue;
+        };
+
+        let axis = slider.config.axis;
+        let min_ratio = slider.ratio_of(slider.min_value());
+        let max_ratio = slider.ratio_of(slider.max_value());
+        for (handle, ratio) in [
+            (slider.min_handle, min_ratio),
+            (slider.max_handle, max_ratio),
+        ] {
+            let (Ok(node), Ok(mut style)) = (q_node.get(handle), q_style.get_mut(handle)) else {
+                continue;
+            };
+
+            position_slider_handle(axis, direction, ratio, slider_bar, node, &mut style);
+        }
+
+        let (Ok(handle), Ok(mut style)) =
+            (q_node.get(slider.min_handle), q_style.get_mut(slider.range))
+        else {
+            continue;
+        };
+
+        // The range spans between the handle centers
+        match axis {
+            SliderAxis::Horizontal => {
+                let width = slider_bar.size().x - handle.size().x;
+                let start = direction.pick(min_ratio, 1. - max_ratio);
+                let left = Val::Px(width * start + handle.size().x / 2.);
+                let length = Val::Px(width * (max_ratio - min_ratio));
+                if style.left != left || style.width != length {
+                    style.left = left;
+                    style.width = length;
+                }
+            }
+            SliderAxis::Vertical => {
+                let height = slider_bar.size().y - handle.size().y;
+                let top = Val::Px(height * (1. - max_ratio) + handle.size().y / 2.);
+                let length = Val::Px(height * (max_ratio - min_ratio));
+                if style.top != top || style.height != length {
+                    style.top = top;
+                    style.height = length;
+                }
+            }
+        }
+    }
+}
+
+fn update_range_slider_readouts(
+    q_slider: Query<&RangeSlider, Changed<RangeSlider>>,
+    mut q_readouts: Query<(&mut NumericField, &RangeSliderReadout)>,
+) {
+    for slider in &q_slider {
+        if !slider.config.show_current {
+            continue;
+        }
+
+        for readout in [slider.min_readout, slider.max_readout] {
+            let Ok((mut field, readout)) = q_readouts.get_mut(readout) else {
+                continue;
+            };
+
+            let value = slider.value(readout.handle);
+            if field.value() != value && !field.is_editing() && !field.is_scrubbing() {
+                field.set_value(value);
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum RangeSliderHandle {
+    #[default]
+    Min,
+    Max,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RangeSliderDragHandle {
+    pub slider: Entity,
+    pub handle: RangeSliderHandle,
+}
+
+impl Default for RangeSliderDragHandle {
+    fn default() -> Self {
+        Self {
+            slider: Entity::PLACEHOLDER,
+            handle: Default::default(),
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RangeSliderBar {
+    pub slider: Entity,
+}
+
+impl Default for RangeSliderBar {
+    fn default() -> Self {
+        Self {
+            slider: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RangeSliderReadout {
+    pub slider: Entity,
+    pub handle: RangeSliderHandle,
+}
+
+impl Default for RangeSliderReadout {
+    fn default() -> Self {
+        Self {
+            slider: Entity::PLACEHOLDER,
+            handle: Default::default(),
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct RangeSliderConfig {
+    pub label: Option<String>,
+    pub min: f32,
+    pub max: f32,
+    pub initial_min: f32,
+    pub initial_max: f32,
+    pub show_current: bool,
+    pub axis: SliderAxis,
+    /// Values snap to multiples of the step from `min`. Zero disables snapping.
+    pub step: f32,
+    /// Number of decimals displayed in the readouts
+    pub precision: usize,
+    pub scale: NumericScale,
+    /// Displayed after the values in the readouts
+    pub suffix: String,
+    /// Marks along the bar
+    pub ticks: Vec<SliderTick>,
+    /// Values snap to the closest tick instead of the step
+    pub snap_to_ticks: bool,
+}
+
+impl RangeSliderConfig {
+    pub fn new(
+        label: impl Into<Option<String>>,
+        min: f32,
+        max: f32,
+        initial_min: f32,
+        initial_max: f32,
+        show_current: bool,
+        axis: SliderAxis,
+    ) -> Self {
+        if max <= min || initial_min < min || initial_max > max || initial_min > initial_max {
+            panic!(
+                "Invalid range slider config values! Min: {}, Max: {}, Initial: {}..{}",
+                min, max, initial_min, initial_max
+            );
+        }
+
+        RangeSliderConfig {
+            label: label.into(),
+            min,
+            max,
+            initial_min,
+            initial_max,
+            show_current,
+            axis,
+            ..default()
+        }
+    }
+
+    pub fn horizontal(
+        label: impl Into<Option<String>>,
+        min: f32,
+        max: f32,
+        initial_min: f32,
+        initial_max: f32,
+        show_current: bool,
+    ) -> Self {
+        Self::new(
+            label.into(),
+            min,
+            max,
+            initial_min,
+            initial_max,
+            show_current,
+            SliderAxis::Horizontal,
+        )
+    }
+
+    pub fn vertical(
+        label: impl Into<Option<String>>,
+        min: f32,
+        max: f32,
+        initial_min: f32,
+        initial_max: f32,
+        show_current: bool,
+    ) -> Self {
+        Self::new(
+            label.into(),
+            min,
+            max,
+            initial_min,
+            initial_max,
+            show_current,
+            SliderAxis::Vertical,
+        )
+    }
+
+    pub fn with_values(self, min: f32, max: f32) -> Self {
+        if min >= self.min && max <= self.max && min <= max {
+            return Self {
+                initial_min: min,
+                initial_max: max,
+                ..self
+            };
+        }
+
+        panic!("Values must be ordered and between min and max!");
+    }
+
+    pub fn with_step(self, step: f32) -> Self {
+        Self { step, ..self }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    pub fn with_scale(self, scale: NumericScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn with_ticks(self, ticks: impl IntoIterator<Item = impl Into<SliderTick>>) -> Self {
+        Self {
+            ticks: ticks.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    pub fn with_snap_to_ticks(self) -> Self {
+        Self {
+            snap_to_ticks: true,
+            ..self
+        }
+    }
+
+    fn readout_config(&self, value: f32) -> NumericFieldConfig {
+        NumericFieldConfig {
+            value,
+            min: self.min.into(),
+            max: self.max.into(),
+            clamped: true,
+            step: self.step,
+            precision: self.precision,
+            scale: self.scale,
+            drag_speed: (self.max - self.min) / 200.,
+            suffix: self.suffix.clone(),
+        }
+    }
+}
+
+impl Default for RangeSliderConfig {
+    fn default() -> Self {
+        Self {
+            label: None,
+            min: 0.,
+            max: 1.,
+            initial_min: 0.25,
+            initial_max: 0.75,
+            show_current: Default::default(),
+            axis: Default::default(),
+            step: 0.,
+            precision: 1,
+            scale: Default::default(),
+            suffix: "".into(),
+            ticks: Vec::new(),
+            snap_to_ticks: false,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RangeSlider {
+    min_ratio: f32,
+    max_ratio: f32,
+    config: RangeSliderConfig,
+    label: Entity,
+    bar_container: Entity,
+    bar: Entity,
+    range: Entity,
+    min_handle: Entity,
+    max_handle: Entity,
+    ticks: Entity,
+    readout_container: Entity,
+    min_readout: Entity,
+    max_readout: Entity,
+    base_ratios: Option<(f32, f32)>,
+}
+
+impl Default for RangeSlider {
+    fn default() -> Self {
+        Self {
+            min_ratio: 0.,
+            max_ratio: 1.,
+            config: Default::default(),
+            base_ratios: None,
+            label: Entity::PLACEHOLDER,
+            bar_container: Entity::PLACEHOLDER,
+            bar: Entity::PLACEHOLDER,
+            range: Entity::PLACEHOLDER,
+            min_handle: Entity::PLACEHOLDER,
+            max_handle: Entity::PLACEHOLDER,
+            ticks: Entity::PLACEHOLDER,
+            readout_container: Entity::PLACEHOLDER,
+            min_readout: Entity::PLACEHOLDER,
+            max_readout: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for RangeSlider {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            RangeSlider::LABEL => Ok(self.label),
+            RangeSlider::BAR_CONTAINER => Ok(self.bar_container),
+            RangeSlider::BAR => Ok(self.bar),
+            RangeSlider::RANGE => Ok(self.range),
+            RangeSlider::MIN_HANDLE => Ok(self.min_handle),
+            RangeSlider::MAX_HANDLE => Ok(self.max_handle),
+            RangeSlider::TICKS => Ok(self.ticks),
+            RangeSlider::READOUT_CONTAINER => Ok(self.readout_container),
+            RangeSlider::MIN_READOUT => Ok(self.min_readout),
+            RangeSlider::MAX_READOUT => Ok(self.max_readout),
+            _ => Err(format!(
+                "{} doesn't exist for RangeSlider. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn cleared_contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        // The readouts are numeric fields with their own theme
+        [
+            RangeSlider::LABEL,
+            RangeSlider::BAR_CONTAINER,
+            RangeSlider::BAR,
+            RangeSlider::RANGE,
+            RangeSlider::MIN_HANDLE,
+            RangeSlider::MAX_HANDLE,
+            RangeSlider::TICKS,
+            RangeSlider::READOUT_CONTAINER,
+        ]
+        .into_iter()
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            RangeSlider::LABEL,
+            RangeSlider::BAR_CONTAINER,
+            RangeSlider::BAR,
+            RangeSlider::RANGE,
+            RangeSlider::MIN_HANDLE,
+            RangeSlider::MAX_HANDLE,
+            RangeSlider::TICKS,
+            RangeSlider::READOUT_CONTAINER,
+            RangeSlider::MIN_READOUT,
+            RangeSlider::MAX_READOUT,
+        ]
+        .into_iter()
+    }
+}
+
+impl DefaultTheme for RangeSlider {
+    fn default_theme() -> Option<Theme<RangeSlider>> {
+        RangeSlider::theme().into()
+    }
+}
+
+impl RangeSlider {
+    pub const LABEL: &'static str = "Label";
+    pub const BAR_CONTAINER: &'static str = "BarContainer";
+    pub const BAR: &'static str = "Bar";
+    pub const RANGE: &'static str = "Range";
+    pub const MIN_HANDLE: &'static str = "MinHandle";
+    pub const MAX_HANDLE: &'static str = "MaxHandle";
+    pub const TICKS: &'static str = "Ticks";
+    pub const READOUT_CONTAINER: &'static str = "ReadoutContainer";
+    pub const MIN_READOUT: &'static str = "MinReadout";
+    pub const MAX_READOUT: &'static str = "MaxReadout";
+
+    pub fn config(&self) -> &RangeSliderConfig {
+        &self.config
+    }
+
+    pub fn min_value(&self) -> f32 {
+        self.value_at(self.min_ratio)
+    }
+
+    pub fn max_value(&self) -> f32 {
+        self.value_at(self.max_ratio)
+    }
+
+    pub fn value(&self, handle: RangeSliderHandle) -> f32 {
+        self.value_at(self.ratio(handle))
+    }
+
+    pub fn set_values(&mut self, min: f32, max: f32) {
+        if min < self.config.min || max > self.config.max || min > max {
+            warn!("Tried to set range slider values outside of range or out of order");
+            return;
+        }
+
+        self.min_ratio = self.ratio_of(min);
+        self.max_ratio = self.ratio_of(max);
+    }
+
+    fn value_at(&self, ratio: f32) -> f32 {
+        let value = self
+            .config
+            .scale
+            .lerp(self.config.min, self.config.max, ratio);
+
+        let value = match self.config.snap_to_ticks {
+            true => snap_to_ticks(value, &self.config.ticks),
+            false => snap_to_step(value, self.config.min, self.config.step),
+        };
+
+        value.clamp(self.config.min, self.config.max)
+    }
+
+    fn ratio_of(&self, value: f32) -> f32 {
+        self.config
+            .scale
+            .ratio(self.config.min, self.config.max, value)
+    }
+
+    fn ratio(&self, handle: RangeSliderHandle) -> f32 {
+        match handle {
+            RangeSliderHandle::Min => self.min_ratio,
+            RangeSliderHandle::Max => self.max_ratio,
+        }
+    }
+
+    /// Moves a handle, stopping it at the other one so the handles can't cross
+    fn set_ratio(&mut self, handle: RangeSliderHandle, ratio: f32) {
+        match handle {
+            RangeSliderHandle::Min => self.min_ratio = ratio.clamp(0., self.max_ratio),
+            RangeSliderHandle::Max => self.max_ratio = ratio.clamp(self.min_ratio, 1.),
+        }
+    }
+
+    #[cfg(feature = "observable")]
+    fn trigger_changed(&self, slider: Entity, commands: &mut Commands) {
+        commands.trigger_targets(
+            RangeSliderChanged {
+                min: self.min_value(),
+                max: self.max_value(),
+            },
+            slider,
+        );
+    }
+
+    pub fn theme() -> Theme<RangeSlider> {
+        let base_theme = PseudoTheme::deferred_context(None, RangeSlider::primary_style);
+        let error_theme = PseudoTheme::deferred(vec![PseudoState::Error], RangeSlider::error_style);
+        Theme::new(vec![base_theme, error_theme])
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(RangeSlider::LABEL)
+            .font_color(colors.accent(Accent::Error));
+
+        style_builder
+            .switch_target(RangeSlider::BAR)
+            .border_color(colors.accent(Accent::Error));
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        slider: &RangeSlider,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let tick_label_room = Slider::tick_label_room(&slider.config().ticks, theme_data);
+        let handles = [RangeSlider::MIN_HANDLE, RangeSlider::MAX_HANDLE];
+        let direction = theme_data.layout_direction;
+
+        match slider.config().axis {
+            SliderAxis::Horizontal => {
+                style_builder
+                    .flex_direction(direction.row())
+                    .justify_content(JustifyContent::SpaceBetween)
+                    .align_items(AlignItems::Center)
+                    .width(Val::Percent(100.))
+                    .height(Val::Px(theme_spacing.areas.small + tick_label_room))
+                    .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.medium)));
+
+                style_builder
+                    .switch_target(RangeSlider::LABEL)
+                    .margin(direction.rect(UiRect::right(Val::Px(theme_spacing.gaps.medium))));
+
+                style_builder
+                    .switch_target(RangeSlider::BAR_CONTAINER)
+                    .width(Val::Percent(100.))
+                    .padding(UiRect::bottom(Val::Px(tick_label_room)));
+
+                style_builder
+                    .switch_target(RangeSlider::TICKS)
+                    .left(Val::Px(theme_spacing.icons.small / 2.))
+                    .right(Val::Px(theme_spacing.icons.small / 2.))
+                    .top(Val::Px(0.))
+                    .bottom(Val::Px(0.));
+
+                style_builder
+                    .switch_target(RangeSlider::BAR)
+                    .width(Val::Percent(100.))
+                    .height(Val::Px(theme_spacing.gaps.small))
+                    .margin(UiRect::vertical(Val::Px(theme_spacing.gaps.medium)));
+
+                style_builder
+                    .switch_target(RangeSlider::RANGE)
+                    .top(Val::Px(0.))
+                    .height(Val::Percent(100.));
+
+                style_builder
+                    .switch_target(RangeSlider::READOUT_CONTAINER)
+                    .flex_direction(direction.row())
+                    .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.medium))));
+
+                style_builder
+                    .switch_target(RangeSlider::MAX_READOUT)
+                    .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.small))));
+
+                for handle in handles {
+                    style_builder
+                        .switch_context(handle, None)
+                        .margin(UiRect::top(Val::Px(
+                            -theme_spacing.gaps.medium + theme_spacing.borders.extra_small,
+                        )));
+                }
+            }
+            SliderAxis::Vertical => {
+                style_builder
+                    .flex_direction(FlexDirection::ColumnReverse)
+                    .justify_content(JustifyContent::SpaceBetween)
+                    .align_items(AlignItems::Center)
+                    .height(Val::Percent(100.))
+                    .padding(UiRect::vertical(Val::Px(theme_spacing.gaps.medium)));
+
+                style_builder
+                    .switch_target(RangeSlider::LABEL)
+                    .margin(UiRect::px(
+                        theme_spacing.gaps.small,
+                        theme_spacing.gaps.small,
+                        theme_spacing.gaps.small,
+                        0.,
+                    ));
+
+                style_builder
+                    .switch_target(RangeSlider::BAR_CONTAINER)
+                    .flex_direction(FlexDirection::Column)
+                    .height(Val::Percent(100.))
+                    .padding(UiRect::right(Val::Px(tick_label_room)));
+
+                style_builder
+                    .switch_target(RangeSlider::TICKS)
+                    .top(Val::Px(theme_spacing.icons.small / 2.))
+                    .bottom(Val::Px(theme_spacing.icons.small / 2.))
+                    .left(Val::Px(0.))
+                    .right(Val::Px(0.));
+
+                style_builder
+                    .switch_target(RangeSlider::BAR)
+                    .flex_direction(FlexDirection::Column)
+                    .width(Val::Px(theme_spacing.gaps.small))
+                    .height(Val::Percent(100.))
+                    .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.medium)));
+
+                style_builder
+                    .switch_target(RangeSlider::RANGE)
+                    .left(Val::Px(0.))
+                    .width(Val::Percent(100.));
+
+                // The max readout is above the min readout
+                style_builder
+                    .switch_target(RangeSlider::READOUT_CONTAINER)
+                    .flex_direction(FlexDirection::ColumnReverse)
+                    .align_items(AlignItems::Center)
+                    .width(Val::Px(theme_spacing.areas.medium))
+                    .overflow(Overflow::clip());
+
+                style_builder
+                    .switch_target(RangeSlider::MIN_READOUT)
+                    .margin(UiRect::all(Val::Px(theme_spacing.gaps.small)));
+
+                style_builder
+                    .switch_target(RangeSlider::MAX_READOUT)
+                    .margin(UiRect::all(Val::Px(theme_spacing.gaps.small)));
+
+                for handle in handles {
+                    style_builder
+                        .switch_context(handle, None)
+                        .margin(UiRect::left(Val::Px(
+                            -theme_spacing.gaps.medium + theme_spacing.borders.extra_small,
+                        )));
+                }
+            }
+        }
+
+        style_builder.reset_context();
+
+        style_builder
+            .switch_target(RangeSlider::LABEL)
+            .sized_font(font.clone())
+            .font_color(colors.on(OnColor::Surface));
+
+        if slider.config().label.is_none() {
+            style_builder
+                .switch_target(RangeSlider::LABEL)
+                .display(Display::None)
+                .visibility(Visibility::Hidden);
+        } else {
+            style_builder
+                .switch_target(RangeSlider::LABEL)
+                .display(Display::Flex)
+                .visibility(Visibility::Inherited);
+        }
+
+        if !slider.config().show_current {
+            style_builder
+                .switch_target(RangeSlider::READOUT_CONTAINER)
+                .display(Display::None)
+                .visibility(Visibility::Hidden);
+        } else {
+            style_builder
+                .switch_target(RangeSlider::READOUT_CONTAINER)
+                .display(Display::Flex)
+                .visibility(Visibility::Inherited);
+        }
+
+        style_builder
+            .switch_target(RangeSlider::MIN_READOUT)
+            .min_width(Val::Px(theme_spacing.areas.medium));
+
+        style_builder
+            .switch_target(RangeSlider::MAX_READOUT)
+            .min_width(Val::Px(theme_spacing.areas.medium));
+
+        style_builder
+            .switch_target(RangeSlider::TICKS)
+            .position_type(PositionType::Absolute);
+
+        style_builder
+            .switch_target(RangeSlider::BAR)
+            .border(UiRect::px(
+                0.,
+                theme_spacing.borders.extra_small,
+                0.,
+                theme_spacing.borders.extra_small,
+            ))
+            .background_color(colors.surface(Surface::SurfaceVariant))
+            .border_color(colors.accent(Accent::Shadow));
+
+        style_builder
+            .switch_target(RangeSlider::RANGE)
+            .position_type(PositionType::Absolute)
+            .background_color(colors.accent(Accent::Primary));
+
+        for handle in handles {
+            style_builder
+                .switch_context(handle, None)
+                .position_type(PositionType::Absolute)
+                .size(Val::Px(theme_spacing.icons.small))
+                .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+                .border_color(colors.accent(Accent::Shadow))
+                .border_radius(BorderRadius::all(Val::Px(theme_spacing.icons.small)))
+                .animated()
+                .background_color(AnimatedVals {
+                    idle: colors.accent(Accent::Primary),
+                    hover: colors.container(Container::Primary).into(),
+                    ..default()
+                })
+                .copy_from(theme_data.interaction_animation);
+        }
+    }
+
+    fn container(name: String) -> impl Bundle {
+        (Name::new(name), NodeBundle::default())
+    }
+
+    fn bar_container(slider: Entity) -> impl Bundle {
+        (
+            Name::new("Bar Container"),
+            NodeBundle::default(),
+            Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
+            Scrollable::default(),
+            RangeSliderBar { slider },
+        )
+    }
+
+    fn bar() -> impl Bundle {
+        (Name::new("Range Slider Bar"), NodeBundle::default())
+    }
+
+    fn range() -> impl Bundle {
+        (Name::new("Range"), NodeBundle::default())
+    }
+
+    fn ticks() -> impl Bundle {
+        (Name::new("Ticks"), NodeBundle::default())
+    }
+
+    fn handle(slider: Entity, handle: RangeSliderHandle) -> impl Bundle {
+        let name = match handle {
+            RangeSliderHandle::Min => "Min Handle",
+            RangeSliderHandle::Max => "Max Handle",
+        };
+
+        (
+            Name::new(name),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            RangeSliderDragHandle { slider, handle },
+            Draggable::default(),
+            RelativeCursorPosition::default(),
+            Scrollable::default(),
+        )
+    }
+
+    fn readout_container() -> impl Bundle {
+        (Name::new("Readouts"), NodeBundle::default())
+    }
+}
+
+pub trait UiRangeSliderExt {
+    fn range_slider(&mut self, config: RangeSliderConfig) -> UiBuilder<Entity>;
+}
+
+impl UiRangeSliderExt for UiBuilder<'_, Entity> {
+    /// A slider with two handles selecting a range. The handles can't cross.
+    ///
+    /// Scrolling over a handle moves it, scrolling over the bar moves the whole range.
+    /// A press on the bar moves the closest handle to the pressed position.
+    fn range_slider(&mut self, config: RangeSliderConfig) -> UiBuilder<Entity> {
+        let mut slider = RangeSlider {
+            config: config.clone(),
+            ..default()
+        };
+        slider.min_ratio = slider.ratio_of(config.initial_min);
+        slider.max_ratio = slider.ratio_of(config.initial_max);
+        let min_readout_config = config.readout_config(slider.min_value());
+        let max_readout_config = config.readout_config(slider.max_value());
+
+        let label = config.label.clone().unwrap_or_default();
+        let name = match label.is_empty() {
+            false => format!("Range Slider [{}]", label),
+            true => "Range Slider".into(),
+        };
+
+        let mut input = self.container(RangeSlider::container(name), |container| {
+            let input_id = container.id();
+
+            slider.label = container.label(LabelConfig::from(label)).id();
+            slider.bar_container = container
+                .container(RangeSlider::bar_container(input_id), |bar_container| {
+                    // Spawned before the bar so the handles are drawn over the ticks
+                    slider.ticks = bar_container
+                        .container(RangeSlider::ticks(), |ticks| {
+                            SliderTickMark::spawn_all(ticks, &config.ticks, config.axis, |value| {
+                                config.scale.ratio(config.min, config.max, value)
+                            });
+                        })
+                        .id();
+                    slider.bar = bar_container
+                        .container(RangeSlider::bar(), |bar| {
+                            slider.range = bar.spawn(RangeSlider::range()).id();
+                            slider.min_handle = bar
+                                .spawn(RangeSlider::handle(input_id, RangeSliderHandle::Min))
+                                .id();
+                            slider.max_handle = bar
+                                .spawn(RangeSlider::handle(input_id, RangeSliderHandle::Max))
+                                .id();
+                        })
+                        .id();
+                })
+                .id();
+
+            slider.readout_container = container
+                .container(RangeSlider::readout_container(), |readout_container| {
+                    slider.min_readout = readout_container
+                        .numeric_field(min_readout_config)
+                        .insert(RangeSliderReadout {
+                            slider: input_id,
+                            handle: RangeSliderHandle::Min,
+                        })
+                        .id();
+                    slider.max_readout = readout_container
+                        .numeric_field(max_readout_config)
+                        .insert(RangeSliderReadout {
+                            slider: input_id,
+                            handle: RangeSliderHandle::Max,
+                        })
+                        .id();
+                })
+                .id();
+        });
+
+        input.insert(slider);
+
+        input
+    }
+}
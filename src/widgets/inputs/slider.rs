@@ -2,28 +2,44 @@ use std::ops::DerefMut;
 
 use bevy::{input::mouse::MouseScrollUnit, prelude::*, ui::RelativeCursorPosition};
 
-use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, UiLabelExt},
+use sickle_ui_scaffold::prelude::*;
+use crate::widgets::{
+    inputs::numeric_field::{
+        snap_to_step, NumericField, NumericFieldConfig, NumericFieldUpdate, NumericScale,
+        UiNumericFieldExt,
+    },
+    layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+    },
 };
 
+/// Fraction of the range a press on the bar moves the handle by, with `SliderBarClick::Page`
+const SLIDER_PAGE_RATIO: f32 = 0.1;
+
 #[cfg(feature = "observable")]
 #[derive(Event, Copy, Clone, Debug)]
 pub struct SliderChanged {
-    pub ratio: f32
+    pub ratio: f32,
+    pub value: f32,
 }
 
 pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ComponentThemePlugin<SliderTickMark>>() {
+            app.add_plugins(ComponentThemePlugin::<SliderTickMark>::default());
+        }
+
         app.add_plugins(ComponentThemePlugin::<Slider>::default())
             .add_systems(
                 Update,
                 (
                     update_slider_on_scroll.after(ScrollableUpdate),
                     update_slider_on_drag.after(DraggableUpdate),
+                    update_slider_on_bar_press.after(FluxInteractionUpdate),
+                    update_slider_from_readout.after(NumericFieldUpdate),
                     update_slider_on_bar_change,
                     update_slider_handle,
                     update_slider_readout,
@@ -36,8 +52,6 @@ impl Plugin for SliderPlugin {
     }
 }
 
-// TODO: Add input for value (w/ read/write flags)
-// TODO: Support click-on-bar value setting
 fn update_slider_on_scroll(
     q_scrollables: Query<
         (AnyOf<(&SliderBar, &SliderDragHandle)>, &Scrollable),
@@ -47,12 +61,9 @@ fn update_slider_on_scroll(
     mut commands: Commands,
 ) {
     for ((slider_bar, handle), scrollable) in &q_scrollables {
-        let Some((axis, diff, unit)) = scrollable.last_change() else {
+        let Some(fraction) = slider_scroll_fraction(scrollable) else {
             continue;
         };
-        if axis == ScrollAxis::Horizontal {
-            continue;
-        }
 
         let slider_id = if let Some(slider_bar) = slider_bar {
             slider_bar.slider
@@ -66,17 +77,12 @@ fn update_slider_on_scroll(
             continue;
         };
 
-        let offset = match unit {
-            MouseScrollUnit::Line => -diff * 5.,
-            MouseScrollUnit::Pixel => -diff,
-        };
-
-        let fraction = offset / 100.;
         slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
 
         #[cfg(feature = "observable")]
         commands.trigger_targets(SliderChanged {
-            ratio: slider.ratio
+            ratio: slider.ratio,
+            value: slider.value(),
         }, slider_id);
     }
 }
@@ -84,6 +90,7 @@ fn update_slider_on_scroll(
 fn update_slider_on_drag(
     q_draggable: Query<(&Draggable, &SliderDragHandle, &Node), Changed<Draggable>>,
     q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
     mut q_slider: Query<&mut Slider>,
     mut commands: Commands,
 ) {
@@ -114,33 +121,110 @@ fn update_slider_on_drag(
             continue;
         };
 
-        let axis = &slider.config.axis;
-        let fraction = match axis {
-            SliderAxis::Horizontal => {
-                let width = slider_bar.size().x - node.size().x;
-                if diff.x == 0. || width == 0. {
-                    continue;
-                }
-                diff.x / width
-            }
-            SliderAxis::Vertical => {
-                let height = slider_bar.size().y - node.size().y;
-                if diff.y == 0. || height == 0. {
-                    continue;
-                }
-                -diff.y / height
-            }
+        let Some(fraction) = slider_drag_fraction(
+            slider.config.axis,
+            theme_data.layout_direction,
+            diff,
+            slider_bar,
+            node,
+        ) else {
+            continue;
         };
 
         slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
 
         #[cfg(feature = "observable")]
         commands.trigger_targets(SliderChanged {
-            ratio: slider.ratio
+            ratio: slider.ratio,
+            value: slider.value(),
         }, handle.slider);
     }
 }
 
+fn update_slider_on_bar_press(
+    q_slider_bars: Query<
+        (&SliderBar, &FluxInteraction, &RelativeCursorPosition),
+        Changed<FluxInteraction>,
+    >,
+    q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
+    mut q_slider: Query<&mut Slider>,
+    mut commands: Commands,
+) {
+    for (bar, interaction, cursor_position) in &q_slider_bars {
+        if *interaction != FluxInteraction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor_position.normalized else {
+            continue;
+        };
+        let Ok(mut slider) = q_slider.get_mut(bar.slider) else {
+            continue;
+        };
+        let (Ok(slider_bar), Ok(handle)) = (
+            q_node.get(slider.bar_container),
+            q_node.get(slider.handle),
+        ) else {
+            continue;
+        };
+
+        let Some(target) = slider_bar_press_ratio(
+            slider.config.axis,
+            theme_data.layout_direction,
+            position,
+            slider_bar,
+            handle,
+        ) else {
+            continue;
+        };
+
+        let ratio = match slider.config.bar_click {
+            SliderBarClick::Jump => target,
+            SliderBarClick::Page if target > slider.ratio => {
+                (slider.ratio + SLIDER_PAGE_RATIO).min(target)
+            }
+            SliderBarClick::Page => (slider.ratio - SLIDER_PAGE_RATIO).max(target),
+        };
+        if ratio == slider.ratio {
+            continue;
+        }
+
+        slider.ratio = ratio;
+
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(SliderChanged {
+            ratio: slider.ratio,
+            value: slider.value(),
+        }, bar.slider);
+    }
+}
+
+fn update_slider_from_readout(
+    q_readouts: Query<(&NumericField, &SliderReadout), Changed<NumericField>>,
+    mut q_slider: Query<&mut Slider>,
+    mut commands: Commands,
+) {
+    for (field, readout) in &q_readouts {
+        if field.is_editing() {
+            continue;
+        }
+        let Ok(mut slider) = q_slider.get_mut(readout.slider) else {
+            continue;
+        };
+        if slider.value() == field.value() {
+            continue;
+        }
+
+        slider.ratio = slider.ratio_of(field.value());
+
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(SliderChanged {
+            ratio: slider.ratio,
+            value: slider.value(),
+        }, readout.slider);
+    }
+}
+
 fn update_slider_on_bar_change(
     q_slider_bars: Query<&SliderBar, Changed<Node>>,
     mut q_slider: Query<&mut Slider>,
@@ -155,11 +239,16 @@ fn update_slider_on_bar_change(
 }
 
 fn update_slider_handle(
-    q_slider: Query<&Slider, Or<(Changed<Slider>, Changed<Node>)>>,
+    q_slider: Query<(Ref<Slider>, Ref<Node>)>,
     q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
     mut q_hadle_style: Query<(&Node, &mut Style), With<SliderDragHandle>>,
 ) {
-    for slider in &q_slider {
+    for (slider, slider_node) in &q_slider {
+        if !slider.is_changed() && !slider_node.is_changed() && !theme_data.is_changed() {
+            continue;
+        }
+
         let Ok(slider_bar) = q_node.get(slider.bar_container) else {
             continue;
         };
@@ -167,38 +256,146 @@ fn update_slider_handle(
             continue;
         };
 
-        let axis = &slider.config.axis;
-        match axis {
-            SliderAxis::Horizontal => {
-                let width = slider_bar.size().x - node.size().x;
-                let handle_position = width * slider.ratio;
-                if style.left != Val::Px(handle_position) {
-                    style.left = Val::Px(handle_position);
-                }
-            }
-            SliderAxis::Vertical => {
-                let height = slider_bar.size().y - node.size().y;
-                let handle_position = height * (1. - slider.ratio);
-                if style.top != Val::Px(handle_position) {
-                    style.top = Val::Px(handle_position);
-                }
-            }
-        }
+        // The handle shows the snapped value
+        let ratio = slider.ratio_of(slider.value());
+        position_slider_handle(
+            slider.config.axis,
+            theme_data.layout_direction,
+            ratio,
+            slider_bar,
+            node,
+            &mut style,
+        );
     }
 }
 
-fn update_slider_readout(q_slider: Query<&Slider, Changed<Slider>>, mut commands: Commands) {
+fn update_slider_readout(
+    q_slider: Query<&Slider, Changed<Slider>>,
+    mut q_readouts: Query<&mut NumericField, With<SliderReadout>>,
+) {
     for slider in &q_slider {
         if !slider.config.show_current {
             continue;
         }
+        let Ok(mut readout) = q_readouts.get_mut(slider.readout) else {
+            continue;
+        };
+
+        let value = slider.value();
+        if readout.value() != value && !readout.is_editing() && !readout.is_scrubbing() {
+            readout.set_value(value);
+        }
+    }
+}
+
+/// Fraction of the range a vertical scroll over a slider moves it by
+pub(crate) fn slider_scroll_fraction(scrollable: &Scrollable) -> Option<f32> {
+    let (axis, diff, unit) = scrollable.last_change()?;
+    if axis == ScrollAxis::Horizontal {
+        return None;
+    }
+
+    let offset = match unit {
+        MouseScrollUnit::Line => -diff * 5.,
+        MouseScrollUnit::Pixel => -diff,
+    };
+
+    Some(offset / 100.)
+}
+
+/// Fraction of the range a handle is dragged by along the bar
+///
+/// Horizontal sliders grow towards the end side, right-to-left layouts have their minimum on the
+/// right.
+pub(crate) fn slider_drag_fraction(
+    axis: SliderAxis,
+    direction: LayoutDirection,
+    diff: Vec2,
+    bar: &Node,
+    handle: &Node,
+) -> Option<f32> {
+    match axis {
+        SliderAxis::Horizontal => {
+            let width = bar.size().x - handle.size().x;
+            if diff.x == 0. || width == 0. {
+                return None;
+            }
+            Some(direction.sign() * diff.x / width)
+        }
+        SliderAxis::Vertical => {
+            let height = bar.size().y - handle.size().y;
+            if diff.y == 0. || height == 0. {
+                return None;
+            }
+            Some(-diff.y / height)
+        }
+    }
+}
+
+/// Ratio that centers a handle on the pressed, normalized position of the bar
+pub(crate) fn slider_bar_press_ratio(
+    axis: SliderAxis,
+    direction: LayoutDirection,
+    position: Vec2,
+    bar: &Node,
+    handle: &Node,
+) -> Option<f32> {
+    let ratio = match axis {
+        SliderAxis::Horizontal => {
+            let width = bar.size().x - handle.size().x;
+            if width <= 0. {
+                return None;
+            }
+            let ratio = (position.x * bar.size().x - handle.size().x / 2.) / width;
+            direction.pick(ratio, 1. - ratio)
+        }
+        SliderAxis::Vertical => {
+            let height = bar.size().y - handle.size().y;
+            if height <= 0. {
+                return None;
+            }
+            1. - (position.y * bar.size().y - handle.size().y / 2.) / height
+        }
+    };
 
-        commands
-            .entity(slider.readout)
-            .update_text(format!("{:.1}", slider.value()));
+    Some(ratio.clamp(0., 1.))
+}
+
+pub(crate) fn position_slider_handle(
+    axis: SliderAxis,
+    direction: LayoutDirection,
+    ratio: f32,
+    bar: &Node,
+    handle: &Node,
+    style: &mut Style,
+) {
+    match axis {
+        SliderAxis::Horizontal => {
+            let width = bar.size().x - handle.size().x;
+            let handle_position = width * direction.pick(ratio, 1. - ratio);
+            if style.left != Val::Px(handle_position) {
+                style.left = Val::Px(handle_position);
+            }
+        }
+        SliderAxis::Vertical => {
+            let height = bar.size().y - handle.size().y;
+            let handle_position = height * (1. - ratio);
+            if style.top != Val::Px(handle_position) {
+                style.top = Val::Px(handle_position);
+            }
+        }
     }
 }
 
+/// Value of the tick closest to `value`, or `value` itself if there are no ticks
+pub(crate) fn snap_to_ticks(value: f32, ticks: &[SliderTick]) -> f32 {
+    ticks
+        .iter()
+        .map(|tick| tick.value)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
+
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
 pub enum SliderAxis {
     #[default]
@@ -234,6 +431,247 @@ impl Default for SliderBar {
     }
 }
 
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SliderReadout {
+    pub slider: Entity,
+}
+
+impl Default for SliderReadout {
+    fn default() -> Self {
+        Self {
+            slider: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+/// A mark along the bar at `value`, with an optional label
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct SliderTick {
+    pub value: f32,
+    pub label: Option<String>,
+}
+
+impl SliderTick {
+    pub fn new(value: f32) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn labeled(value: f32, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into().into(),
+        }
+    }
+}
+
+impl From<f32> for SliderTick {
+    fn from(value: f32) -> Self {
+        SliderTick::new(value)
+    }
+}
+
+impl From<(f32, &str)> for SliderTick {
+    fn from((value, label): (f32, &str)) -> Self {
+        SliderTick::labeled(value, label)
+    }
+}
+
+/// A tick of a slider, positioned along the bar by its theme
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SliderTickMark {
+    ratio: f32,
+    axis: SliderAxis,
+    has_label: bool,
+    mark: Entity,
+    label: Entity,
+}
+
+impl Default for SliderTickMark {
+    fn default() -> Self {
+        Self {
+            ratio: 0.,
+            axis: Default::default(),
+            has_label: false,
+            mark: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for SliderTickMark {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            SliderTickMark::MARK => Ok(self.mark),
+            SliderTickMark::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for SliderTickMark. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [SliderTickMark::MARK, SliderTickMark::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for SliderTickMark {
+    fn default_theme() -> Option<Theme<SliderTickMark>> {
+        SliderTickMark::theme().into()
+    }
+}
+
+impl SliderTickMark {
+    pub const MARK: &'static str = "Mark";
+    pub const LABEL: &'static str = "Label";
+
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn theme() -> Theme<SliderTickMark> {
+        let base_theme = PseudoTheme::deferred_context(None, SliderTickMark::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        tick_mark: &SliderTickMark,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Label, FontScale::Small, FontType::Regular);
+        let mark_length = theme_spacing.gaps.medium + theme_spacing.gaps.small;
+        let direction = theme_data.layout_direction;
+
+        // Ticks are zero-sized along the bar so their content is centered on the position
+        match tick_mark.axis {
+            SliderAxis::Horizontal => {
+                style_builder
+                    .left(Val::Percent(
+                        direction.pick(tick_mark.ratio, 1. - tick_mark.ratio) * 100.,
+                    ))
+                    .top(Val::Px(0.))
+                    .width(Val::Px(0.))
+                    .flex_direction(FlexDirection::Column);
+
+                style_builder
+                    .switch_target(SliderTickMark::MARK)
+                    .width(Val::Px(theme_spacing.borders.small))
+                    .height(Val::Px(mark_length))
+                    .margin(UiRect::top(Val::Px(theme_spacing.gaps.small)));
+
+                style_builder
+                    .switch_target(SliderTickMark::LABEL)
+                    .margin(UiRect::top(Val::Px(theme_spacing.gaps.extra_small)));
+            }
+            SliderAxis::Vertical => {
+                style_builder
+                    .top(Val::Percent((1. - tick_mark.ratio) * 100.))
+                    .left(Val::Px(0.))
+                    .height(Val::Px(0.))
+                    .flex_direction(FlexDirection::Row);
+
+                style_builder
+                    .switch_target(SliderTickMark::MARK)
+                    .width(Val::Px(mark_length))
+                    .height(Val::Px(theme_spacing.borders.small))
+                    .margin(UiRect::left(Val::Px(theme_spacing.gaps.small)));
+
+                style_builder
+                    .switch_target(SliderTickMark::LABEL)
+                    .margin(UiRect::left(Val::Px(theme_spacing.gaps.extra_small)));
+            }
+        }
+
+        style_builder.reset_target();
+
+        style_builder
+            .position_type(PositionType::Absolute)
+            .align_items(AlignItems::Center);
+
+        style_builder
+            .switch_target(SliderTickMark::MARK)
+            .background_color(colors.accent(Accent::OutlineVariant));
+
+        style_builder
+            .switch_target(SliderTickMark::LABEL)
+            .sized_font(font)
+            .font_color(colors.on(OnColor::SurfaceVariant));
+
+        if tick_mark.has_label {
+            style_builder
+                .switch_target(SliderTickMark::LABEL)
+                .display(Display::Flex)
+                .visibility(Visibility::Inherited);
+        } else {
+            style_builder
+                .switch_target(SliderTickMark::LABEL)
+                .display(Display::None)
+                .visibility(Visibility::Hidden);
+        }
+    }
+
+    fn frame(tick: &SliderTick) -> impl Bundle {
+        (
+            Name::new(format!("Tick [{}]", tick.value)),
+            NodeBundle::default(),
+        )
+    }
+
+    fn mark() -> impl Bundle {
+        (Name::new("Mark"), NodeBundle::default())
+    }
+
+    /// Spawns a tick mark for each tick, placed at the ratio `ratio_of` returns for its value
+    pub(crate) fn spawn_all(
+        container: &mut UiBuilder<Entity>,
+        ticks: &[SliderTick],
+        axis: SliderAxis,
+        ratio_of: impl Fn(f32) -> f32,
+    ) {
+        for tick in ticks.iter() {
+            let mut tick_mark = SliderTickMark {
+                ratio: ratio_of(tick.value).clamp(0., 1.),
+                axis,
+                has_label: tick.label.is_some(),
+                ..default()
+            };
+
+            let mut tick_container = container.container(SliderTickMark::frame(tick), |frame| {
+                tick_mark.mark = frame.spawn(SliderTickMark::mark()).id();
+                tick_mark.label = frame
+                    .label(LabelConfig {
+                        label: tick.label.clone().unwrap_or_default().into(),
+                        ..default()
+                    })
+                    .id();
+            });
+
+            tick_container.insert(tick_mark);
+        }
+    }
+
+    /// Whether any of the ticks has a label, which needs room next to the bar
+    pub(crate) fn any_labeled(ticks: &[SliderTick]) -> bool {
+        ticks.iter().any(|tick| tick.label.is_some())
+    }
+}
+
+/// What a press on the bar (outside of the handle) does
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum SliderBarClick {
+    /// Move the handle to the pressed position
+    #[default]
+    Jump,
+    /// Move the handle towards the pressed position by a tenth of the range
+    Page,
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct SliderConfig {
     pub label: Option<String>,
@@ -242,6 +680,18 @@ pub struct SliderConfig {
     pub initial_value: f32,
     pub show_current: bool,
     pub axis: SliderAxis,
+    /// Values snap to multiples of the step from `min`. Zero disables snapping.
+    pub step: f32,
+    /// Number of decimals displayed in the readout
+    pub precision: usize,
+    pub scale: NumericScale,
+    /// Displayed after the value in the readout
+    pub suffix: String,
+    pub bar_click: SliderBarClick,
+    /// Marks along the bar
+    pub ticks: Vec<SliderTick>,
+    /// Values snap to the closest tick instead of the step
+    pub snap_to_ticks: bool,
 }
 
 impl SliderConfig {
@@ -267,6 +717,7 @@ impl SliderConfig {
             initial_value,
             show_current,
             axis,
+            ..default()
         }
     }
 
@@ -314,6 +765,57 @@ impl SliderConfig {
 
         panic!("Value must be between min and max!");
     }
+
+    pub fn with_step(self, step: f32) -> Self {
+        Self { step, ..self }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    pub fn with_scale(self, scale: NumericScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn with_bar_click(self, bar_click: SliderBarClick) -> Self {
+        Self { bar_click, ..self }
+    }
+
+    pub fn with_ticks(self, ticks: impl IntoIterator<Item = impl Into<SliderTick>>) -> Self {
+        Self {
+            ticks: ticks.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    pub fn with_snap_to_ticks(self) -> Self {
+        Self {
+            snap_to_ticks: true,
+            ..self
+        }
+    }
+
+    fn readout_config(&self, value: f32) -> NumericFieldConfig {
+        NumericFieldConfig {
+            value,
+            min: self.min.into(),
+            max: self.max.into(),
+            clamped: true,
+            step: self.step,
+            precision: self.precision,
+            scale: self.scale,
+            drag_speed: (self.max - self.min) / 200.,
+            suffix: self.suffix.clone(),
+        }
+    }
 }
 
 impl Default for SliderConfig {
@@ -325,6 +827,13 @@ impl Default for SliderConfig {
             initial_value: 0.5,
             show_current: Default::default(),
             axis: Default::default(),
+            step: 0.,
+            precision: 1,
+            scale: Default::default(),
+            suffix: "".into(),
+            bar_click: Default::default(),
+            ticks: Vec::new(),
+            snap_to_ticks: false,
         }
     }
 }
@@ -338,6 +847,7 @@ pub struct Slider {
     bar_container: Entity,
     bar: Entity,
     handle: Entity,
+    ticks: Entity,
     readout_container: Entity,
     readout: Entity,
     base_ratio: Option<f32>,
@@ -353,6 +863,7 @@ impl Default for Slider {
             bar_container: Entity::PLACEHOLDER,
             bar: Entity::PLACEHOLDER,
             handle: Entity::PLACEHOLDER,
+            ticks: Entity::PLACEHOLDER,
             readout_container: Entity::PLACEHOLDER,
             readout: Entity::PLACEHOLDER,
         }
@@ -366,6 +877,7 @@ impl UiContext for Slider {
             Slider::BAR_CONTAINER => Ok(self.bar_container),
             Slider::BAR => Ok(self.bar),
             Slider::HANDLE => Ok(self.handle),
+            Slider::TICKS => Ok(self.ticks),
             Slider::READOUT_CONTAINER => Ok(self.readout_container),
             Slider::READOUT => Ok(self.readout),
             _ => Err(format!(
@@ -376,12 +888,26 @@ impl UiContext for Slider {
         }
     }
 
+    fn cleared_contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        // The readout is a numeric field with its own theme
+        [
+            Slider::LABEL,
+            Slider::BAR_CONTAINER,
+            Slider::BAR,
+            Slider::HANDLE,
+            Slider::TICKS,
+            Slider::READOUT_CONTAINER,
+        ]
+        .into_iter()
+    }
+
     fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
         [
             Slider::LABEL,
             Slider::BAR_CONTAINER,
             Slider::BAR,
             Slider::HANDLE,
+            Slider::TICKS,
             Slider::READOUT_CONTAINER,
             Slider::READOUT,
         ]
@@ -400,11 +926,22 @@ impl Slider {
     pub const BAR_CONTAINER: &'static str = "BarContainer";
     pub const BAR: &'static str = "Bar";
     pub const HANDLE: &'static str = "Handle";
+    pub const TICKS: &'static str = "Ticks";
     pub const READOUT_CONTAINER: &'static str = "ReadoutContainer";
     pub const READOUT: &'static str = "Readout";
 
     pub fn value(&self) -> f32 {
-        self.config.min.lerp(self.config.max, self.ratio)
+        let value = self
+            .config
+            .scale
+            .lerp(self.config.min, self.config.max, self.ratio);
+
+        let value = match self.config.snap_to_ticks {
+            true => snap_to_ticks(value, &self.config.ticks),
+            false => snap_to_step(value, self.config.min, self.config.step),
+        };
+
+        value.clamp(self.config.min, self.config.max)
     }
 
     pub fn config(&self) -> &SliderConfig {
@@ -417,37 +954,65 @@ impl Slider {
             return;
         }
 
-        self.ratio = (value - self.config.min) / (self.config.max + (0. - self.config.min))
+        self.ratio = self.ratio_of(value);
+    }
+
+    fn ratio_of(&self, value: f32) -> f32 {
+        self.config
+            .scale
+            .ratio(self.config.min, self.config.max, value)
     }
 
     pub fn theme() -> Theme<Slider> {
         let base_theme = PseudoTheme::deferred_context(None, Slider::primary_style);
-        Theme::new(vec![base_theme])
+        let error_theme = PseudoTheme::deferred(vec![PseudoState::Error], Slider::error_style);
+        Theme::new(vec![base_theme, error_theme])
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(Slider::LABEL)
+            .font_color(colors.accent(Accent::Error));
+
+        style_builder
+            .switch_target(Slider::BAR)
+            .border_color(colors.accent(Accent::Error));
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, slider: &Slider, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let tick_label_room = Slider::tick_label_room(&slider.config().ticks, theme_data);
+        let direction = theme_data.layout_direction;
 
         match slider.config().axis {
             SliderAxis::Horizontal => {
                 style_builder
+                    .flex_direction(direction.row())
                     .justify_content(JustifyContent::SpaceBetween)
                     .align_items(AlignItems::Center)
                     .width(Val::Percent(100.))
-                    .height(Val::Px(theme_spacing.areas.small))
+                    .height(Val::Px(theme_spacing.areas.small + tick_label_room))
                     .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.medium)));
 
                 style_builder
                     .switch_target(Slider::LABEL)
-                    .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)));
+                    .margin(direction.rect(UiRect::right(Val::Px(theme_spacing.gaps.medium))));
 
                 style_builder
                     .switch_target(Slider::BAR_CONTAINER)
-                    .width(Val::Percent(100.));
+                    .width(Val::Percent(100.))
+                    .padding(UiRect::bottom(Val::Px(tick_label_room)));
+
+                style_builder
+                    .switch_target(Slider::TICKS)
+                    .left(Val::Px(theme_spacing.icons.small / 2.))
+                    .right(Val::Px(theme_spacing.icons.small / 2.))
+                    .top(Val::Px(0.))
+                    .bottom(Val::Px(0.));
 
                 style_builder
                     .switch_target(Slider::BAR)
@@ -458,7 +1023,7 @@ impl Slider {
                 style_builder
                     .switch_target(Slider::READOUT)
                     .min_width(Val::Px(theme_spacing.areas.medium))
-                    .margin(UiRect::left(Val::Px(theme_spacing.gaps.medium)));
+                    .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.medium))));
 
                 style_builder
                     .switch_context(Slider::HANDLE, None)
@@ -486,7 +1051,15 @@ impl Slider {
                 style_builder
                     .switch_target(Slider::BAR_CONTAINER)
                     .flex_direction(FlexDirection::Column)
-                    .height(Val::Percent(100.));
+                    .height(Val::Percent(100.))
+                    .padding(UiRect::right(Val::Px(tick_label_room)));
+
+                style_builder
+                    .switch_target(Slider::TICKS)
+                    .top(Val::Px(theme_spacing.icons.small / 2.))
+                    .bottom(Val::Px(theme_spacing.icons.small / 2.))
+                    .left(Val::Px(0.))
+                    .right(Val::Px(0.));
 
                 style_builder
                     .switch_target(Slider::BAR)
@@ -546,9 +1119,8 @@ impl Slider {
         }
 
         style_builder
-            .switch_target(Slider::READOUT)
-            .sized_font(font.clone())
-            .font_color(colors.on(OnColor::Surface));
+            .switch_target(Slider::TICKS)
+            .position_type(PositionType::Absolute);
 
         style_builder
             .switch_target(Slider::BAR)
@@ -576,6 +1148,18 @@ impl Slider {
             .copy_from(theme_data.interaction_animation);
     }
 
+    /// Space next to the bar needed by tick labels, along the cross axis
+    pub(crate) fn tick_label_room(ticks: &[SliderTick], theme_data: &ThemeData) -> f32 {
+        if !SliderTickMark::any_labeled(ticks) {
+            return 0.;
+        }
+
+        let theme_spacing = theme_data.spacing();
+        let font = theme_data.font(FontStyle::Label, FontScale::Small, FontType::Regular);
+
+        font.size + theme_spacing.gaps.small
+    }
+
     fn container(name: String) -> impl Bundle {
         (Name::new(name), NodeBundle::default())
     }
@@ -585,6 +1169,8 @@ impl Slider {
             Name::new("Bar Container"),
             NodeBundle::default(),
             Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
             Scrollable::default(),
         )
     }
@@ -593,6 +1179,10 @@ impl Slider {
         (Name::new("Slider Bar"), NodeBundle::default())
     }
 
+    fn ticks() -> impl Bundle {
+        (Name::new("Ticks"), NodeBundle::default())
+    }
+
     fn handle(slider: Entity) -> impl Bundle {
         (
             Name::new("Handle"),
@@ -617,10 +1207,11 @@ pub trait UiSliderExt {
 impl UiSliderExt for UiBuilder<'_, Entity> {
     fn slider(&mut self, config: SliderConfig) -> UiBuilder<Entity> {
         let mut slider = Slider {
-            ratio: (config.initial_value - config.min) / (config.max + (0. - config.min)),
+            ratio: config.scale.ratio(config.min, config.max, config.initial_value),
             config: config.clone(),
             ..default()
         };
+        let readout_config = config.readout_config(slider.value());
 
         let label = match config.label {
             Some(label) => label,
@@ -635,11 +1226,22 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
         let mut input = self.container(Slider::container(name), |container| {
             let input_id = container.id();
 
-            slider.label = container.label(LabelConfig { label, ..default() }).id();
+            slider.label = container.label(LabelConfig::from(label)).id();
             slider.bar_container = container
                 .container(
                     (Slider::bar_container(), SliderBar { slider: input_id }),
                     |bar_container| {
+                        // Spawned before the bar so the handle is drawn over the ticks
+                        slider.ticks = bar_container
+                            .container(Slider::ticks(), |ticks| {
+                                SliderTickMark::spawn_all(
+                                    ticks,
+                                    &config.ticks,
+                                    config.axis,
+                                    |value| config.scale.ratio(config.min, config.max, value),
+                                );
+                            })
+                            .id();
                         slider.bar = bar_container
                             .container(Slider::bar(), |bar| {
                                 slider.handle = bar.spawn(Slider::handle(input_id)).id();
@@ -651,7 +1253,10 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
 
             slider.readout_container = container
                 .container(Slider::readout_container(), |readout_container| {
-                    slider.readout = readout_container.label(LabelConfig::default()).id();
+                    slider.readout = readout_container
+                        .numeric_field(readout_config)
+                        .insert(SliderReadout { slider: input_id })
+                        .id();
                 })
                 .id();
         });
@@ -0,0 +1,348 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::layout::{
+    container::UiContainerExt,
+    label::{LabelConfig, UiLabelExt},
+};
+
+#[cfg(feature = "observable")]
+use crate::widgets::inputs::checkbox::CheckboxChanged;
+
+pub struct SwitchPlugin;
+
+impl Plugin for SwitchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<Switch>::default())
+            .add_systems(
+                Update,
+                (toggle_switch, update_switch)
+                    .chain()
+                    .after(FluxInteractionUpdate),
+            );
+    }
+}
+
+fn toggle_switch(
+    mut q_switches: Query<(Entity, &mut Switch, &FluxInteraction), Changed<FluxInteraction>>,
+    mut commands: Commands,
+) {
+    for (entity, mut switch, interaction) in &mut q_switches {
+        if *interaction == FluxInteraction::Released {
+            switch.checked = !switch.checked;
+
+            #[cfg(feature = "observable")]
+            commands.trigger_targets(
+                CheckboxChanged {
+                    value: switch.checked,
+                },
+                entity,
+            );
+        }
+    }
+}
+
+fn update_switch(
+    q_switches: Query<(Entity, Ref<Switch>), Changed<Switch>>,
+    mut commands: Commands,
+) {
+    for (entity, switch) in &q_switches {
+        match switch.checked {
+            true => commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Checked),
+            false => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Checked),
+        };
+
+        // The thumb slides once the switch changes after being spawned
+        if !switch.is_added() && !switch.animate_thumb {
+            commands.add(move |world: &mut World| {
+                if let Some(mut switch) = world.get_mut::<Switch>(entity) {
+                    switch.bypass_change_detection().animate_thumb = true;
+                }
+            });
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Switch {
+    pub checked: bool,
+    animate_thumb: bool,
+    track: Entity,
+    thumb: Entity,
+    label: Entity,
+}
+
+impl Default for Switch {
+    fn default() -> Self {
+        Self {
+            checked: false,
+            animate_thumb: false,
+            track: Entity::PLACEHOLDER,
+            thumb: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for Switch {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            Switch::TRACK => Ok(self.track),
+            Switch::THUMB => Ok(self.thumb),
+            Switch::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for Switch. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [Switch::TRACK, Switch::THUMB, Switch::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for Switch {
+    fn default_theme() -> Option<Theme<Switch>> {
+        Switch::theme().into()
+    }
+}
+
+impl Switch {
+    pub const TRACK: &'static str = "Track";
+    pub const THUMB: &'static str = "Thumb";
+    pub const LABEL: &'static str = "Label";
+
+    pub fn theme() -> Theme<Switch> {
+        let base_theme = PseudoTheme::deferred_context(None, Switch::primary_style);
+        let checked_theme =
+            PseudoTheme::deferred_context(vec![PseudoState::Checked], Switch::checked_style);
+        let error_theme = PseudoTheme::deferred(vec![PseudoState::Error], Switch::error_style);
+        Theme::new(vec![base_theme, checked_theme, error_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, switch: &Switch, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let sizes = theme_spacing.inputs.switch;
+        let colors = theme_data.colors();
+        let direction = theme_data.layout_direction;
+        // The thumb travels towards the end side when checked
+        let (off, on) = direction.pick(
+            (sizes.thumb_inset(), sizes.thumb_travel()),
+            (sizes.thumb_travel(), sizes.thumb_inset()),
+        );
+
+        style_builder
+            .flex_direction(direction.row())
+            .height(Val::Px(theme_spacing.inputs.checkbox.line_height))
+            .min_height(Val::Px(theme_data.min_hit_target()))
+            .justify_content(JustifyContent::Start)
+            .align_items(AlignItems::Center)
+            .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .background_color(Color::NONE);
+
+        style_builder
+            .switch_target(Switch::TRACK)
+            .width(Val::Px(sizes.track_width))
+            .height(Val::Px(sizes.track_height))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+            .border(UiRect::all(Val::Px(sizes.border_size)))
+            .border_radius(BorderRadius::all(Val::Px(sizes.track_height / 2.)))
+            .switch_transition(theme_data.style_transition)
+            .background_color(colors.surface(Surface::SurfaceVariant))
+            .reset_transition()
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::Outline),
+                hover: colors.on(OnColor::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
+
+        style_builder
+            .switch_target(Switch::THUMB)
+            .position_type(PositionType::Absolute)
+            .top(Val::Px(sizes.thumb_inset()))
+            .size(Val::Px(sizes.thumb_size))
+            .border_radius(BorderRadius::all(Val::Px(sizes.thumb_size / 2.)))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.accent(Accent::Outline),
+                hover: colors.on(OnColor::SurfaceVariant).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
+
+        style_builder
+            .switch_target(Switch::THUMB)
+            .animated()
+            .left(AnimatedVals {
+                idle: Val::Px(off),
+                enter_from: switch.animate_thumb.then_some(Val::Px(on)),
+                ..default()
+            })
+            .copy_from(theme_data.enter_animation);
+
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        style_builder
+            .switch_target(Switch::LABEL)
+            .margin(direction.rect(UiRect::px(
+                theme_spacing.gaps.small,
+                theme_spacing.gaps.medium,
+                0.,
+                0.,
+            )))
+            .sized_font(font)
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(OnColor::SurfaceVariant),
+                hover: colors.on(OnColor::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn checked_style(style_builder: &mut StyleBuilder, switch: &Switch, theme_data: &ThemeData) {
+        let sizes = theme_data.spacing().inputs.switch;
+        let colors = theme_data.colors();
+        let (off, on) = theme_data.layout_direction.pick(
+            (sizes.thumb_inset(), sizes.thumb_travel()),
+            (sizes.thumb_travel(), sizes.thumb_inset()),
+        );
+
+        style_builder
+            .switch_target(Switch::TRACK)
+            .switch_transition(theme_data.style_transition)
+            .background_color(colors.accent(Accent::Primary))
+            .reset_transition()
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::Primary),
+                hover: colors.container(Container::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
+
+        style_builder
+            .switch_target(Switch::THUMB)
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.on(OnColor::Primary),
+                hover: colors.container(Container::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
+
+        style_builder
+            .switch_target(Switch::THUMB)
+            .animated()
+            .left(AnimatedVals {
+                idle: Val::Px(on),
+                enter_from: switch.animate_thumb.then_some(Val::Px(off)),
+                ..default()
+            })
+            .copy_from(theme_data.enter_animation);
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(Switch::TRACK)
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::Error),
+                hover: colors.container(Container::Error).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(Switch::LABEL)
+            .font_color(colors.accent(Accent::Error));
+    }
+
+    fn container(name: String) -> impl Bundle {
+        (
+            Name::new(name),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn track() -> impl Bundle {
+        (
+            Name::new("Track"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn thumb() -> impl Bundle {
+        (
+            Name::new("Thumb"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
+
+pub trait UiSwitchExt {
+    fn switch(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity>;
+}
+
+impl UiSwitchExt for UiBuilder<'_, Entity> {
+    /// A toggle switch with an optional label. Fires [`CheckboxChanged`] when toggled.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked`, when the switch is on
+    /// - `PseudoState::Error`, when a `Validation` on it fails
+    fn switch(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity> {
+        let mut switch = Switch {
+            checked,
+            ..default()
+        };
+
+        let label = label.into().unwrap_or_default();
+        let has_label = !label.is_empty();
+        let name = match has_label {
+            true => format!("Switch [{}]", label),
+            false => "Switch".into(),
+        };
+
+        let mut input = self.container(Switch::container(name), |container| {
+            switch.track = container
+                .container(Switch::track(), |track| {
+                    switch.thumb = track.spawn(Switch::thumb()).id();
+                })
+                .id();
+
+            switch.label = container
+                .label(LabelConfig::from(label))
+                .style()
+                .render(has_label)
+                .id();
+        });
+
+        input.insert(switch);
+
+        input
+    }
+}
@@ -0,0 +1,347 @@
+use std::sync::Arc;
+
+use bevy::{prelude::*, reflect::ReflectRef};
+use regex::Regex;
+
+use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+
+use crate::widgets::{
+    inputs::{
+        binding::{Bind, Bindable},
+        numeric_field::read_number,
+    },
+    layout::label::{LabelConfig, UiLabelExt},
+};
+
+pub struct ValidationPlugin;
+
+impl Plugin for ValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ComponentThemePlugin::<ValidationText>::default())
+            .add_systems(
+                Update,
+                (
+                    spawn_validation_text,
+                    update_validation_state,
+                    despawn_orphaned_validation_text,
+                )
+                    .chain()
+                    .in_set(ValidationUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ValidationUpdate;
+
+pub(crate) fn validate_input<W: Bindable>(
+    mut q_inputs: Query<
+        (&W, &mut Validation, Option<&Bind>),
+        Or<(Added<Validation>, Changed<W>, Changed<Bind>)>,
+    >,
+) {
+    for (input, mut validation, bind) in &mut q_inputs {
+        let error = validation.check(&input.bound_value()).or_else(|| {
+            bind.is_some_and(|bind| !bind.is_valid())
+                .then(|| "Invalid value".into())
+        });
+
+        if validation.error != error {
+            validation.error = error;
+        }
+    }
+}
+
+fn spawn_validation_text(
+    mut q_validations: Query<(Entity, &mut Validation, &Parent), Added<Validation>>,
+    q_children: Query<&Children>,
+    mut commands: Commands,
+) {
+    for (entity, mut validation, parent) in &mut q_validations {
+        let index = q_children
+            .get(parent.get())
+            .ok()
+            .and_then(|children| children.iter().position(|child| *child == entity))
+            .unwrap_or_default();
+
+        let text = commands
+            .ui_builder(parent.get())
+            .label(LabelConfig {
+                label: String::new().into(),
+                ..default()
+            })
+            .insert(ValidationText { input: entity })
+            .id();
+
+        commands
+            .entity(parent.get())
+            .insert_children(index + 1, &[text]);
+        validation.text = text;
+    }
+}
+
+fn update_validation_state(
+    q_validations: Query<(Entity, &Validation), Changed<Validation>>,
+    mut commands: Commands,
+) {
+    for (entity, validation) in &q_validations {
+        let (text, error) = match &validation.error {
+            Some(error) => (Some(error), true),
+            None => (validation.helper_text.as_ref(), false),
+        };
+
+        for target in [entity, validation.text] {
+            if target == Entity::PLACEHOLDER {
+                continue;
+            }
+
+            match error {
+                true => commands.entity(target).add_pseudo_state(PseudoState::Error),
+                false => commands
+                    .entity(target)
+                    .remove_pseudo_state(PseudoState::Error),
+            };
+        }
+
+        if validation.text != Entity::PLACEHOLDER {
+            commands
+                .entity(validation.text)
+                .update_text(text.cloned().unwrap_or_default());
+            commands.style(validation.text).render(text.is_some());
+        }
+    }
+}
+
+fn despawn_orphaned_validation_text(
+    mut q_removed: RemovedComponents<Validation>,
+    q_texts: Query<(Entity, &ValidationText)>,
+    q_validations: Query<(), With<Validation>>,
+    mut commands: Commands,
+) {
+    if q_removed.read().count() == 0 {
+        return;
+    }
+
+    for (entity, text) in &q_texts {
+        if !q_validations.contains(text.input) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+type ValidatorCheck = Arc<dyn Fn(&dyn Reflect) -> bool + Send + Sync>;
+
+/// A rule checked against the value of a [`Bindable`] input
+#[derive(Clone)]
+pub struct Validator {
+    message: String,
+    check: ValidatorCheck,
+}
+
+impl Validator {
+    /// Fails on `None`, `false`, empty strings and empty lists
+    pub fn required() -> Self {
+        Self {
+            message: "Required".into(),
+            check: Arc::new(is_present),
+        }
+    }
+
+    /// Fails on numbers outside of `min..=max`
+    ///
+    /// Both ends of range slider values must be in range.
+    pub fn range(min: f32, max: f32) -> Self {
+        Self {
+            message: format!("Must be between {} and {}", min, max),
+            check: Arc::new(move |value| {
+                if let Some((number, _)) = read_number(value) {
+                    (min as f64..=max as f64).contains(&number)
+                } else if let Some(range) = value.downcast_ref::<Vec2>() {
+                    range.x >= min && range.y <= max
+                } else {
+                    true
+                }
+            }),
+        }
+    }
+
+    /// Fails if the text of the value doesn't match `pattern`
+    ///
+    /// Numbers are matched by their displayed text. An invalid `pattern` fails every value.
+    pub fn regex(pattern: &str) -> Self {
+        let regex = match Regex::new(pattern) {
+            Ok(regex) => Some(regex),
+            Err(err) => {
+                warn!("Invalid validation pattern {}: {}", pattern, err);
+                None
+            }
+        };
+
+        Self {
+            message: "Invalid format".into(),
+            check: Arc::new(move |value| {
+                let Some(regex) = &regex else {
+                    return false;
+                };
+
+                value_text(value).is_some_and(|text| regex.is_match(&text))
+            }),
+        }
+    }
+
+    /// Fails if `check` rejects the value, or if the value isn't a `V`
+    pub fn custom<V: FromReflect>(
+        message: impl Into<String>,
+        check: impl Fn(&V) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            check: Arc::new(move |value| V::from_reflect(value).is_some_and(|value| check(&value))),
+        }
+    }
+
+    /// Replaces the error text shown when the validator fails
+    pub fn with_message(self, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..self
+        }
+    }
+}
+
+fn is_present(value: &dyn Reflect) -> bool {
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) => value.variant_name() != "None",
+        ReflectRef::List(list) => !list.is_empty(),
+        _ => {
+            value.downcast_ref::<bool>() != Some(&false)
+                && value
+                    .downcast_ref::<String>()
+                    .iter()
+                    .all(|text| !text.is_empty())
+        }
+    }
+}
+
+fn value_text(value: &dyn Reflect) -> Option<String> {
+    if let Some(text) = value.downcast_ref::<String>() {
+        Some(text.clone())
+    } else if let Some(number) = value.downcast_ref::<f32>() {
+        Some(number.to_string())
+    } else {
+        read_number(value).map(|(number, _)| number.to_string())
+    }
+}
+
+/// Validates the value of a [`Bindable`] input
+///
+/// Failing inputs get `PseudoState::Error`, and the error of the first failing
+/// [`Validator`] is shown below the input in place of the helper text. A [`Bind`] on the
+/// same input only writes values that pass validation.
+///
+/// ```ignore
+/// column
+///     .numeric_field(NumericFieldConfig::new(8.))
+///     .insert(Validation::new(vec![Validator::range(1., 64.)]).with_helper_text("Grid cell size"));
+/// ```
+#[derive(Component)]
+pub struct Validation {
+    validators: Vec<Validator>,
+    helper_text: Option<String>,
+    error: Option<String>,
+    text: Entity,
+}
+
+impl Validation {
+    pub fn new(validators: Vec<Validator>) -> Self {
+        Self {
+            validators,
+            helper_text: None,
+            error: None,
+            text: Entity::PLACEHOLDER,
+        }
+    }
+
+    /// Text shown below the input while it is valid
+    pub fn with_helper_text(self, helper_text: impl Into<String>) -> Self {
+        Self {
+            helper_text: Some(helper_text.into()),
+            ..self
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the message of the first validator rejecting `value`
+    pub(crate) fn check(&self, value: &dyn Reflect) -> Option<String> {
+        self.validators
+            .iter()
+            .find(|validator| !(validator.check)(value))
+            .map(|validator| validator.message.clone())
+    }
+}
+
+/// The helper or error text of a [`Validation`], spawned after its input
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ValidationText {
+    input: Entity,
+}
+
+impl Default for ValidationText {
+    fn default() -> Self {
+        Self {
+            input: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for ValidationText {}
+
+impl DefaultTheme for ValidationText {
+    fn default_theme() -> Option<Theme<ValidationText>> {
+        ValidationText::theme().into()
+    }
+}
+
+impl ValidationText {
+    pub fn input(&self) -> Entity {
+        self.input
+    }
+
+    pub fn theme() -> Theme<ValidationText> {
+        let base_theme = PseudoTheme::deferred(None, ValidationText::primary_style);
+        let error_theme =
+            PseudoTheme::deferred(vec![PseudoState::Error], ValidationText::error_style);
+        Theme::new(vec![base_theme, error_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Small, FontType::Regular);
+
+        style_builder
+            .align_self(AlignSelf::Start)
+            .margin(UiRect::px(
+                theme_spacing.gaps.medium,
+                theme_spacing.gaps.medium,
+                0.,
+                theme_spacing.gaps.small,
+            ))
+            .sized_font(font)
+            .font_color(colors.on(OnColor::SurfaceVariant));
+    }
+
+    fn error_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.font_color(colors.accent(Accent::Error));
+    }
+}
@@ -0,0 +1,697 @@
+use bevy::prelude::*;
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    inputs::{
+        checkbox::{Checkbox, UiCheckboxExt},
+        numeric_field::{NumericField, NumericFieldConfig, NumericFieldUpdate, UiNumericFieldExt},
+    },
+    layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+        tooltip::Tooltip,
+    },
+};
+
+/// Label colors of the X / R, Y / G and Z / B axes
+const AXIS_COLORS: [Color; 3] = [
+    Color::srgb(0.89, 0.33, 0.33),
+    Color::srgb(0.47, 0.74, 0.25),
+    Color::srgb(0.33, 0.55, 0.95),
+];
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct Vec2FieldChanged {
+    pub value: Vec2,
+}
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct Vec3FieldChanged {
+    pub value: Vec3,
+}
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct QuatFieldChanged {
+    pub value: Quat,
+    /// The edited XYZ euler angles, in degrees
+    pub euler: Vec3,
+}
+
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct ColorFieldChanged {
+    pub value: Color,
+}
+
+pub struct VectorFieldPlugin;
+
+impl Plugin for VectorFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ComponentThemePlugin::<VectorField>::default(),
+            ComponentThemePlugin::<VectorFieldAxis>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                update_vector_field_link,
+                update_vector_field_from_axes,
+                update_vector_field_axes,
+            )
+                .chain()
+                .after(FluxInteractionUpdate)
+                .after(NumericFieldUpdate),
+        );
+
+        #[cfg(feature = "observable")]
+        app.add_event::<Vec2FieldChanged>()
+            .add_event::<Vec3FieldChanged>()
+            .add_event::<QuatFieldChanged>()
+            .add_event::<ColorFieldChanged>();
+    }
+}
+
+fn update_vector_field_link(
+    q_links: Query<(&Checkbox, &VectorFieldLink), Changed<Checkbox>>,
+    mut q_vector_fields: Query<&mut VectorField>,
+) {
+    for (checkbox, link) in &q_links {
+        let Ok(mut vector_field) = q_vector_fields.get_mut(link.field) else {
+            continue;
+        };
+
+        if vector_field.linked != checkbox.checked {
+            vector_field.linked = checkbox.checked;
+        }
+    }
+}
+
+fn update_vector_field_from_axes(
+    mut q_inputs: ParamSet<(
+        Query<(&NumericField, &VectorFieldInput), Changed<NumericField>>,
+        Query<&mut NumericField>,
+    )>,
+    mut q_vector_fields: Query<&mut VectorField>,
+    mut commands: Commands,
+) {
+    let changes: Vec<(Entity, usize, f32)> = q_inputs
+        .p0()
+        .iter()
+        .filter(|(input, _)| !input.is_editing())
+        .map(|(input, axis)| (axis.field, axis.axis, input.value()))
+        .collect();
+
+    for (entity, axis, value) in changes {
+        let Ok(mut vector_field) = q_vector_fields.get_mut(entity) else {
+            continue;
+        };
+        let previous = vector_field.values[axis];
+        if previous == value {
+            continue;
+        }
+
+        vector_field.values[axis] = value;
+
+        if vector_field.linked && previous != 0. {
+            let factor = value / previous;
+            let mut q_axis_inputs = q_inputs.p1();
+            for other in 0..vector_field.kind.axes() {
+                if other == axis {
+                    continue;
+                }
+
+                let Ok(mut input) = q_axis_inputs.get_mut(vector_field.inputs[other]) else {
+                    continue;
+                };
+                let scaled = input
+                    .config()
+                    .constrain(vector_field.values[other] * factor);
+                vector_field.values[other] = scaled;
+                if input.value() != scaled {
+                    input.set_value(scaled);
+                }
+            }
+        }
+
+        #[cfg(feature = "observable")]
+        vector_field.trigger_changed(entity, &mut commands);
+    }
+}
+
+fn update_vector_field_axes(
+    mut q_vector_fields: Query<&mut VectorField, Changed<VectorField>>,
+    mut q_inputs: Query<&mut NumericField>,
+    mut commands: Commands,
+) {
+    for mut vector_field in &mut q_vector_fields {
+        for axis in 0..vector_field.kind.axes() {
+            let Ok(mut input) = q_inputs.get_mut(vector_field.inputs[axis]) else {
+                continue;
+            };
+            if input.is_editing() || input.is_scrubbing() {
+                continue;
+            }
+
+            let value = input.config().constrain(vector_field.values[axis]);
+            if vector_field.values[axis] != value {
+                vector_field.values[axis] = value;
+            }
+            if input.value() != value {
+                input.set_value(value);
+            }
+        }
+
+        if vector_field.kind == VectorFieldKind::Color {
+            commands
+                .style_unchecked(vector_field.swatch)
+                .background_color(vector_field.color());
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum VectorFieldKind {
+    #[default]
+    Vec2,
+    Vec3,
+    /// A rotation edited as XYZ euler angles in degrees
+    QuatEuler,
+    /// An sRGBA color edited per channel
+    Color,
+}
+
+impl VectorFieldKind {
+    /// Number of edited axes
+    pub fn axes(&self) -> usize {
+        match self {
+            VectorFieldKind::Vec2 => 2,
+            VectorFieldKind::Vec3 | VectorFieldKind::QuatEuler => 3,
+            VectorFieldKind::Color => 4,
+        }
+    }
+
+    pub fn axis_name(&self, axis: usize) -> &'static str {
+        match self {
+            VectorFieldKind::Color => ["R", "G", "B", "A"][axis],
+            _ => ["X", "Y", "Z"][axis],
+        }
+    }
+
+    /// The numeric field config used for the axes, unless overridden in [`VectorFieldConfig`]
+    pub fn axis_config(&self) -> NumericFieldConfig {
+        match self {
+            VectorFieldKind::Vec2 | VectorFieldKind::Vec3 => NumericFieldConfig::default(),
+            VectorFieldKind::QuatEuler => NumericFieldConfig::default()
+                .unclamped()
+                .with_precision(1)
+                .with_drag_speed(1.)
+                .with_suffix("°"),
+            VectorFieldKind::Color => NumericFieldConfig::default()
+                .with_range(0., 1.)
+                .with_drag_speed(0.005),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            VectorFieldKind::Vec2 => "Vec2 Field",
+            VectorFieldKind::Vec3 => "Vec3 Field",
+            VectorFieldKind::QuatEuler => "Rotation Field",
+            VectorFieldKind::Color => "Color Field",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct VectorFieldConfig {
+    pub label: Option<String>,
+    /// Config of every axis, the value is ignored. `None` uses the defaults of the field kind.
+    pub axis: Option<NumericFieldConfig>,
+    /// Shows the linked proportions toggle in the given state.
+    /// While linked, changing one axis scales the others by the same factor.
+    pub linked: Option<bool>,
+}
+
+impl VectorFieldConfig {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into().into(),
+            ..default()
+        }
+    }
+
+    pub fn with_axis(self, axis: NumericFieldConfig) -> Self {
+        Self {
+            axis: axis.into(),
+            ..self
+        }
+    }
+
+    pub fn with_link(self, linked: bool) -> Self {
+        Self {
+            linked: linked.into(),
+            ..self
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct VectorFieldInput {
+    field: Entity,
+    axis: usize,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct VectorFieldLink {
+    field: Entity,
+}
+
+/// A single axis of a vector field: the colored axis label and its numeric field
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct VectorFieldAxis {
+    kind: VectorFieldKind,
+    axis: usize,
+    label: Entity,
+}
+
+impl Default for VectorFieldAxis {
+    fn default() -> Self {
+        Self {
+            kind: default(),
+            axis: 0,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for VectorFieldAxis {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            VectorFieldAxis::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for VectorFieldAxis. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [VectorFieldAxis::LABEL].into_iter()
+    }
+}
+
+impl DefaultTheme for VectorFieldAxis {
+    fn default_theme() -> Option<Theme<VectorFieldAxis>> {
+        VectorFieldAxis::theme().into()
+    }
+}
+
+impl VectorFieldAxis {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    pub fn theme() -> Theme<VectorFieldAxis> {
+        let base_theme = PseudoTheme::deferred_context(None, VectorFieldAxis::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        axis: &VectorFieldAxis,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Label, FontScale::Medium, FontType::Bold);
+        let axis_color = match AXIS_COLORS.get(axis.axis) {
+            Some(color) => *color,
+            None => colors.on(OnColor::SurfaceVariant),
+        };
+
+        style_builder
+            .flex_grow(1.)
+            .flex_basis(Val::Px(0.))
+            .align_items(AlignItems::Center)
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.small)));
+
+        style_builder
+            .switch_target(VectorFieldAxis::LABEL)
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.small)))
+            .sized_font(font)
+            .font_color(axis_color);
+    }
+
+    fn frame(kind: VectorFieldKind, axis: usize) -> impl Bundle {
+        (
+            Name::new(format!("Axis [{}]", kind.axis_name(axis))),
+            NodeBundle::default(),
+        )
+    }
+}
+
+/// A compound editor of a typed value, with a numeric field per axis
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct VectorField {
+    kind: VectorFieldKind,
+    values: Vec4,
+    linked: bool,
+    label: Entity,
+    link: Entity,
+    swatch: Entity,
+    inputs: Vec<Entity>,
+}
+
+impl Default for VectorField {
+    fn default() -> Self {
+        Self {
+            kind: default(),
+            values: Vec4::ZERO,
+            linked: false,
+            label: Entity::PLACEHOLDER,
+            link: Entity::PLACEHOLDER,
+            swatch: Entity::PLACEHOLDER,
+            inputs: Vec::new(),
+        }
+    }
+}
+
+impl UiContext for VectorField {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            VectorField::LABEL => Ok(self.label),
+            VectorField::LINK => Ok(self.link),
+            VectorField::SWATCH => Ok(self.swatch),
+            _ => Err(format!(
+                "{} doesn't exist for VectorField. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn cleared_contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        // The link toggle is a checkbox with its own theme
+        [VectorField::LABEL, VectorField::SWATCH].into_iter()
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [VectorField::LABEL, VectorField::LINK, VectorField::SWATCH].into_iter()
+    }
+}
+
+impl DefaultTheme for VectorField {
+    fn default_theme() -> Option<Theme<VectorField>> {
+        VectorField::theme().into()
+    }
+}
+
+impl VectorField {
+    pub const LABEL: &'static str = "Label";
+    pub const LINK: &'static str = "Link";
+    pub const SWATCH: &'static str = "Swatch";
+
+    pub fn kind(&self) -> VectorFieldKind {
+        self.kind
+    }
+
+    /// Checks if the axes are scaled together
+    pub fn is_linked(&self) -> bool {
+        self.linked
+    }
+
+    pub fn vec2(&self) -> Vec2 {
+        self.values.truncate().truncate()
+    }
+
+    pub fn set_vec2(&mut self, value: Vec2) {
+        self.set_values(value.extend(0.).extend(0.));
+    }
+
+    pub fn vec3(&self) -> Vec3 {
+        self.values.truncate()
+    }
+
+    pub fn set_vec3(&mut self, value: Vec3) {
+        self.set_values(value.extend(0.));
+    }
+
+    /// The edited XYZ euler angles, in degrees
+    pub fn euler(&self) -> Vec3 {
+        self.values.truncate()
+    }
+
+    pub fn quat(&self) -> Quat {
+        let euler = self.euler();
+        Quat::from_euler(
+            EulerRot::XYZ,
+            euler.x.to_radians(),
+            euler.y.to_radians(),
+            euler.z.to_radians(),
+        )
+    }
+
+    pub fn set_quat(&mut self, value: Quat) {
+        self.set_values(VectorField::euler_degrees(value).extend(0.));
+    }
+
+    /// The XYZ euler angles of a rotation, in degrees, as edited by `QuatEuler` fields
+    pub fn euler_degrees(value: Quat) -> Vec3 {
+        let (x, y, z) = value.to_euler(EulerRot::XYZ);
+        Vec3::new(x.to_degrees(), y.to_degrees(), z.to_degrees())
+    }
+
+    pub fn color(&self) -> Color {
+        Color::srgba(self.values.x, self.values.y, self.values.z, self.values.w)
+    }
+
+    pub fn set_color(&mut self, value: Color) {
+        let value = value.to_srgba();
+        self.set_values(Vec4::new(value.red, value.green, value.blue, value.alpha));
+    }
+
+    fn set_values(&mut self, values: Vec4) {
+        if self.values != values {
+            self.values = values;
+        }
+    }
+
+    #[cfg(feature = "observable")]
+    fn trigger_changed(&self, entity: Entity, commands: &mut Commands) {
+        match self.kind {
+            VectorFieldKind::Vec2 => {
+                commands.trigger_targets(Vec2FieldChanged { value: self.vec2() }, entity)
+            }
+            VectorFieldKind::Vec3 => {
+                commands.trigger_targets(Vec3FieldChanged { value: self.vec3() }, entity)
+            }
+            VectorFieldKind::QuatEuler => commands.trigger_targets(
+                QuatFieldChanged {
+                    value: self.quat(),
+                    euler: self.euler(),
+                },
+                entity,
+            ),
+            VectorFieldKind::Color => commands.trigger_targets(
+                ColorFieldChanged {
+                    value: self.color(),
+                },
+                entity,
+            ),
+        }
+    }
+
+    pub fn theme() -> Theme<VectorField> {
+        let base_theme = PseudoTheme::deferred_context(None, VectorField::primary_style);
+
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        vector_field: &VectorField,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+
+        style_builder.padding(UiRect::axes(
+            Val::Px(theme_spacing.gaps.small),
+            Val::Px(theme_spacing.gaps.extra_small),
+        ));
+
+        if vector_field.label != Entity::PLACEHOLDER {
+            style_builder
+                .switch_target(VectorField::LABEL)
+                .min_width(Val::Px(theme_spacing.areas.extra_large))
+                .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)))
+                .sized_font(font)
+                .font_color(colors.on(OnColor::Surface));
+        }
+
+        if vector_field.swatch != Entity::PLACEHOLDER {
+            style_builder
+                .switch_target(VectorField::SWATCH)
+                .width(Val::Px(theme_spacing.areas.small))
+                .height(Val::Px(theme_spacing.areas.small))
+                .flex_shrink(0.)
+                .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+                .border_radius(BorderRadius::all(Val::Px(
+                    theme_spacing.corners.extra_small,
+                )))
+                .border_color(colors.accent(Accent::OutlineVariant));
+        }
+    }
+
+    fn swatch(color: Color) -> impl Bundle {
+        (
+            Name::new("Swatch"),
+            NodeBundle {
+                background_color: color.into(),
+                ..default()
+            },
+            BorderColor::default(),
+        )
+    }
+
+    fn scaffold<'a>(
+        builder: &'a mut UiBuilder<'_, Entity>,
+        kind: VectorFieldKind,
+        values: Vec4,
+        config: VectorFieldConfig,
+    ) -> UiBuilder<'a, Entity> {
+        let axis_config = config.axis.unwrap_or_else(|| kind.axis_config());
+        let values = Vec4::from_array(values.to_array().map(|value| axis_config.constrain(value)));
+        let mut vector_field = VectorField {
+            kind,
+            values,
+            linked: config.linked.unwrap_or(false),
+            ..default()
+        };
+
+        let mut field = builder.row(|row| {
+            let field_id = row.id();
+
+            if let Some(label) = config.label.clone() {
+                vector_field.label = row.label(LabelConfig::from(label)).id();
+            }
+
+            for axis in 0..kind.axes() {
+                let mut label_id = Entity::PLACEHOLDER;
+                row.container(VectorFieldAxis::frame(kind, axis), |container| {
+                    label_id = container
+                        .label(LabelConfig {
+                            label: kind.axis_name(axis).into(),
+                            ..default()
+                        })
+                        .id();
+
+                    let input = container
+                        .numeric_field(NumericFieldConfig {
+                            value: values[axis],
+                            ..axis_config.clone()
+                        })
+                        .insert(VectorFieldInput {
+                            field: field_id,
+                            axis,
+                        })
+                        .id();
+                    vector_field.inputs.push(input);
+
+                    container.insert(VectorFieldAxis {
+                        kind,
+                        axis,
+                        label: label_id,
+                    });
+                });
+            }
+
+            if let Some(linked) = config.linked {
+                vector_field.link = row
+                    .checkbox(None, linked)
+                    .insert((
+                        VectorFieldLink { field: field_id },
+                        Tooltip::text("Link proportions"),
+                    ))
+                    .id();
+            }
+
+            if kind == VectorFieldKind::Color {
+                vector_field.swatch = row.spawn(VectorField::swatch(vector_field.color())).id();
+            }
+        });
+
+        field.insert((Name::new(kind.name()), vector_field));
+
+        field
+    }
+}
+
+pub trait UiVectorFieldExt {
+    fn vec2_field(&mut self, value: Vec2, config: VectorFieldConfig) -> UiBuilder<Entity>;
+
+    fn vec3_field(&mut self, value: Vec3, config: VectorFieldConfig) -> UiBuilder<Entity>;
+
+    fn quat_euler_field(&mut self, value: Quat, config: VectorFieldConfig) -> UiBuilder<Entity>;
+
+    fn color_field(&mut self, value: Color, config: VectorFieldConfig) -> UiBuilder<Entity>;
+}
+
+impl UiVectorFieldExt for UiBuilder<'_, Entity> {
+    /// Edits a `Vec2` with a numeric field per axis.
+    ///
+    /// Triggers `Vec2FieldChanged` when an axis is changed.
+    fn vec2_field(&mut self, value: Vec2, config: VectorFieldConfig) -> UiBuilder<Entity> {
+        VectorField::scaffold(
+            self,
+            VectorFieldKind::Vec2,
+            value.extend(0.).extend(0.),
+            config,
+        )
+    }
+
+    /// Edits a `Vec3` with a numeric field per axis.
+    ///
+    /// Use [`VectorFieldConfig::with_link`] for scales, to edit the axes proportionally.
+    /// Triggers `Vec3FieldChanged` when an axis is changed.
+    fn vec3_field(&mut self, value: Vec3, config: VectorFieldConfig) -> UiBuilder<Entity> {
+        VectorField::scaffold(self, VectorFieldKind::Vec3, value.extend(0.), config)
+    }
+
+    /// Edits a rotation as XYZ euler angles, in degrees.
+    ///
+    /// Triggers `QuatFieldChanged` with both the rotation and the edited angles.
+    fn quat_euler_field(&mut self, value: Quat, config: VectorFieldConfig) -> UiBuilder<Entity> {
+        let euler = VectorField::euler_degrees(value);
+
+        VectorField::scaffold(self, VectorFieldKind::QuatEuler, euler.extend(0.), config)
+    }
+
+    /// Edits the sRGBA channels of a color, with a swatch of the current color.
+    ///
+    /// Triggers `ColorFieldChanged` when a channel is changed.
+    fn color_field(&mut self, value: Color, config: VectorFieldConfig) -> UiBuilder<Entity> {
+        let value = value.to_srgba();
+
+        VectorField::scaffold(
+            self,
+            VectorFieldKind::Color,
+            Vec4::new(value.red, value.green, value.blue, value.alpha),
+            config,
+        )
+    }
+}
@@ -7,7 +7,9 @@ pub mod icon;
 pub mod label;
 pub mod panel;
 pub mod resize_handles;
+pub mod rich_text;
 pub mod row;
 pub mod scroll_view;
 pub mod sized_zone;
 pub mod tab_container;
+pub mod tooltip;
@@ -510,7 +510,7 @@ impl Default for FloatingPanelCloseButton {
 
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct FloatingPanelConfig {
-    pub title: Option<String>,
+    pub title: Option<LocalizedText>,
     pub draggable: bool,
     pub resizable: bool,
     pub foldable: bool,
@@ -534,7 +534,7 @@ impl Default for FloatingPanelConfig {
 }
 
 impl FloatingPanelConfig {
-    pub fn title(&self) -> Option<String> {
+    pub fn title(&self) -> Option<LocalizedText> {
         self.title.clone()
     }
 }
@@ -643,8 +643,9 @@ impl FloatingPanel {
         panel: &FloatingPanel,
         theme_data: &ThemeData,
     ) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
+        let direction = theme_data.layout_direction;
 
         style_builder
             .absolute_position(panel.position)
@@ -675,6 +676,7 @@ impl FloatingPanel {
 
         style_builder
             .switch_target(FloatingPanel::TITLE_CONTAINER)
+            .flex_direction(direction.row())
             .width(Val::Percent(100.))
             .align_items(AlignItems::Center)
             .justify_content(JustifyContent::Start)
@@ -686,26 +688,24 @@ impl FloatingPanel {
         style_builder
             .switch_target(FloatingPanel::TITLE)
             .flex_grow(1.)
-            .margin(UiRect::px(
+            .margin(direction.rect(UiRect::px(
                 theme_spacing.gaps.small,
                 theme_spacing.gaps.extra_large,
                 theme_spacing.gaps.small,
                 theme_spacing.gaps.extra_small,
-            ))
-            .sized_font(
-                theme_data
-                    .text
-                    .get(FontStyle::Body, FontScale::Large, FontType::Regular),
-            )
+            )))
+            .sized_font(theme_data.font(FontStyle::Body, FontScale::Large, FontType::Regular))
             .font_color(colors.on(OnColor::Surface));
 
         style_builder
             .switch_target(FloatingPanel::CLOSE_BUTTON_CONTAINER)
-            .right(Val::Px(0.))
+            .left(direction.pick(Val::Auto, Val::Px(0.)))
+            .right(direction.pick(Val::Px(0.), Val::Auto))
             .background_color(colors.container(Container::SurfaceMid))
-            .border_radius(BorderRadius::top_right(Val::Px(
-                theme_spacing.corners.extra_small,
-            )));
+            .border_radius(direction.pick(
+                BorderRadius::top_right(Val::Px(theme_spacing.corners.extra_small)),
+                BorderRadius::top_left(Val::Px(theme_spacing.corners.extra_small)),
+            ));
 
         style_builder
             .switch_target(FloatingPanel::CONTENT_VIEW)
@@ -771,7 +771,7 @@ impl FloatingPanel {
         panel: &FloatingPanel,
         theme_data: &ThemeData,
     ) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -798,8 +798,7 @@ impl FloatingPanel {
             .switch_target(FloatingPanel::FOLD_BUTTON)
             .icon(
                 theme_data
-                    .icons
-                    .chevron_right
+                    .directional_icon(&theme_data.icons.chevron_right)
                     .with(colors.on(OnColor::Surface), theme_spacing.icons.small),
             );
     }
@@ -816,7 +815,7 @@ impl FloatingPanel {
         self.title_container
     }
 
-    fn frame(title: String) -> impl Bundle {
+    fn frame(title: &LocalizedText) -> impl Bundle {
         (
             Name::new(format!("Floating Panel [{}]", title)),
             NodeBundle {
@@ -953,7 +952,7 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
         let restrict_to = config.restrict_scroll;
-        let title_text = if let Some(text) = config.title.clone() {
+        let title_text: LocalizedText = if let Some(text) = config.title.clone() {
             text
         } else {
             "Untitled".into()
@@ -966,7 +965,7 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
             ..default()
         };
 
-        let mut frame = self.container(FloatingPanel::frame(title_text.clone()), |container| {
+        let mut frame = self.container(FloatingPanel::frame(&title_text), |container| {
             let panel = container.id();
             floating_panel.resize_handles = container
                 .resize_handles(FloatingPanelResizeHandle { panel }, |_| {})
@@ -981,10 +980,7 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
                         .id();
 
                     floating_panel.title = container
-                        .label(LabelConfig {
-                            label: title_text.clone(),
-                            ..default()
-                        })
+                        .label(LabelConfig::truncated(title_text.clone()))
                         .id();
 
                     floating_panel.close_button_container = container
@@ -143,11 +143,12 @@ impl Foldable {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let direction = theme_data.layout_direction;
+
+        style_builder.flex_direction(direction.row());
 
         style_builder
             .switch_target(Foldable::BUTTON_ICON)
@@ -169,7 +170,7 @@ impl Foldable {
 
         style_builder
             .switch_target(Foldable::BUTTON_LABEL)
-            .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)))
+            .margin(direction.rect(UiRect::right(Val::Px(theme_spacing.gaps.medium))))
             .sized_font(font)
             .animated()
             .font_color(AnimatedVals {
@@ -188,13 +189,12 @@ impl Foldable {
     }
 
     fn folded_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder.switch_target(Foldable::BUTTON_ICON).icon(
             theme_data
-                .icons
-                .chevron_right
+                .directional_icon(&theme_data.icons.chevron_right)
                 .with(colors.on(OnColor::Surface), theme_spacing.icons.small),
         );
 
@@ -205,13 +205,12 @@ impl Foldable {
     }
 
     fn empty_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder.switch_target(Foldable::BUTTON_ICON).icon(
             theme_data
-                .icons
-                .arrow_right
+                .directional_icon(&theme_data.icons.arrow_right)
                 .with(colors.on(OnColor::Surface), theme_spacing.icons.small),
         );
 
@@ -244,7 +243,7 @@ impl Foldable {
 pub trait UiFoldableExt {
     fn foldable(
         &mut self,
-        name: impl Into<String>,
+        name: impl Into<LocalizedText>,
         open: bool,
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
@@ -259,7 +258,7 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
     /// - `PseudoState::Empty` is used when the folded panel is set to be empty. This is used for styling its icon
     fn foldable(
         &mut self,
-        name: impl Into<String>,
+        name: impl Into<LocalizedText>,
         open: bool,
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
@@ -273,7 +272,7 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
         };
 
         let button = self
-            .container(Foldable::button(name.clone()), |button| {
+            .container(Foldable::button(name.to_string()), |button| {
                 foldable.icon = button.spawn(Foldable::button_icon()).id();
                 foldable.label = button
                     .label(LabelConfig {
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use sickle_ui_scaffold::{ui_builder::*, ui_style::prelude::*};
+use sickle_ui_scaffold::{theme::icons::IconData, ui_builder::*, ui_style::prelude::*};
 
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -21,6 +21,9 @@ impl Icon {
 
 pub trait UiIconExt {
     fn icon(&mut self, path: impl Into<String>) -> UiBuilder<Entity>;
+
+    /// An icon looked up by name in the theme's `Icons`, i.e. `"save"` from a loaded icon set
+    fn named_icon(&mut self, name: impl Into<String>) -> UiBuilder<Entity>;
 }
 
 impl UiIconExt for UiBuilder<'_, Entity> {
@@ -31,4 +34,12 @@ impl UiIconExt for UiBuilder<'_, Entity> {
 
         icon
     }
+
+    fn named_icon(&mut self, name: impl Into<String>) -> UiBuilder<Entity> {
+        let mut icon = self.spawn((Name::new("Icon"), Icon::bundle(), Icon));
+
+        icon.style().icon(IconData::named(name));
+
+        icon
+    }
 }
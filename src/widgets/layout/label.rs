@@ -4,16 +4,114 @@ use bevy::{
     ui::FocusPolicy,
 };
 
-use sickle_ui_scaffold::ui_builder::UiBuilder;
+use sickle_ui_scaffold::{prelude::*, ui_builder::UiBuilder};
+
+use super::tooltip::{Tooltip, TooltipContent};
+
+pub struct LabelPlugin;
+
+impl Plugin for LabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_label_font_size.before(ThemeUpdate))
+            .add_systems(
+                PostUpdate,
+                update_label_truncation_tooltip.after(TextOverflowUpdate),
+            );
+    }
+}
+
+/// Sizes labels without a fixed `font_size` to the body text of the theme.
+/// Only sections still at the size they were spawned with, or the previous theme size, are
+/// resized. Sizes set with `.style().font_size(..)` or by widget themes are kept.
+fn update_label_font_size(
+    mut q_labels: Query<(Ref<LabelConfig>, &mut Text)>,
+    theme_data: Res<ThemeData>,
+    mut applied_font_size: Local<Option<f32>>,
+) {
+    let font_size = LabelConfig::theme_font_size(&theme_data);
+    let previous_font_size = applied_font_size.replace(font_size);
+    let theme_resized = previous_font_size.is_some_and(|size| size != font_size);
+
+    for (config, mut text) in &mut q_labels {
+        if config.font_size.is_some() {
+            continue;
+        }
+
+        let unsized_font_size = match config.is_added() {
+            true => TextStyle::default().font_size,
+            false if theme_resized => previous_font_size.unwrap(),
+            false => continue,
+        };
+        if text
+            .sections
+            .iter()
+            .all(|section| section.style.font_size != unsized_font_size)
+        {
+            continue;
+        }
+
+        for section in text.sections.iter_mut() {
+            if section.style.font_size == unsized_font_size {
+                section.style.font_size = font_size;
+            }
+        }
+    }
+}
+
+/// Shows the full text of truncated labels with `tooltip_on_truncate` in a tooltip
+fn update_label_truncation_tooltip(
+    q_labels: Query<
+        (
+            Entity,
+            &LabelConfig,
+            &TruncatedText,
+            Option<&BidiText>,
+            Option<&Tooltip>,
+        ),
+        Changed<TruncatedText>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, config, truncated_text, bidi_text, tooltip) in &q_labels {
+        if !config.tooltip_on_truncate {
+            continue;
+        }
+
+        if truncated_text.is_truncated() {
+            // The tooltip reorders the text itself, it needs the logical order
+            let full_text = match bidi_text {
+                Some(bidi_text) => bidi_text.logical_text(),
+                None => truncated_text.full_text(),
+            };
+            let up_to_date = tooltip.is_some_and(|tooltip| {
+                matches!(
+                    &tooltip.content,
+                    TooltipContent::Text(LocalizedText::Literal(text)) if *text == full_text
+                )
+            });
+            if !up_to_date {
+                commands.entity(entity).insert(Tooltip::text(full_text));
+            }
+        } else if tooltip.is_some() {
+            commands.entity(entity).remove::<Tooltip>();
+        }
+    }
+}
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct LabelConfig {
-    pub label: String,
+    pub label: LocalizedText,
     pub color: Color,
     pub margin: UiRect,
     pub wrap: FlexWrap,
     pub flex_grow: f32,
+    /// Follows the body text size of the theme if `None`
+    pub font_size: Option<f32>,
+    /// Truncates the label to its node, only applies to labels with `FlexWrap::NoWrap`
+    pub overflow: TextOverflow,
+    /// Shows the full text in a tooltip while the label is truncated
+    pub tooltip_on_truncate: bool,
 }
 
 impl Default for LabelConfig {
@@ -24,6 +122,9 @@ impl Default for LabelConfig {
             margin: Default::default(),
             wrap: FlexWrap::NoWrap,
             flex_grow: 0.,
+            font_size: None,
+            overflow: TextOverflow::Visible,
+            tooltip_on_truncate: false,
         }
     }
 }
@@ -38,7 +139,7 @@ impl From<&str> for LabelConfig {
 }
 
 impl LabelConfig {
-    pub fn from(label: impl Into<String>) -> LabelConfig {
+    pub fn from(label: impl Into<LocalizedText>) -> LabelConfig {
         LabelConfig {
             label: label.into(),
             ..default()
@@ -48,13 +149,30 @@ impl LabelConfig {
     fn text_style(&self) -> TextStyle {
         TextStyle {
             color: self.color,
-            font_size: 14.,
+            font_size: self.font_size.unwrap_or(TextStyle::default().font_size),
+            ..default()
+        }
+    }
+
+    fn theme_font_size(theme_data: &ThemeData) -> f32 {
+        theme_data
+            .font(FontStyle::Body, FontScale::Medium, FontType::Regular)
+            .size
+    }
+
+    /// Ellipsis truncation with the full text in a tooltip, for titles in constrained space
+    pub fn truncated(label: impl Into<LocalizedText>) -> LabelConfig {
+        LabelConfig {
+            label: label.into(),
+            overflow: TextOverflow::Ellipsis,
+            tooltip_on_truncate: true,
             ..default()
         }
     }
 
     fn frame(self) -> impl Bundle {
-        let mut section = Text::from_section(self.label.clone(), self.text_style());
+        // Localized labels are resolved before layout, in `LocalizationUpdate`
+        let mut section = Text::from_section(self.label.to_string(), self.text_style());
 
         if self.wrap == FlexWrap::NoWrap {
             section = section.with_no_wrap();
@@ -85,12 +203,31 @@ pub trait UiLabelExt {
 
 impl UiLabelExt for UiBuilder<'_, Entity> {
     fn label(&mut self, config: impl Into<LabelConfig>) -> UiBuilder<Entity> {
-        self.spawn((config.into().frame(), Label))
+        let config = config.into();
+        let overflow = match config.wrap {
+            FlexWrap::NoWrap => config.overflow,
+            _ => TextOverflow::Visible,
+        };
+        let tooltip_on_truncate = config.tooltip_on_truncate;
+        let localized_text = config.label.clone();
+
+        let mut label = self.spawn((config.frame(), Label));
+        if !localized_text.is_literal() {
+            label.insert(localized_text);
+        }
+        if overflow != TextOverflow::Visible {
+            label.insert(TruncatedText::new(overflow));
+        }
+        if tooltip_on_truncate {
+            label.insert((Interaction::default(), TrackedInteraction::default()));
+        }
+
+        label
     }
 }
 
 struct UpdateLabelText {
-    text: String,
+    text: LocalizedText,
 }
 
 impl EntityCommand for UpdateLabelText {
@@ -103,7 +240,10 @@ impl EntityCommand for UpdateLabelText {
 
             return;
         };
-        let style = config.text_style();
+        let mut style = config.text_style();
+        let themed_size = config.font_size.is_none();
+        let value = world.resource::<Locale>().text(&self.text);
+
         let Some(mut text) = world.get_mut::<Text>(entity) else {
             warn!(
                 "Failed to set label text on entity {}: No Text component found!",
@@ -113,16 +253,29 @@ impl EntityCommand for UpdateLabelText {
             return;
         };
 
-        text.sections = vec![TextSection::new(self.text, style)];
+        // Keep the size the label was given by the theme or its style
+        if let Some(section) = text.sections.first().filter(|_| themed_size) {
+            style.font_size = section.style.font_size;
+        }
+
+        text.sections = vec![TextSection::new(value, style)];
+
+        match self.text.is_literal() {
+            true => world.entity_mut(entity).remove::<LocalizedText>(),
+            false => world.entity_mut(entity).insert(self.text.clone()),
+        };
+        if let Some(mut config) = world.get_mut::<LabelConfig>(entity) {
+            config.label = self.text;
+        }
     }
 }
 
 pub trait SetLabelTextExt {
-    fn set_label_text(&mut self, text: impl Into<String>) -> &mut Self;
+    fn set_label_text(&mut self, text: impl Into<LocalizedText>) -> &mut Self;
 }
 
 impl SetLabelTextExt for EntityCommands<'_> {
-    fn set_label_text(&mut self, text: impl Into<String>) -> &mut Self {
+    fn set_label_text(&mut self, text: impl Into<LocalizedText>) -> &mut Self {
         self.add(UpdateLabelText { text: text.into() });
 
         self
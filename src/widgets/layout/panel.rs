@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use sickle_ui_scaffold::ui_builder::UiBuilder;
+use sickle_ui_scaffold::{localization::LocalizedText, ui_builder::UiBuilder};
 
 use super::container::UiContainerExt;
 
@@ -8,14 +8,14 @@ use super::container::UiContainerExt;
 #[reflect(Component)]
 pub struct Panel {
     own_id: Entity,
-    pub title: String,
+    pub title: LocalizedText,
 }
 
 impl Default for Panel {
     fn default() -> Self {
         Self {
             own_id: Entity::PLACEHOLDER,
-            title: "".into(),
+            title: default(),
         }
     }
 }
@@ -25,7 +25,7 @@ impl Panel {
         self.own_id
     }
 
-    pub fn title(&self) -> String {
+    pub fn title(&self) -> LocalizedText {
         self.title.clone()
     }
 
@@ -45,7 +45,7 @@ impl Panel {
 pub trait UiPanelExt {
     fn panel(
         &mut self,
-        title: String,
+        title: LocalizedText,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity>;
 }
@@ -53,10 +53,10 @@ pub trait UiPanelExt {
 impl UiPanelExt for UiBuilder<'_, Entity> {
     fn panel(
         &mut self,
-        title: String,
+        title: LocalizedText,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
-        let name = format!("Panel [{}]", title.clone());
+        let name = format!("Panel [{}]", title);
         let mut container = self.container((Name::new(name), Panel::frame()), spawn_children);
         let own_id = container.id();
 
@@ -366,7 +366,7 @@ impl ResizeHandles {
         world: &World,
     ) {
         let theme_data = world.resource::<ThemeData>();
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
         let interaction_animation = theme_data.delayed_interaction_animation;
         let colors = theme_data.colors();
 
@@ -504,14 +504,14 @@ impl ResizeHandles {
             .visibility(Visibility::Inherited);
     }
     fn resizable_north_north_west(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_NORTH)
             .left(Val::Px(resize_spacing.width + resize_spacing.handle_gap));
     }
     fn resizable_north_north_east(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_NORTH)
@@ -534,14 +534,14 @@ impl ResizeHandles {
             .visibility(Visibility::Inherited);
     }
     fn resizable_east_north_east(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_EAST)
             .top(Val::Px(resize_spacing.width + resize_spacing.handle_gap));
     }
     fn resizable_east_south_east(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_EAST)
@@ -564,14 +564,14 @@ impl ResizeHandles {
             .visibility(Visibility::Inherited);
     }
     fn resizable_south_south_east(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_SOUTH)
             .right(Val::Px(resize_spacing.width + resize_spacing.handle_gap));
     }
     fn resizable_south_south_west(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_SOUTH)
@@ -594,14 +594,14 @@ impl ResizeHandles {
             .visibility(Visibility::Inherited);
     }
     fn resizable_west_south_west(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_WEST)
             .bottom(Val::Px(resize_spacing.width + resize_spacing.handle_gap));
     }
     fn resizable_west_north_west(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let resize_spacing = theme_data.spacing.resize_zone;
+        let resize_spacing = theme_data.spacing().resize_zone;
 
         style_builder
             .switch_target(ResizeHandles::HANDLE_WEST)
@@ -0,0 +1,422 @@
+use bevy::{
+    prelude::*,
+    text::TextLayoutInfo,
+    ui::{FocusPolicy, RelativeCursorPosition},
+    window::PrimaryWindow,
+};
+
+use sickle_ui_scaffold::{prelude::*, theme::theme_colors::Role, ui_builder::UiBuilder};
+
+/// Horizontal reach of link glyphs, relative to the font size, so gaps between words still hit
+const LINK_HIT_PADDING: f32 = 0.25;
+
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, RichTextUpdate.after(FluxInteractionUpdate))
+            .add_systems(Update, handle_rich_text_link_click.in_set(RichTextUpdate))
+            .add_systems(PostUpdate, update_rich_text_sections.before(ThemeUpdate));
+
+        #[cfg(feature = "observable")]
+        app.add_event::<RichTextLinkClicked>();
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct RichTextUpdate;
+
+/// Triggered on the rich text entity when one of its link spans is clicked
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct RichTextLinkClicked {
+    pub link: String,
+}
+
+fn handle_rich_text_link_click(
+    q_texts: Query<
+        (
+            Entity,
+            &RichText,
+            &FluxInteraction,
+            &RelativeCursorPosition,
+            &Node,
+            &Text,
+            &TextLayoutInfo,
+        ),
+        Changed<FluxInteraction>,
+    >,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut commands: Commands,
+) {
+    let Ok(window) = q_window.get_single() else {
+        return;
+    };
+
+    for (entity, rich_text, interaction, cursor, node, text, text_layout) in &q_texts {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+
+        let scale_factor = window.scale_factor() * ui_scale.0;
+        let position = normalized * node.unrounded_size();
+        let Some(link) = rich_text.link_at(text, text_layout, position, scale_factor) else {
+            continue;
+        };
+
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(
+            RichTextLinkClicked {
+                link: link.to_string(),
+            },
+            entity,
+        );
+    }
+}
+
+fn update_rich_text_sections(
+    mut q_texts: Query<(Ref<RichText>, &mut Text)>,
+    theme_data: Res<ThemeData>,
+    asset_server: Res<AssetServer>,
+) {
+    for (rich_text, mut text) in &mut q_texts {
+        if !(rich_text.is_changed() || theme_data.is_changed()) {
+            continue;
+        }
+
+        text.sections = rich_text.sections(&theme_data, &asset_server);
+    }
+}
+
+/// The color of a span, either a hex color or an extended color of the theme
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum RichTextColor {
+    Color(Color),
+    Extended(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum RichTextContent {
+    Text(String),
+    /// An icon of the theme by name, only font icons can be shown inline
+    Icon(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RichTextSpan {
+    pub content: RichTextContent,
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<RichTextColor>,
+    pub link: Option<String>,
+}
+
+impl RichTextSpan {
+    pub fn font_type(&self) -> FontType {
+        match (self.bold, self.italic) {
+            (false, false) => FontType::Regular,
+            (true, false) => FontType::Bold,
+            (false, true) => FontType::Italic,
+            (true, true) => FontType::BoldItalic,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum MarkupTag {
+    Bold,
+    Italic,
+    Color(RichTextColor),
+    Link(String),
+}
+
+impl MarkupTag {
+    fn name(&self) -> &'static str {
+        match self {
+            MarkupTag::Bold => "b",
+            MarkupTag::Italic => "i",
+            MarkupTag::Color(_) => "color",
+            MarkupTag::Link(_) => "link",
+        }
+    }
+
+    fn parse(tag: &str) -> Option<Self> {
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name, Some(value.trim())),
+            None => (tag, None),
+        };
+
+        match (name.trim(), value) {
+            ("b", None) => Some(MarkupTag::Bold),
+            ("i", None) => Some(MarkupTag::Italic),
+            ("color", Some(value)) => match value.strip_prefix('#') {
+                Some(hex) => Some(MarkupTag::Color(RichTextColor::Color(
+                    Srgba::hex(hex).ok()?.into(),
+                ))),
+                None => Some(MarkupTag::Color(RichTextColor::Extended(value.into()))),
+            },
+            ("link", Some(value)) => Some(MarkupTag::Link(value.into())),
+            _ => None,
+        }
+    }
+}
+
+/// Text with inline styling, icons and clickable links, written in a BBCode-like markup:
+///
+/// - `[b]bold[/b]` and `[i]italic[/i]`, using the bold / italic fonts of the theme
+/// - `[color=#FF8800]hex[/color]` or `[color=warning]extended theme color[/color]`
+/// - `[link=target]clickable[/link]`, triggering `RichTextLinkClicked` with the target
+/// - `[icon=save]`, an icon of the theme by name
+/// - `[[` for a literal `[`
+///
+/// Unknown or unmatched tags are kept as text.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RichText {
+    markup: String,
+    spans: Vec<RichTextSpan>,
+    pub font_style: FontStyle,
+    pub font_scale: FontScale,
+    /// Color of the spans without a color, follows the theme if `None`
+    pub color: Option<Color>,
+}
+
+impl Default for RichText {
+    fn default() -> Self {
+        Self {
+            markup: Default::default(),
+            spans: Default::default(),
+            font_style: FontStyle::Body,
+            font_scale: FontScale::Medium,
+            color: None,
+        }
+    }
+}
+
+impl RichText {
+    pub fn new(markup: impl Into<String>) -> Self {
+        let mut rich_text = Self::default();
+        rich_text.set_markup(markup);
+
+        rich_text
+    }
+
+    pub fn markup(&self) -> &str {
+        &self.markup
+    }
+
+    pub fn set_markup(&mut self, markup: impl Into<String>) {
+        self.markup = markup.into();
+        self.spans = RichText::parse(&self.markup);
+    }
+
+    /// The parsed spans, matching the text sections one to one
+    pub fn spans(&self) -> &Vec<RichTextSpan> {
+        &self.spans
+    }
+
+    pub fn parse(markup: &str) -> Vec<RichTextSpan> {
+        let mut spans: Vec<RichTextSpan> = Vec::new();
+        let mut tags: Vec<MarkupTag> = Vec::new();
+        let mut text = String::new();
+        let mut rest = markup;
+
+        while let Some(start) = rest.find('[') {
+            text.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            if rest.starts_with("[[") {
+                text.push('[');
+                rest = &rest[2..];
+                continue;
+            }
+
+            let Some(end) = rest.find(']') else {
+                break;
+            };
+
+            let tag = &rest[1..end];
+            if tag.contains('[') {
+                text.push('[');
+                rest = &rest[1..];
+                continue;
+            }
+
+            if let Some(name) = tag.strip_prefix('/') {
+                match tags.iter().rposition(|open| open.name() == name.trim()) {
+                    Some(index) => {
+                        RichText::push_span(&mut spans, &mut text, &tags);
+                        tags.truncate(index);
+                    }
+                    None => text.push_str(&rest[..=end]),
+                }
+            } else if let Some(icon) = tag.strip_prefix("icon=") {
+                RichText::push_span(&mut spans, &mut text, &tags);
+                spans.push(RichText::span(
+                    RichTextContent::Icon(icon.trim().into()),
+                    &tags,
+                ));
+            } else {
+                match MarkupTag::parse(tag) {
+                    Some(open) => {
+                        RichText::push_span(&mut spans, &mut text, &tags);
+                        tags.push(open);
+                    }
+                    None => text.push_str(&rest[..=end]),
+                }
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        text.push_str(rest);
+        RichText::push_span(&mut spans, &mut text, &tags);
+
+        spans
+    }
+
+    fn push_span(spans: &mut Vec<RichTextSpan>, text: &mut String, tags: &[MarkupTag]) {
+        if text.is_empty() {
+            return;
+        }
+
+        spans.push(RichText::span(
+            RichTextContent::Text(std::mem::take(text)),
+            tags,
+        ));
+    }
+
+    fn span(content: RichTextContent, tags: &[MarkupTag]) -> RichTextSpan {
+        RichTextSpan {
+            content,
+            bold: tags.contains(&MarkupTag::Bold),
+            italic: tags.contains(&MarkupTag::Italic),
+            color: tags.iter().rev().find_map(|tag| match tag {
+                MarkupTag::Color(color) => color.clone().into(),
+                _ => None,
+            }),
+            link: tags.iter().rev().find_map(|tag| match tag {
+                MarkupTag::Link(link) => link.clone().into(),
+                _ => None,
+            }),
+        }
+    }
+
+    /// Builds the text sections of the spans with the fonts and colors of the theme
+    pub fn sections(&self, theme_data: &ThemeData, asset_server: &AssetServer) -> Vec<TextSection> {
+        let colors = theme_data.colors();
+        let text_color = self.color.unwrap_or(colors.on(OnColor::Surface));
+        let link_color = colors.accent(Accent::Primary);
+
+        self.spans
+            .iter()
+            .map(|span| {
+                let font = theme_data.font(self.font_style, self.font_scale, span.font_type());
+                let color = match &span.color {
+                    Some(RichTextColor::Color(color)) => *color,
+                    Some(RichTextColor::Extended(name)) => colors.extended(name, Role::Color),
+                    None if span.link.is_some() => link_color,
+                    None => text_color,
+                };
+
+                let (value, font_path) = match &span.content {
+                    RichTextContent::Text(text) => (text.clone(), font.font),
+                    RichTextContent::Icon(name) => match theme_data.icons.get(name) {
+                        IconData::FontCodepoint(icon_font, codepoint, _, _) => {
+                            (codepoint.to_string(), icon_font)
+                        }
+                        _ => {
+                            warn!(
+                                "Icon {} can't be shown in rich text, only font icons can",
+                                name
+                            );
+                            (String::new(), font.font)
+                        }
+                    },
+                };
+
+                TextSection::new(
+                    value,
+                    TextStyle {
+                        font: asset_server.load(font_path),
+                        font_size: font.size,
+                        color,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the link under `position`, given in logical pixels from the top left of the node.
+    ///
+    /// Glyphs are laid out in physical pixels, hence the `scale_factor`.
+    pub fn link_at(
+        &self,
+        text: &Text,
+        text_layout: &TextLayoutInfo,
+        position: Vec2,
+        scale_factor: f32,
+    ) -> Option<&str> {
+        let position = position * scale_factor;
+
+        text_layout
+            .glyphs
+            .iter()
+            .filter(|glyph| {
+                let Some(section) = text.sections.get(glyph.section_index) else {
+                    return false;
+                };
+                let font_size = section.style.font_size * scale_factor;
+                let reach = Vec2::new(
+                    glyph.size.x / 2. + font_size * LINK_HIT_PADDING,
+                    glyph.size.y.max(font_size) / 2.,
+                );
+
+                (position - glyph.position).abs().cmple(reach).all()
+            })
+            .min_by(|a, b| {
+                let a = a.position.distance_squared(position);
+                let b = b.position.distance_squared(position);
+                a.total_cmp(&b)
+            })
+            .and_then(|glyph| self.spans.get(glyph.section_index)?.link.as_deref())
+    }
+}
+
+pub trait UiRichTextExt {
+    fn rich_text(&mut self, rich_text: impl Into<RichText>) -> UiBuilder<Entity>;
+}
+
+impl UiRichTextExt for UiBuilder<'_, Entity> {
+    /// A text node rendering the markup of [`RichText`]
+    fn rich_text(&mut self, rich_text: impl Into<RichText>) -> UiBuilder<Entity> {
+        self.spawn((
+            Name::new("Rich Text"),
+            TextBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+            RelativeCursorPosition::default(),
+            rich_text.into(),
+        ))
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(markup: &str) -> Self {
+        RichText::new(markup)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(markup: String) -> Self {
+        RichText::new(markup)
+    }
+}
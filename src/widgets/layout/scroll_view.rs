@@ -67,6 +67,7 @@ fn update_scroll_view_on_scroll(
         (AnyOf<(&ScrollViewViewport, &ScrollBarHandle)>, &Scrollable),
         Changed<Scrollable>,
     >,
+    theme_data: Res<ThemeData>,
     mut q_scroll_view: Query<&mut ScrollView>,
 ) {
     for ((viewport, handle), scrollable) in &q_scrollables {
@@ -90,8 +91,12 @@ fn update_scroll_view_on_scroll(
             continue;
         }
 
+        // Offsets are measured from the start side, which is the right in right-to-left layouts
         let offset = match axis {
-            ScrollAxis::Horizontal => Vec2 { x: diff, y: 0. },
+            ScrollAxis::Horizontal => Vec2 {
+                x: theme_data.layout_direction.sign() * diff,
+                y: 0.,
+            },
             ScrollAxis::Vertical => Vec2 { x: 0., y: diff },
         };
         let diff = match unit {
@@ -107,6 +112,7 @@ fn update_scroll_view_on_scroll(
 fn update_scroll_view_on_drag(
     q_draggable: Query<(Entity, &Draggable, &ScrollBarHandle), Changed<Draggable>>,
     q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
     mut q_scroll_view: Query<&mut ScrollView>,
 ) {
     for (entity, draggable, bar_handle) in &q_draggable {
@@ -157,7 +163,7 @@ fn update_scroll_view_on_drag(
         let remaining_space = container_size - bar_size;
         let ratio = overflow / remaining_space;
         let diff = match bar_handle.axis {
-            ScrollAxis::Horizontal => diff.x,
+            ScrollAxis::Horizontal => theme_data.layout_direction.sign() * diff.x,
             ScrollAxis::Vertical => diff.y,
         } * ratio;
 
@@ -216,11 +222,17 @@ fn update_scroll_view_offset(
 }
 
 fn update_scroll_view_layout(
-    q_scroll_view: Query<(Entity, &ScrollView), Or<(Changed<ScrollView>, Changed<Node>)>>,
+    q_scroll_view: Query<(Entity, Ref<ScrollView>, Ref<Node>)>,
     q_node: Query<&Node>,
+    theme_data: Res<ThemeData>,
     mut commands: Commands,
 ) {
-    for (entity, scroll_view) in &q_scroll_view {
+    let direction = theme_data.layout_direction;
+    for (entity, scroll_view, node) in &q_scroll_view {
+        if !scroll_view.is_changed() && !node.is_changed() && !theme_data.is_changed() {
+            continue;
+        }
+
         if scroll_view.disabled {
             commands
                 .entity(entity)
@@ -242,9 +254,11 @@ fn update_scroll_view_layout(
         let overflow_y = scroll_view.overflow.y;
 
         if overflow_x > 0. {
+            // Right-to-left content starts scrolled to its right edge
+            let content_offset = direction.pick(scroll_offset_x, overflow_x - scroll_offset_x);
             commands
                 .style_unchecked(scroll_view.content_container)
-                .left(Val::Px(-scroll_offset_x));
+                .left(Val::Px(-content_offset));
             commands
                 .entity(entity)
                 .add_pseudo_state(PseudoState::OverflowX);
@@ -256,7 +270,7 @@ fn update_scroll_view_layout(
             let bar_width = (scroll_view.visible_ratio.x * bar_container_width)
                 .clamp(5., bar_container_width.max(5.));
             let remaining_space = bar_container_width - bar_width;
-            let bar_offset = (scroll_offset_x / overflow_x) * remaining_space;
+            let bar_offset = (content_offset / overflow_x) * remaining_space;
             commands
                 .style_unchecked(scroll_view.horizontal_scroll_bar_handle)
                 .width(Val::Px(bar_width))
@@ -506,8 +520,9 @@ impl ScrollView {
         _scroll_view: &ScrollView,
         theme_data: &ThemeData,
     ) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
+        let direction = theme_data.layout_direction;
 
         style_builder
             .width(Val::Percent(100.))
@@ -526,10 +541,11 @@ impl ScrollView {
 
         style_builder
             .switch_target(ScrollView::VERTICAL_SCROLL_BAR)
-            .right(Val::Px(0.))
+            .left(direction.pick(Val::Auto, Val::Px(0.)))
+            .right(direction.pick(Val::Px(0.), Val::Auto))
             .width(Val::Px(theme_spacing.scroll_bar_size))
             .height(Val::Percent(100.))
-            .border(UiRect::left(Val::Px(theme_spacing.borders.extra_small)))
+            .border(direction.rect(UiRect::left(Val::Px(theme_spacing.borders.extra_small))))
             .border_color(colors.accent(Accent::Shadow))
             .background_color(colors.container(Container::SurfaceMid))
             .visibility(Visibility::Hidden);
@@ -579,7 +595,7 @@ impl ScrollView {
     }
 
     fn overflow_x_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
 
         style_builder
             .switch_target(ScrollView::HORIZONTAL_SCROLL_BAR)
@@ -591,7 +607,7 @@ impl ScrollView {
     }
 
     fn overflow_y_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
 
         style_builder
             .switch_target(ScrollView::VERTICAL_SCROLL_BAR)
@@ -599,24 +615,35 @@ impl ScrollView {
 
         style_builder
             .switch_target(ScrollView::CONTENT_CONTAINER)
-            .padding(UiRect::right(Val::Px(theme_spacing.scroll_bar_size)));
+            .padding(
+                theme_data
+                    .layout_direction
+                    .rect(UiRect::right(Val::Px(theme_spacing.scroll_bar_size))),
+            );
     }
 
     fn overflow_xy_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
-
-        style_builder
-            .switch_target(ScrollView::HORIZONTAL_SCROLL_BAR)
-            .right(Val::Px(theme_spacing.scroll_bar_size));
+        let theme_spacing = theme_data.spacing();
+        let direction = theme_data.layout_direction;
+
+        // The horizontal bar leaves room for the vertical one on the end side
+        match direction {
+            LayoutDirection::LeftToRight => style_builder
+                .switch_target(ScrollView::HORIZONTAL_SCROLL_BAR)
+                .right(Val::Px(theme_spacing.scroll_bar_size)),
+            LayoutDirection::RightToLeft => style_builder
+                .switch_target(ScrollView::HORIZONTAL_SCROLL_BAR)
+                .left(Val::Px(theme_spacing.scroll_bar_size)),
+        };
 
         style_builder
             .switch_target(ScrollView::CONTENT_CONTAINER)
-            .padding(UiRect::px(
+            .padding(direction.rect(UiRect::px(
                 0.,
                 theme_spacing.scroll_bar_size,
                 0.,
                 theme_spacing.scroll_bar_size,
-            ));
+            )));
     }
 
     fn frame() -> impl Bundle {
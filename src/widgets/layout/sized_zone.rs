@@ -43,7 +43,7 @@ impl Plugin for SizedZonePlugin {
         .add_systems(
             PostUpdate,
             update_sized_zone_resize_handles
-                .run_if(should_update_sized_zone_layout)
+                .run_if(should_update_sized_zone_layout.or_else(resource_changed::<ThemeData>))
                 .after(fit_sized_zones_on_window_resize),
         );
     }
@@ -175,6 +175,14 @@ fn update_sized_zone_resize_handles(
     for parent in parents {
         let children: Vec<Entity> = q_children.get(parent).unwrap().iter().map(|e| *e).collect();
         let child_count = children.len();
+        // Zones of reversed parents (i.e. in right-to-left layouts) have their previous sibling
+        // on the far side
+        let reversed = q_style.get(parent).is_ok_and(|style| {
+            matches!(
+                style.flex_direction,
+                FlexDirection::RowReverse | FlexDirection::ColumnReverse
+            )
+        });
 
         if child_count == 1 {
             let Ok(zone) = q_sized_zones.get(children[0]) else {
@@ -207,14 +215,14 @@ fn update_sized_zone_resize_handles(
 
                 match zone.flex_direction {
                     FlexDirection::Row => {
+                        let (prev_dir, next_dir) = match reversed {
+                            true => (CardinalDirection::South, CardinalDirection::North),
+                            false => (CardinalDirection::North, CardinalDirection::South),
+                        };
+                        handle_visibility.push((zone.resize_handles, prev_dir, !prev_is_zone));
                         handle_visibility.push((
                             zone.resize_handles,
-                            CardinalDirection::North,
-                            !prev_is_zone,
-                        ));
-                        handle_visibility.push((
-                            zone.resize_handles,
-                            CardinalDirection::South,
+                            next_dir,
                             i != child_count - 1,
                         ));
                         handle_visibility.push((
@@ -229,14 +237,14 @@ fn update_sized_zone_resize_handles(
                         ));
                     }
                     FlexDirection::Column => {
+                        let (prev_dir, next_dir) = match reversed {
+                            true => (CardinalDirection::East, CardinalDirection::West),
+                            false => (CardinalDirection::West, CardinalDirection::East),
+                        };
+                        handle_visibility.push((zone.resize_handles, prev_dir, !prev_is_zone));
                         handle_visibility.push((
                             zone.resize_handles,
-                            CardinalDirection::West,
-                            !prev_is_zone,
-                        ));
-                        handle_visibility.push((
-                            zone.resize_handles,
-                            CardinalDirection::East,
+                            next_dir,
                             i != child_count - 1,
                         ));
                         handle_visibility.push((
@@ -262,31 +270,33 @@ fn update_sized_zone_resize_handles(
 
             for i in 0..zone_children.len() {
                 let zone = q_sized_zones.get(zone_children[i]).unwrap();
-                let Some((prev_dir, prev_handle, next_dir, next_handle)) =
-                    (match zone.flex_direction {
-                        FlexDirection::Row => (
-                            CardinalDirection::North,
-                            zone.top_handle,
-                            CardinalDirection::South,
-                            zone.bottom_handle,
-                        )
-                            .into(),
-                        FlexDirection::Column => (
-                            CardinalDirection::West,
-                            zone.left_handle,
-                            CardinalDirection::East,
-                            zone.right_handle,
-                        )
-                            .into(),
-                        _ => None,
-                    })
-                else {
+                let Some(handles) = (match zone.flex_direction {
+                    FlexDirection::Row => (
+                        CardinalDirection::North,
+                        zone.top_handle,
+                        CardinalDirection::South,
+                        zone.bottom_handle,
+                    )
+                        .into(),
+                    FlexDirection::Column => (
+                        CardinalDirection::West,
+                        zone.left_handle,
+                        CardinalDirection::East,
+                        zone.right_handle,
+                    )
+                        .into(),
+                    _ => None,
+                }) else {
                     warn!(
                         "Invalid flex_direction detected on sized zone {}",
                         zone_children[i]
                     );
                     continue;
                 };
+                let (prev_dir, prev_handle, next_dir, next_handle) = match reversed {
+                    true => (handles.2, handles.3, handles.0, handles.1),
+                    false => handles,
+                };
 
                 if i == 0 {
                     handle_visibility.push((zone.resize_handles, prev_dir, false));
@@ -437,9 +447,17 @@ fn update_sized_zone_on_resize(
     }
 }
 
-fn update_sized_zone_style(mut q_sized_zones: Query<(&SizedZone, &mut Style), Changed<SizedZone>>) {
+fn update_sized_zone_style(
+    theme_data: Res<ThemeData>,
+    mut q_sized_zones: Query<(Ref<SizedZone>, &mut Style)>,
+) {
+    let direction = theme_data.layout_direction;
     for (zone, mut style) in &mut q_sized_zones {
-        style.flex_direction = zone.flex_direction;
+        if !zone.is_changed() && !theme_data.is_changed() {
+            continue;
+        }
+
+        style.flex_direction = direction.flex_direction(zone.flex_direction);
         match zone.flex_direction {
             FlexDirection::Row => {
                 style.width = Val::Percent(100.);
@@ -718,16 +736,18 @@ impl SizedZone {
 
     fn style_row(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         style_builder.border(UiRect::vertical(Val::Px(
-            theme_data.spacing.borders.extra_small,
+            theme_data.spacing().borders.extra_small,
         )));
     }
     fn style_row_first(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         style_builder.border(UiRect::bottom(Val::Px(
-            theme_data.spacing.borders.extra_small,
+            theme_data.spacing().borders.extra_small,
         )));
     }
     fn style_row_last(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        style_builder.border(UiRect::top(Val::Px(theme_data.spacing.borders.extra_small)));
+        style_builder.border(UiRect::top(Val::Px(
+            theme_data.spacing().borders.extra_small,
+        )));
     }
     fn style_row_single(style_builder: &mut StyleBuilder, _: &ThemeData) {
         style_builder.border(UiRect::all(Val::Auto));
@@ -735,18 +755,18 @@ impl SizedZone {
 
     fn style_column(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         style_builder.border(UiRect::horizontal(Val::Px(
-            theme_data.spacing.borders.extra_small,
+            theme_data.spacing().borders.extra_small,
         )));
     }
     fn style_column_first(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        style_builder.border(UiRect::right(Val::Px(
-            theme_data.spacing.borders.extra_small,
-        )));
+        style_builder.border(theme_data.layout_direction.rect(UiRect::right(Val::Px(
+            theme_data.spacing().borders.extra_small,
+        ))));
     }
     fn style_column_last(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        style_builder.border(UiRect::left(Val::Px(
-            theme_data.spacing.borders.extra_small,
-        )));
+        style_builder.border(theme_data.layout_direction.rect(UiRect::left(Val::Px(
+            theme_data.spacing().borders.extra_small,
+        ))));
     }
     fn style_column_single(style_builder: &mut StyleBuilder, _: &ThemeData) {
         style_builder.border(UiRect::all(Val::Auto));
@@ -116,10 +116,7 @@ fn dock_panel_in_tab_container(
                     tab.label_container = container
                         .container(NodeBundle::default(), |container| {
                             tab.label = container
-                                .label(LabelConfig {
-                                    label: panel.title(),
-                                    ..default()
-                                })
+                                .label(LabelConfig::truncated(panel.title()))
                                 .id();
                         })
                         .id();
@@ -640,14 +637,14 @@ impl ContextMenuGenerator for Tab {
 
         container
             .menu_item(MenuItemConfig {
-                name: "Close Tab".into(),
+                name: LocalizedText::new("sickle-tab-close").with_fallback("Close Tab"),
                 leading_icon: icons.close,
                 ..default()
             })
             .insert(CloseTabContextMenu { tab: context });
         container
             .menu_item(MenuItemConfig {
-                name: "Popout Tab".into(),
+                name: LocalizedText::new("sickle-tab-popout").with_fallback("Popout Tab"),
                 trailing_icon: icons.open_in_new,
                 ..default()
             })
@@ -697,13 +694,12 @@ impl Tab {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
         style_builder
+            .min_height(Val::Px(theme_data.min_hit_target()))
             .padding(UiRect::bottom(Val::Px(theme_spacing.gaps.small)))
             .border(UiRect::right(Val::Px(theme_spacing.borders.small)))
             .border_color(colors.accent(Accent::OutlineVariant))
@@ -715,7 +711,8 @@ impl Tab {
                 hover: colors.container(Container::SurfaceHighest).into(),
                 ..default()
             })
-            .copy_from(theme_data.interaction_animation);
+            .copy_from(theme_data.interaction_animation)
+            .transition_from(theme_data.style_transition);
 
         style_builder
             .switch_target(Tab::LABEL_CONTAINER)
@@ -741,11 +738,13 @@ impl Tab {
     }
 
     fn selected_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
+            .switch_transition(theme_data.style_transition)
             .background_color(colors.surface(Surface::Surface))
+            .reset_transition()
             .animated()
             .bottom(AnimatedVals {
                 idle: Val::Px(-theme_spacing.gaps.tiny),
@@ -988,7 +987,7 @@ impl TabContainer {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -1083,7 +1082,7 @@ pub trait UiTabContainerSubExt {
 
     fn add_tab(
         &mut self,
-        title: String,
+        title: LocalizedText,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<(Entity, TabContainer)>;
 
@@ -1101,7 +1100,7 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
     /// - `PseudoState::Selected` is added to the tab currently selected per TabContainer
     fn add_tab(
         &mut self,
-        title: String,
+        title: LocalizedText,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<(Entity, TabContainer)> {
         let context = self.context().clone();
@@ -1129,10 +1128,7 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
                     tab.label_container = container
                         .container(NodeBundle::default(), |container| {
                             tab.label = container
-                                .label(LabelConfig {
-                                    label: title,
-                                    ..default()
-                                })
+                                .label(LabelConfig::truncated(title))
                                 .id();
                         })
                         .id();
@@ -0,0 +1,463 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use sickle_ui_scaffold::{flux_interaction::StopwatchLock, prelude::*};
+
+use super::{
+    container::UiContainerExt,
+    label::{LabelConfig, UiLabelExt},
+};
+
+const TOOLTIP_Z_INDEX: i32 = 100100;
+const TOOLTIP_CURSOR_OFFSET: f32 = 16.;
+const TOOLTIP_STOPWATCH_LOCK: &'static str = "Tooltip";
+const HOVER_CARD_GRACE_PERIOD: f32 = 0.3;
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, TooltipUpdate.after(FluxInteractionUpdate))
+            .add_plugins(ComponentThemePlugin::<TooltipPanel>::default())
+            .add_systems(
+                Update,
+                (
+                    lock_tooltip_stopwatch,
+                    hide_tooltips,
+                    show_tooltips,
+                    position_tooltip_panels,
+                )
+                    .chain()
+                    .in_set(TooltipUpdate),
+            )
+            .add_systems(PostUpdate, delete_orphaned_tooltip_panels);
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TooltipUpdate;
+
+fn lock_tooltip_stopwatch(
+    mut q_tooltips: Query<
+        (Entity, &Tooltip, Option<&mut FluxInteractionStopwatchLock>),
+        Changed<Tooltip>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, tooltip, stopwatch_lock) in &mut q_tooltips {
+        // Keep the stopwatch alive until the tooltip can be shown
+        let lock_duration = StopwatchLock::Duration(Duration::from_secs_f32(tooltip.delay));
+        if let Some(mut stopwatch_lock) = stopwatch_lock {
+            stopwatch_lock.lock(TOOLTIP_STOPWATCH_LOCK, lock_duration);
+        } else {
+            let mut stopwatch_lock = FluxInteractionStopwatchLock::new();
+            stopwatch_lock.lock(TOOLTIP_STOPWATCH_LOCK, lock_duration);
+            commands.entity(entity).insert(stopwatch_lock);
+        }
+    }
+}
+
+fn hide_tooltips(
+    mut q_tooltips: Query<(
+        &mut Tooltip,
+        &FluxInteraction,
+        Option<&FluxInteractionStopwatch>,
+    )>,
+    q_panels: Query<&Interaction, With<TooltipPanel>>,
+    mut commands: Commands,
+) {
+    for (mut tooltip, flux_interaction, stopwatch) in &mut q_tooltips {
+        let Some(panel) = tooltip.panel else {
+            continue;
+        };
+
+        let Ok(panel_interaction) = q_panels.get(panel) else {
+            tooltip.panel = None;
+            continue;
+        };
+
+        let hide = match flux_interaction {
+            FluxInteraction::PointerEnter => false,
+            FluxInteraction::None | FluxInteraction::PointerLeave => {
+                // Hover cards stay open while the pointer is over them,
+                // with a grace period to allow moving the pointer from the node to the card
+                let in_grace_period = stopwatch
+                    .is_some_and(|stopwatch| stopwatch.0.elapsed_secs() < HOVER_CARD_GRACE_PERIOD);
+
+                !tooltip.interactive
+                    || (*panel_interaction == Interaction::None && !in_grace_period)
+            }
+            FluxInteraction::Pressed
+            | FluxInteraction::Released
+            | FluxInteraction::PressCanceled
+            | FluxInteraction::Disabled => true,
+        };
+
+        if hide {
+            commands.entity(panel).despawn_recursive();
+            tooltip.panel = None;
+        }
+    }
+}
+
+fn show_tooltips(
+    mut q_tooltips: Query<(
+        Entity,
+        &mut Tooltip,
+        &FluxInteraction,
+        Option<&FluxInteractionStopwatch>,
+    )>,
+    q_parents: Query<&Parent>,
+    q_context_roots: Query<&UiContextRoot>,
+    mut commands: Commands,
+) {
+    for (entity, mut tooltip, flux_interaction, stopwatch) in &mut q_tooltips {
+        if tooltip.panel.is_some() || *flux_interaction != FluxInteraction::PointerEnter {
+            continue;
+        }
+
+        let Some(stopwatch) = stopwatch else {
+            continue;
+        };
+
+        if stopwatch.0.elapsed_secs() < tooltip.delay {
+            continue;
+        }
+
+        let mut root_node = entity;
+        while let Ok(parent) = q_parents.get(root_node) {
+            root_node = parent.get();
+            if q_context_roots.get(root_node).is_ok() {
+                break;
+            }
+        }
+
+        let mut label = Entity::PLACEHOLDER;
+        let name = format!("Tooltip of [{}]", entity);
+        let panel = commands
+            .ui_builder(root_node)
+            .container(
+                TooltipPanel::frame(name, tooltip.interactive),
+                |panel| match &tooltip.content {
+                    TooltipContent::Text(text) => {
+                        label = panel.label(LabelConfig::from(text.clone())).id();
+                    }
+                    TooltipContent::Builder(spawn_content) => spawn_content(panel),
+                },
+            )
+            .insert(TooltipPanel {
+                source: entity,
+                label,
+                interactive: tooltip.interactive,
+            })
+            .id();
+
+        tooltip.panel = panel.into();
+    }
+}
+
+fn position_tooltip_panels(world: &mut World) {
+    let mut q_panels = world.query::<(Entity, &TooltipPanel)>();
+    let placements: Vec<(Entity, Vec2)> = q_panels
+        .iter(world)
+        .filter_map(|(entity, panel)| {
+            TooltipPanel::placement_for(entity, panel.source, world)
+                .map(|placement| (entity, placement))
+        })
+        .collect();
+
+    for (entity, placement) in placements {
+        if let Some(mut style) = world.get_mut::<Style>(entity) {
+            if style.left != Val::Px(placement.x) {
+                style.left = Val::Px(placement.x);
+            }
+            if style.top != Val::Px(placement.y) {
+                style.top = Val::Px(placement.y);
+            }
+        }
+
+        // Panels are hidden until their size is known and they can be placed
+        if let Some(mut visibility) = world.get_mut::<Visibility>(entity) {
+            if *visibility == Visibility::Hidden {
+                *visibility = Visibility::Inherited;
+            }
+        }
+    }
+}
+
+fn delete_orphaned_tooltip_panels(
+    q_panels: Query<(Entity, &TooltipPanel)>,
+    q_tooltips: Query<&Tooltip>,
+    mut commands: Commands,
+) {
+    for (entity, panel) in &q_panels {
+        let is_orphan = match q_tooltips.get(panel.source) {
+            Ok(tooltip) => tooltip.panel != Some(entity),
+            Err(_) => true,
+        };
+
+        if is_orphan {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Content of a [`Tooltip`]
+#[derive(Clone)]
+pub enum TooltipContent {
+    /// A label kept in sync with the [`Locale`] while shown
+    Text(LocalizedText),
+    /// Spawns arbitrary content into the tooltip panel each time it is shown
+    Builder(Arc<dyn Fn(&mut UiBuilder<Entity>) + Send + Sync>),
+}
+
+impl fmt::Debug for TooltipContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Builder(_) => f.debug_tuple("Builder").finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TooltipAnchor {
+    /// Placed below the cursor when it is shown, following it while the node is hovered
+    #[default]
+    Cursor,
+    /// Placed below or above the node
+    Node,
+}
+
+/// Shows a floating panel after the node is hovered for `delay` seconds
+///
+/// The node must have a `TrackedInteraction`. The tooltip is dismissed when the node is pressed
+/// or the pointer leaves it. Interactive tooltips (hover cards) also stay open while the pointer
+/// is over the panel itself.
+#[derive(Component, Clone, Debug)]
+pub struct Tooltip {
+    pub content: TooltipContent,
+    pub anchor: TooltipAnchor,
+    pub delay: f32,
+    pub interactive: bool,
+    panel: Option<Entity>,
+}
+
+impl Tooltip {
+    pub fn text(text: impl Into<LocalizedText>) -> Self {
+        Self {
+            content: TooltipContent::Text(text.into()),
+            anchor: TooltipAnchor::Cursor,
+            delay: 0.5,
+            interactive: false,
+            panel: None,
+        }
+    }
+
+    pub fn builder(spawn_content: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static) -> Self {
+        Self {
+            content: TooltipContent::Builder(Arc::new(spawn_content)),
+            ..Tooltip::text("")
+        }
+    }
+
+    /// An interactive tooltip anchored to the node, with arbitrary content
+    pub fn hover_card(
+        spawn_content: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            anchor: TooltipAnchor::Node,
+            delay: 0.8,
+            interactive: true,
+            ..Tooltip::builder(spawn_content)
+        }
+    }
+
+    pub fn with_anchor(self, anchor: TooltipAnchor) -> Self {
+        Self { anchor, ..self }
+    }
+
+    pub fn with_delay(self, delay: f32) -> Self {
+        Self { delay, ..self }
+    }
+
+    pub fn panel(&self) -> Option<Entity> {
+        self.panel
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TooltipPanel {
+    source: Entity,
+    label: Entity,
+    interactive: bool,
+}
+
+impl Default for TooltipPanel {
+    fn default() -> Self {
+        Self {
+            source: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            interactive: false,
+        }
+    }
+}
+
+impl UiContext for TooltipPanel {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            TooltipPanel::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for TooltipPanel. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        // Tooltips with custom content have no label
+        let label = match self.label == Entity::PLACEHOLDER {
+            true => None,
+            false => Some(TooltipPanel::LABEL),
+        };
+
+        label.into_iter()
+    }
+}
+
+impl DefaultTheme for TooltipPanel {
+    fn default_theme() -> Option<Theme<TooltipPanel>> {
+        TooltipPanel::theme().into()
+    }
+}
+
+impl TooltipPanel {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+
+    pub fn theme() -> Theme<TooltipPanel> {
+        let base_theme = PseudoTheme::deferred_context(None, TooltipPanel::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        panel: &TooltipPanel,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .position_type(PositionType::Absolute)
+            .flex_direction(FlexDirection::Column)
+            .z_index(ZIndex::Global(TOOLTIP_Z_INDEX))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )));
+
+        if panel.interactive {
+            style_builder
+                .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+                .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+                .background_color(colors.container(Container::SurfaceMid))
+                .border_color(colors.accent(Accent::Shadow));
+        } else {
+            style_builder
+                .padding(UiRect::axes(
+                    Val::Px(theme_spacing.gaps.small),
+                    Val::Px(theme_spacing.gaps.extra_small),
+                ))
+                .background_color(colors.surface(Surface::InverseSurface));
+        }
+
+        if panel.label != Entity::PLACEHOLDER {
+            let font = theme_data.font(FontStyle::Body, FontScale::Small, FontType::Regular);
+
+            style_builder
+                .switch_target(TooltipPanel::LABEL)
+                .sized_font(font)
+                .font_color(colors.on(OnColor::InverseSurface));
+        }
+    }
+
+    /// Calculates the position of the panel relative to its parent
+    ///
+    /// Like dropdown panels, the panel is flipped to fit the viewport, see [`UiUtils::flip_to_fit`].
+    pub fn placement_for(entity: Entity, source: Entity, world: &World) -> Option<Vec2> {
+        let tooltip = world.get::<Tooltip>(source)?;
+        let panel_size = world.get::<Node>(entity)?.unrounded_size();
+        if panel_size == Vec2::ZERO {
+            return None;
+        }
+
+        let (anchor_min, anchor_max) = match tooltip.anchor {
+            TooltipAnchor::Cursor => {
+                let cursor = UiUtils::get_primary_window(world).cursor_position()?;
+                (cursor, cursor + Vec2::new(0., TOOLTIP_CURSOR_OFFSET))
+            }
+            TooltipAnchor::Node => {
+                let node = world.get::<Node>(source)?;
+                let rect = node.logical_rect(world.get::<GlobalTransform>(source)?);
+                (rect.min, rect.max)
+            }
+        };
+
+        let theme_data = world.resource::<ThemeData>();
+        let gap = theme_data.spacing().gaps.tiny;
+        let viewport_size = UiUtils::viewport_size(entity, world);
+        let (align_right, place_above) = UiUtils::flip_to_fit(
+            Rect::from_corners(anchor_min, anchor_max),
+            panel_size.y + gap,
+            viewport_size,
+            theme_data.layout_direction,
+        );
+
+        let x = match align_right {
+            true => anchor_max.x - panel_size.x,
+            false => anchor_min.x,
+        };
+        let y = match place_above {
+            true => anchor_min.y - panel_size.y - gap,
+            false => anchor_max.y + gap,
+        };
+        let position = Vec2::new(
+            x.clamp(0., (viewport_size.x - panel_size.x).max(0.)),
+            y.clamp(0., (viewport_size.y - panel_size.y).max(0.)),
+        );
+
+        let parent = world.get::<Parent>(entity)?.get();
+        let parent_size = world.get::<Node>(parent)?.unrounded_size();
+        let parent_top_left = world
+            .get::<GlobalTransform>(parent)?
+            .translation()
+            .truncate()
+            - (parent_size / 2.);
+
+        Some(position - parent_top_left)
+    }
+
+    fn frame(name: String, interactive: bool) -> impl Bundle {
+        let focus_policy = match interactive {
+            true => FocusPolicy::Block,
+            false => FocusPolicy::Pass,
+        };
+
+        (
+            Name::new(name),
+            NodeBundle {
+                focus_policy,
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            Interaction::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
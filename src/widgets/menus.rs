@@ -3,6 +3,8 @@ pub mod extra_menu;
 pub mod menu;
 pub mod menu_bar;
 pub mod menu_item;
+pub mod menu_model;
+pub mod menu_scroll;
 pub mod menu_separators;
 pub mod shortcut;
 pub mod submenu;
@@ -1,24 +1,34 @@
-use bevy::{ecs::world::CommandQueue, prelude::*, ui::FocusPolicy, window::PrimaryWindow};
+use bevy::{
+    ecs::world::CommandQueue,
+    prelude::*,
+    time::Stopwatch,
+    ui::{CalculatedClip, FocusPolicy},
+    window::PrimaryWindow,
+};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
-use super::menu_separators::UiMenuItemSeparatorExt;
+use crate::widgets::layout::container::UiContainerExt;
+
+use super::{menu_scroll::MenuScrollView, menu_separators::UiMenuItemSeparatorExt};
 
 const MENU_CONTAINER_Z_INDEX: i32 = 100002;
 
-// TODO: Implement scroll container and up/down arrows when content larger than screen
 pub struct ContextMenuPlugin;
 
 impl Plugin for ContextMenuPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, ContextMenuUpdate.after(FluxInteractionUpdate))
+            .init_resource::<ContextMenuConfig>()
+            .init_resource::<ContextMenuLongPress>()
             .add_plugins(ComponentThemePlugin::<ContextMenu>::default())
             .add_systems(
                 Update,
                 (
                     update_context_menu_vertical_position,
                     handle_click_or_touch,
+                    update_context_menu_long_press,
                     delete_closed_context_menu,
                     generate_context_menu,
                     position_added_context_menu,
@@ -30,9 +40,44 @@ impl Plugin for ContextMenuPlugin {
     }
 }
 
-// TODO: Handle long press on touch
+/// Configures how context menus are opened on touch screens
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ContextMenuConfig {
+    /// Seconds a touch must be held in place to open the context menu under it
+    pub long_press_duration: f32,
+    /// Distance in logical pixels a held touch may travel before the long press is abandoned
+    pub long_press_tolerance: f32,
+}
+
+impl Default for ContextMenuConfig {
+    fn default() -> Self {
+        Self {
+            long_press_duration: 0.5,
+            long_press_tolerance: 10.,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PendingLongPress {
+    touch: u64,
+    target: Entity,
+    origin: Vec2,
+    stopwatch: Stopwatch,
+}
+
+#[derive(Resource, Debug, Default)]
+struct ContextMenuLongPress {
+    pending: Option<PendingLongPress>,
+    /// The touch that opened a context menu, its release must not close the menu
+    opened_by: Option<u64>,
+}
+
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    r_long_press: Res<ContextMenuLongPress>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
     q_context_menu: Query<&Interaction, (With<ContextMenu>, Changed<Interaction>)>,
     mut q_interacted: Query<(Entity, &Interaction, &mut GenerateContextMenu)>,
     mut commands: Commands,
@@ -40,6 +85,11 @@ fn handle_click_or_touch(
     let mut close_all = false;
 
     if r_mouse.just_pressed(MouseButton::Right) {
+        let position = q_window
+            .get_single()
+            .ok()
+            .and_then(|window| window.cursor_position());
+
         let mut open: Option<Entity> = None;
         for (entity, interaction, _) in &q_interacted {
             if *interaction == Interaction::Hovered {
@@ -51,6 +101,7 @@ fn handle_click_or_touch(
         if let Some(open) = open {
             for (entity, _, mut gen_menu) in &mut q_interacted {
                 if entity == open {
+                    gen_menu.position = position;
                     if !gen_menu.is_open {
                         gen_menu.is_open = true;
                     } else if let Some(container) = gen_menu.container {
@@ -64,7 +115,9 @@ fn handle_click_or_touch(
         } else {
             close_all = true;
         }
-    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle])
+        || r_touches.any_just_pressed()
+    {
         let mut on_context_menu = false;
         for interaction in &q_context_menu {
             if *interaction == Interaction::Pressed {
@@ -75,7 +128,11 @@ fn handle_click_or_touch(
         if !on_context_menu {
             close_all = true;
         }
-    } else if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle])
+        || r_touches
+            .iter_just_released()
+            .any(|touch| Some(touch.id()) != r_long_press.opened_by)
+    {
         close_all = true;
     }
 
@@ -88,6 +145,95 @@ fn handle_click_or_touch(
     }
 }
 
+fn update_context_menu_long_press(
+    r_time: Res<Time<Real>>,
+    r_config: Res<ContextMenuConfig>,
+    r_touches: Res<Touches>,
+    mut r_long_press: ResMut<ContextMenuLongPress>,
+    q_targets: Query<
+        (
+            Entity,
+            &Node,
+            &GlobalTransform,
+            &ViewVisibility,
+            Option<&CalculatedClip>,
+        ),
+        With<GenerateContextMenu>,
+    >,
+    mut q_gen_menus: Query<(Entity, &mut GenerateContextMenu)>,
+) {
+    if let Some(touch) = r_long_press.opened_by {
+        if r_touches.get_pressed(touch).is_none() {
+            r_long_press.opened_by = None;
+        }
+    }
+
+    if r_long_press.pending.is_none() {
+        // Only a single finger can open a context menu
+        if r_touches.iter().count() != 1 {
+            return;
+        }
+
+        let Some(touch) = r_touches.iter_just_pressed().next() else {
+            return;
+        };
+
+        let position = touch.position();
+        let target = q_targets
+            .iter()
+            .filter(|(_, node, transform, visibility, clip)| {
+                visibility.get() && UiUtils::node_contains_point(node, transform, *clip, position)
+            })
+            .max_by_key(|(_, node, ..)| node.stack_index());
+
+        if let Some((target, ..)) = target {
+            r_long_press.pending = PendingLongPress {
+                touch: touch.id(),
+                target,
+                origin: position,
+                stopwatch: Stopwatch::new(),
+            }
+            .into();
+        }
+
+        return;
+    }
+
+    // Safe unwrap: checked above
+    let pending = r_long_press.pending.as_mut().unwrap();
+    let Some(touch) = r_touches.get_pressed(pending.touch) else {
+        r_long_press.pending = None;
+        return;
+    };
+
+    if r_touches.iter().count() != 1
+        || touch.position().distance(pending.origin) > r_config.long_press_tolerance
+    {
+        r_long_press.pending = None;
+        return;
+    }
+
+    pending.stopwatch.tick(r_time.delta());
+    if pending.stopwatch.elapsed_secs() < r_config.long_press_duration {
+        return;
+    }
+
+    let target = pending.target;
+    let opened_by = pending.touch;
+    let position = touch.position();
+    r_long_press.opened_by = opened_by.into();
+    r_long_press.pending = None;
+
+    for (entity, mut gen_menu) in &mut q_gen_menus {
+        if entity == target {
+            gen_menu.is_open = true;
+            gen_menu.position = position.into();
+        } else if gen_menu.is_open {
+            gen_menu.is_open = false;
+        }
+    }
+}
+
 fn delete_closed_context_menu(
     mut q_gen_menus: Query<&mut GenerateContextMenu, Changed<GenerateContextMenu>>,
     mut commands: Commands,
@@ -195,14 +341,18 @@ fn generate_context_menu(world: &mut World) {
     let mut commands = Commands::new(&mut queue, world);
     let name = format!("Context Menu of [{}]", entity);
 
+    let mut content = Entity::PLACEHOLDER;
     let container_id = commands
         .ui_builder(root_node)
-        .spawn(ContextMenu::frame(name))
+        .container(ContextMenu::frame(name), |container| {
+            (_, content) = MenuScrollView::scaffold(container, false);
+        })
         .id();
 
     let context_menu = ContextMenu {
         context: entity,
         container: container_id,
+        content,
     };
 
     commands.entity(container_id).insert(context_menu);
@@ -228,27 +378,27 @@ fn generate_context_menu(world: &mut World) {
     }
 }
 
-// TODO: Handle touch position
 fn position_added_context_menu(
-    q_context_menus: Query<Entity, Added<ContextMenu>>,
+    q_context_menus: Query<(Entity, &ContextMenu), Added<ContextMenu>>,
+    q_gen_menus: Query<&GenerateContextMenu>,
     q_window: Query<&Window, With<PrimaryWindow>>,
-    //r_touches: Res<Touches>,
     mut commands: Commands,
 ) {
     let Ok(window) = q_window.get_single() else {
         return;
     };
 
-    let position = window.cursor_position();
-    // if position.is_none() {
-    //     position = r_touches.first_pressed_position();
-    // }
+    for (entity, context_menu) in &q_context_menus {
+        let position = q_gen_menus
+            .get(context_menu.context)
+            .ok()
+            .and_then(|gen_menu| gen_menu.position)
+            .or(window.cursor_position());
 
-    let Some(position) = position else {
-        return;
-    };
+        let Some(position) = position else {
+            continue;
+        };
 
-    for entity in &q_context_menus {
         commands
             .style(entity)
             .position_type(PositionType::Absolute)
@@ -258,7 +408,7 @@ fn position_added_context_menu(
 
 fn update_context_menu_vertical_position(
     mut q_node_style: Query<
-        (&Node, &Transform, &mut Style, &mut Visibility),
+        (&Node, &GlobalTransform, &mut Style, &mut Visibility),
         (With<ContextMenu>, Changed<Node>),
     >,
     q_window: Query<&Window, With<PrimaryWindow>>,
@@ -271,13 +421,22 @@ fn update_context_menu_vertical_position(
     for (node, transform, mut style, mut visibility) in &mut q_node_style {
         let size = node.size();
 
-        let position = transform.translation.truncate() - (size / 2.);
+        let position = transform.translation().truncate() - (size / 2.);
 
+        // Flip to the other side of the cursor, or push back into the window if that doesn't fit
         if position.x + size.x > resolution.x {
-            style.left = Val::Px(0f32.max(position.x - size.x));
+            let flipped = position.x - size.x;
+            style.left = Val::Px(match flipped >= 0. {
+                true => flipped,
+                false => 0f32.max(resolution.x - size.x),
+            });
         }
         if position.y + size.y > resolution.y {
-            style.top = Val::Px(0f32.max(position.y - size.y));
+            let flipped = position.y - size.y;
+            style.top = Val::Px(match flipped >= 0. {
+                true => flipped,
+                false => 0f32.max(resolution.y - size.y),
+            });
         }
 
         *visibility = Visibility::Visible;
@@ -309,6 +468,7 @@ pub trait ContextMenuGenerator {
 pub struct GenerateContextMenu {
     is_open: bool,
     container: Option<Entity>,
+    position: Option<Vec2>,
 }
 
 impl GenerateContextMenu {
@@ -322,6 +482,7 @@ impl GenerateContextMenu {
 pub struct ContextMenu {
     context: Entity,
     container: Entity,
+    content: Entity,
 }
 
 impl Default for ContextMenu {
@@ -329,6 +490,7 @@ impl Default for ContextMenu {
         Self {
             context: Entity::PLACEHOLDER,
             container: Entity::PLACEHOLDER,
+            content: Entity::PLACEHOLDER,
         }
     }
 }
@@ -344,13 +506,23 @@ impl ContextMenu {
         self.context
     }
 
+    /// The entity menu items are spawned into
+    pub(crate) fn item_container(&self) -> Entity {
+        self.content
+    }
+
+    /// The panel submenu containers are mounted to
+    pub(crate) fn panel(&self) -> Entity {
+        self.container
+    }
+
     pub fn theme() -> Theme<ContextMenu> {
         let base_theme = PseudoTheme::deferred(None, ContextMenu::container);
         Theme::new(vec![base_theme])
     }
 
     fn container(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -389,11 +561,19 @@ impl ContextMenu {
 }
 
 pub trait UiContextMenuExt {
+    /// The entity menu items are spawned into
     fn container(&self) -> Entity;
+
+    /// The context menu panel, submenu panels are mounted here to escape the scrolled area
+    fn panel(&self) -> Entity;
 }
 
 impl UiContextMenuExt for UiBuilder<'_, ContextMenu> {
     fn container(&self) -> Entity {
+        self.context().content
+    }
+
+    fn panel(&self) -> Entity {
         self.context().container
     }
 }
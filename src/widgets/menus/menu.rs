@@ -7,12 +7,11 @@ use crate::widgets::layout::{
     label::{LabelConfig, UiLabelExt},
 };
 
-use super::{menu_bar::MenuBar, menu_item::MenuItem};
+use super::{menu_bar::MenuBar, menu_item::MenuItem, menu_scroll::MenuScrollView};
 
 // TODO: Move all z-index constants to a resource
 const MENU_CONTAINER_Z_INDEX: i32 = 100000;
 
-// TODO: Implement scrolling and up/down arrows when menu too large (>70%?)
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
@@ -153,7 +152,7 @@ fn update_menu_container_visibility(
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
 pub struct MenuConfig {
-    pub name: String,
+    pub name: LocalizedText,
     pub alt_code: Option<KeyCode>,
 }
 
@@ -162,6 +161,8 @@ pub struct MenuConfig {
 pub struct Menu {
     label: Entity,
     container: Entity,
+    scroll_view: Entity,
+    content: Entity,
     is_open: bool,
 }
 
@@ -170,6 +171,8 @@ impl Default for Menu {
         Self {
             label: Entity::PLACEHOLDER,
             container: Entity::PLACEHOLDER,
+            scroll_view: Entity::PLACEHOLDER,
+            content: Entity::PLACEHOLDER,
             is_open: false,
         }
     }
@@ -203,18 +206,26 @@ impl Menu {
     pub const CONTAINER: &'static str = "Container";
     pub const LABEL: &'static str = "Label";
 
+    /// The entity menu items are spawned into
+    pub(crate) fn item_container(&self) -> Entity {
+        self.content
+    }
+
+    /// The panel submenu containers are mounted to
+    pub(crate) fn panel(&self) -> Entity {
+        self.container
+    }
+
     pub fn theme() -> Theme<Menu> {
         let base_theme = PseudoTheme::deferred(None, Menu::primary_style);
-        let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Menu::open_style);
+        let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Menu::open_style);
         Theme::new(vec![base_theme, open_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
         style_builder
             .align_items(AlignItems::Center)
@@ -259,7 +270,9 @@ impl Menu {
             .visibility(Visibility::Hidden);
     }
 
-    fn open_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+    fn open_style(style_builder: &mut StyleBuilder, entity: Entity, menu: &Menu, world: &World) {
+        let theme_data = world.resource::<ThemeData>();
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -270,8 +283,31 @@ impl Menu {
             .switch_target(Menu::LABEL)
             .font_color(colors.on(OnColor::Surface));
 
+        // Shift the container left if it would overflow the viewport.
+        // Unsafe unwrap: If a UI element doesn't have a Node or GT, we should panic!
+        let border = theme_spacing.borders.extra_small;
+        let button_node = world.get::<Node>(entity).unwrap();
+        let button_left = world
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation()
+            .x
+            - (button_node.unrounded_size().x / 2.);
+        let container_width = world
+            .get::<Node>(menu.container)
+            .unwrap()
+            .unrounded_size()
+            .x;
+        let viewport_width = UiUtils::viewport_size(entity, world).x;
+        let overflow = button_left - border + container_width - viewport_width;
+        let left = match overflow > 0. {
+            true => (-border - overflow).max(-button_left),
+            false => -border,
+        };
+
         style_builder
             .switch_target(Menu::CONTAINER)
+            .left(Val::Px(left))
             .visibility(Visibility::Inherited);
     }
 
@@ -326,7 +362,12 @@ impl UiMenuExt for UiBuilder<'_, Entity> {
 
         let button_id = self
             .container(Menu::button(name), |menu_button| {
-                menu.container = menu_button.spawn(Menu::container()).id();
+                menu.container = menu_button
+                    .container(Menu::container(), |container| {
+                        (menu.scroll_view, menu.content) =
+                            MenuScrollView::scaffold(container, true);
+                    })
+                    .id();
                 menu.label = menu_button
                     .label(LabelConfig {
                         label: config.name.clone(),
@@ -362,11 +403,19 @@ impl UiMenuExt for UiBuilder<'_, (Entity, MenuBar)> {
 }
 
 pub trait UiMenuSubExt {
+    /// The entity menu items are spawned into
     fn container(&self) -> Entity;
+
+    /// The menu panel, submenu panels are mounted here to escape the scrolled area
+    fn panel(&self) -> Entity;
 }
 
 impl UiMenuSubExt for UiBuilder<'_, Menu> {
     fn container(&self) -> Entity {
+        self.context().content
+    }
+
+    fn panel(&self) -> Entity {
         self.context().container
     }
 }
@@ -74,9 +74,9 @@ fn update_menu_item_on_shortcut_press(
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct MenuItemConfig {
-    pub name: String,
+    pub name: LocalizedText,
     pub leading_icon: IconData,
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
@@ -212,16 +212,17 @@ impl MenuItem {
         leading_icon: IconData,
         trailing_icon: IconData,
     ) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let font = theme_data.font(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let direction = theme_data.layout_direction;
 
         style_builder
+            .flex_direction(direction.row())
             .justify_content(JustifyContent::End)
             .align_items(AlignItems::Center)
             .height(Val::Px(theme_spacing.areas.small))
+            .min_height(Val::Px(theme_data.min_hit_target()))
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.extra_small)))
             .margin(UiRect::vertical(Val::Px(theme_spacing.gaps.tiny)))
             .animated()
@@ -232,6 +233,7 @@ impl MenuItem {
             })
             .copy_from(theme_data.interaction_animation);
 
+        let leading_icon = theme_data.directional_icon(&theme_data.icons.resolve(&leading_icon));
         let leading_icon = match leading_icon.is_codepoint() {
             true => leading_icon.with(
                 colors.on(OnColor::SurfaceVariant),
@@ -255,14 +257,16 @@ impl MenuItem {
             .switch_target(MenuItem::SHORTCUT_CONTAINER)
             .justify_content(JustifyContent::End)
             .flex_wrap(FlexWrap::NoWrap)
+            .flex_direction(direction.row())
             .flex_grow(2.)
-            .margin(UiRect::left(Val::Px(theme_spacing.areas.large)));
+            .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.areas.large))));
 
         style_builder
             .switch_target(MenuItem::SHORTCUT)
             .sized_font(font)
             .font_color(colors.on(OnColor::SurfaceVariant));
 
+        let trailing_icon = theme_data.directional_icon(&theme_data.icons.resolve(&trailing_icon));
         let trailing_icon = match trailing_icon.is_codepoint() {
             true => trailing_icon.with(
                 colors.on(OnColor::SurfaceVariant),
@@ -273,7 +277,7 @@ impl MenuItem {
         style_builder
             .switch_target(MenuItem::TRAILING_ICON)
             .aspect_ratio(1.)
-            .margin(UiRect::left(Val::Px(theme_spacing.gaps.small)))
+            .margin(direction.rect(UiRect::left(Val::Px(theme_spacing.gaps.small))))
             .size(Val::Px(theme_spacing.icons.small))
             .icon(trailing_icon);
     }
@@ -345,19 +349,14 @@ impl MenuItem {
 
         let mut item = builder.container(MenuItem::button(name), |container| {
             menu_item.leading = container.spawn(MenuItem::leading_icon_bundle()).id();
-            menu_item.label = container
-                .label(LabelConfig {
-                    label: config.name,
-                    ..default()
-                })
-                .id();
+            menu_item.label = container.label(LabelConfig::truncated(config.name)).id();
             menu_item.shortcut_container = container
                 .container(
                     MenuItem::shortcut_container_bundle(),
                     |shortcut_container| {
                         menu_item.shortcut = shortcut_container
                             .label(LabelConfig {
-                                label: shortcut_text,
+                                label: shortcut_text.into(),
                                 ..default()
                             })
                             .id();
@@ -0,0 +1,330 @@
+use bevy::{ecs::world::CommandQueue, prelude::*};
+
+use sickle_ui_scaffold::prelude::*;
+
+use super::{
+    context_menu::ContextMenu,
+    menu::Menu,
+    menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+    menu_separators::UiMenuItemSeparatorExt,
+    submenu::{Submenu, SubmenuConfig},
+    toggle_menu_item::{ToggleMenuItem, ToggleMenuItemConfig, UiToggleMenuItemExt},
+};
+
+pub struct MenuModelPlugin;
+
+impl Plugin for MenuModelPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, MenuModelUpdate.before(MenuItemUpdate))
+            .add_systems(Update, reconcile_menu_models.in_set(MenuModelUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct MenuModelUpdate;
+
+fn reconcile_menu_models(world: &mut World) {
+    let mut q_models = world.query_filtered::<Entity, Changed<MenuModel>>();
+    let changed: Vec<Entity> = q_models.iter(world).collect();
+
+    for entity in changed {
+        let Some(model) = world.get::<MenuModel>(entity).cloned() else {
+            continue;
+        };
+
+        MenuModel::reconcile(entity, &model, world);
+    }
+}
+
+/// The kind of an entry in a [`MenuModel`], with the config used to spawn it
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuModelEntryKind {
+    Item(MenuItemConfig),
+    Toggle(ToggleMenuItemConfig),
+    Separator,
+    Submenu(SubmenuConfig, MenuModel),
+}
+
+impl MenuModelEntryKind {
+    fn name(&self) -> Option<&LocalizedText> {
+        match self {
+            MenuModelEntryKind::Item(config) => Some(&config.name),
+            MenuModelEntryKind::Toggle(config) => Some(&config.name),
+            MenuModelEntryKind::Separator => None,
+            MenuModelEntryKind::Submenu(config, _) => Some(&config.name),
+        }
+    }
+
+    /// Checks if `other` only differs in ways that can be applied to the existing entity
+    fn can_update_to(&self, other: &MenuModelEntryKind) -> bool {
+        match (self, other) {
+            (MenuModelEntryKind::Item(current), MenuModelEntryKind::Item(next)) => {
+                MenuItemConfig {
+                    name: next.name.clone(),
+                    ..current.clone()
+                } == *next
+            }
+            (MenuModelEntryKind::Toggle(current), MenuModelEntryKind::Toggle(next)) => {
+                ToggleMenuItemConfig {
+                    name: next.name.clone(),
+                    initially_checked: next.initially_checked,
+                    ..current.clone()
+                } == *next
+            }
+            (MenuModelEntryKind::Separator, MenuModelEntryKind::Separator) => true,
+            (MenuModelEntryKind::Submenu(current, _), MenuModelEntryKind::Submenu(next, _)) => {
+                SubmenuConfig {
+                    name: next.name.clone(),
+                    ..current.clone()
+                } == *next
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuModelEntry {
+    pub key: String,
+    pub kind: MenuModelEntryKind,
+}
+
+/// Declarative content of a menu, submenu or context menu
+///
+/// Insert it on the `Menu`, `Submenu` or `ContextMenu` entity (or any plain container) and
+/// replace it whenever the underlying data changes. Entries are matched to the spawned items by
+/// their key: unchanged items are kept as is, labels and checked states are updated in place,
+/// and only new, removed, or otherwise changed entries are spawned or despawned.
+/// Kept items retain their interaction state, and submenus stay open.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct MenuModel {
+    pub entries: Vec<MenuModelEntry>,
+}
+
+impl MenuModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn item(mut self, key: impl Into<String>, config: impl Into<MenuItemConfig>) -> Self {
+        self.push(key, MenuModelEntryKind::Item(config.into()));
+        self
+    }
+
+    pub fn toggle(mut self, key: impl Into<String>, config: ToggleMenuItemConfig) -> Self {
+        self.push(key, MenuModelEntryKind::Toggle(config));
+        self
+    }
+
+    pub fn separator(mut self, key: impl Into<String>) -> Self {
+        self.push(key, MenuModelEntryKind::Separator);
+        self
+    }
+
+    pub fn submenu(
+        mut self,
+        key: impl Into<String>,
+        config: SubmenuConfig,
+        entries: MenuModel,
+    ) -> Self {
+        self.push(key, MenuModelEntryKind::Submenu(config, entries));
+        self
+    }
+
+    pub fn push(&mut self, key: impl Into<String>, kind: MenuModelEntryKind) {
+        self.entries.push(MenuModelEntry {
+            key: key.into(),
+            kind,
+        });
+    }
+
+    fn containers_of(entity: Entity, world: &World) -> (Entity, Entity) {
+        if let Some(menu) = world.get::<Menu>(entity) {
+            (menu.item_container(), menu.panel())
+        } else if let Some(submenu) = world.get::<Submenu>(entity) {
+            (submenu.item_container(), submenu.panel())
+        } else if let Some(context_menu) = world.get::<ContextMenu>(entity) {
+            (context_menu.item_container(), context_menu.panel())
+        } else {
+            (entity, entity)
+        }
+    }
+
+    fn reconcile(entity: Entity, model: &MenuModel, world: &mut World) {
+        let (container, panel) = MenuModel::containers_of(entity, world);
+        let children: Vec<Entity> = match world.get::<Children>(container) {
+            Some(children) => children.iter().copied().collect(),
+            None => Vec::new(),
+        };
+
+        let mut existing: Vec<(Entity, MenuModelEntry)> = children
+            .iter()
+            .filter_map(|child| {
+                world
+                    .get::<MenuModelItem>(*child)
+                    .map(|item| (*child, item.entry.clone()))
+            })
+            .collect();
+
+        let mut ordered: Vec<Entity> = Vec::with_capacity(model.entries.len());
+        for (index, entry) in model.entries.iter().enumerate() {
+            if model.entries[..index]
+                .iter()
+                .any(|other| other.key == entry.key)
+            {
+                warn!(
+                    "Duplicate key {} in menu model of {}, entry skipped",
+                    entry.key, entity
+                );
+                continue;
+            }
+
+            let current = existing
+                .iter()
+                .position(|(_, current)| current.key == entry.key)
+                .map(|index| existing.swap_remove(index));
+
+            let item = match current {
+                Some((item, current)) if current == *entry => item,
+                Some((item, current)) if current.kind.can_update_to(&entry.kind) => {
+                    MenuModel::update_item(item, entry, world);
+                    item
+                }
+                Some((item, _)) => {
+                    world.entity_mut(item).despawn_recursive();
+                    MenuModel::spawn_item(container, panel, entry, world)
+                }
+                None => MenuModel::spawn_item(container, panel, entry, world),
+            };
+
+            if let MenuModelEntryKind::Submenu(_, entries) = &entry.kind {
+                if world.get::<MenuModel>(item) != Some(entries) {
+                    world.entity_mut(item).insert(entries.clone());
+                    MenuModel::reconcile(item, entries, world);
+                }
+            }
+
+            ordered.push(item);
+        }
+
+        for (item, _) in existing {
+            world.entity_mut(item).despawn_recursive();
+        }
+
+        // Keep children that are not part of the model after the modelled items
+        let current_children: Vec<Entity> = match world.get::<Children>(container) {
+            Some(children) => children.iter().copied().collect(),
+            None => Vec::new(),
+        };
+        ordered.extend(
+            current_children
+                .iter()
+                .filter(|child| world.get::<MenuModelItem>(**child).is_none()),
+        );
+
+        if ordered != current_children {
+            world.entity_mut(container).replace_children(&ordered);
+        }
+    }
+
+    fn spawn_item(
+        container: Entity,
+        panel: Entity,
+        entry: &MenuModelEntry,
+        world: &mut World,
+    ) -> Entity {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let mut builder = commands.ui_builder(container);
+
+        let item = match &entry.kind {
+            MenuModelEntryKind::Item(config) => builder.menu_item(config.clone()).id(),
+            MenuModelEntryKind::Toggle(config) => builder.toggle_menu_item(config.clone()).id(),
+            MenuModelEntryKind::Separator => builder.separator().id(),
+            MenuModelEntryKind::Submenu(config, _) => {
+                Submenu::scaffold(&mut builder, panel, panel.into(), config.clone(), |_| {})
+            }
+        };
+
+        commands.entity(item).insert(MenuModelItem {
+            entry: entry.clone(),
+        });
+        queue.apply(world);
+
+        item
+    }
+
+    fn update_item(item: Entity, entry: &MenuModelEntry, world: &mut World) {
+        if let Some(name) = entry.kind.name() {
+            let label = if let Some(menu_item) = world.get::<MenuItem>(item) {
+                menu_item.get(MenuItem::LABEL).ok()
+            } else if let Some(toggle) = world.get::<ToggleMenuItem>(item) {
+                toggle.get(MenuItem::LABEL).ok()
+            } else if let Some(submenu) = world.get::<Submenu>(item) {
+                submenu.get(MenuItem::LABEL).ok()
+            } else {
+                None
+            };
+
+            if let Some(label) = label {
+                let value = world.resource::<Locale>().text(name);
+                if let Some(mut text) = world.get_mut::<Text>(label) {
+                    if let Some(section) = text.sections.first_mut() {
+                        if section.value != value {
+                            section.value = value;
+                        }
+                    }
+                }
+
+                if world.get::<LocalizedText>(label) != Some(name) {
+                    match name.is_literal() {
+                        true => world.entity_mut(label).remove::<LocalizedText>(),
+                        false => world.entity_mut(label).insert(name.clone()),
+                    };
+                }
+            }
+
+            if let Some(mut item_name) = world.get_mut::<Name>(item) {
+                item_name.set(format!("Menu Item [{}]", name));
+            }
+        }
+
+        if let MenuModelEntryKind::Toggle(config) = &entry.kind {
+            // The user toggled state is kept unless the model changes the initial state
+            let initial_state_changed = match world.get::<MenuModelItem>(item) {
+                Some(MenuModelItem {
+                    entry:
+                        MenuModelEntry {
+                            kind: MenuModelEntryKind::Toggle(old_config),
+                            ..
+                        },
+                }) => old_config.initially_checked != config.initially_checked,
+                _ => true,
+            };
+
+            if initial_state_changed {
+                if let Some(mut toggle) = world.get_mut::<ToggleMenuItem>(item) {
+                    if toggle.checked != config.initially_checked {
+                        toggle.checked = config.initially_checked;
+                    }
+                }
+            }
+        }
+
+        if let Some(mut model_item) = world.get_mut::<MenuModelItem>(item) {
+            model_item.entry = entry.clone();
+        }
+    }
+}
+
+/// Marks an item spawned from a [`MenuModel`] entry
+#[derive(Component, Clone, Debug)]
+pub struct MenuModelItem {
+    entry: MenuModelEntry,
+}
+
+impl MenuModelItem {
+    pub fn key(&self) -> &str {
+        &self.entry.key
+    }
+}
@@ -0,0 +1,393 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::layout::{
+    container::UiContainerExt,
+    scroll_view::{ScrollView, ScrollViewOffsetUpdate, UiScrollViewExt},
+};
+
+const MENU_SCROLL_SPEED: f32 = 300.;
+
+pub struct MenuScrollPlugin;
+
+impl Plugin for MenuScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            MenuScrollUpdate
+                .after(FluxInteractionUpdate)
+                .before(ScrollViewOffsetUpdate),
+        )
+        .add_plugins(ComponentThemePlugin::<MenuScrollArrow>::default())
+        .add_systems(
+            Update,
+            (scroll_menu_on_arrow_hover, fit_menu_scroll_views)
+                .chain()
+                .in_set(MenuScrollUpdate),
+        )
+        .add_systems(
+            Update,
+            update_menu_scroll_arrows.after(ScrollViewOffsetUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct MenuScrollUpdate;
+
+fn scroll_menu_on_arrow_hover(
+    r_time: Res<Time>,
+    q_arrows: Query<(&MenuScrollArrow, &Interaction)>,
+    mut q_scroll_views: Query<&mut ScrollView>,
+) {
+    for (arrow, interaction) in &q_arrows {
+        if *interaction == Interaction::None {
+            continue;
+        }
+
+        let Ok(mut scroll_view) = q_scroll_views.get_mut(arrow.scroll_view) else {
+            continue;
+        };
+
+        if scroll_view.disabled || scroll_view.overflow().y <= 0. {
+            continue;
+        }
+
+        let diff = MENU_SCROLL_SPEED * r_time.delta_seconds();
+        scroll_view.scroll_offset.y += match arrow.direction {
+            MenuScrollDirection::Up => -diff,
+            MenuScrollDirection::Down => diff,
+        };
+    }
+}
+
+fn fit_menu_scroll_views(world: &mut World) {
+    let mut q_scroll_views = world.query::<(Entity, &MenuScrollView, &ScrollView)>();
+    let mut sizes: Vec<(Entity, Vec2)> = Vec::with_capacity(q_scroll_views.iter(world).len());
+
+    for (entity, menu_scroll_view, scroll_view) in q_scroll_views.iter(world) {
+        let Some(content_node) = world.get::<Node>(scroll_view.content_container_id()) else {
+            continue;
+        };
+
+        // Closed submenus are not laid out, keep the last known size until they are.
+        let content_size = content_node.unrounded_size();
+        if content_size == Vec2::ZERO {
+            continue;
+        }
+
+        let viewport_size = UiUtils::viewport_size(entity, world);
+        let insets = UiUtils::padding_as_px(menu_scroll_view.container, world)
+            + UiUtils::border_as_px(menu_scroll_view.container, world);
+
+        let arrow_height = |arrow: Entity| -> f32 {
+            match scroll_view.overflow().y > 0. {
+                true => world
+                    .get::<Node>(arrow)
+                    .map_or(0., |node| node.unrounded_size().y),
+                false => 0.,
+            }
+        };
+
+        let height_limit = if menu_scroll_view.fixed_top {
+            // Unsafe unwrap: If a UI element doesn't have a Node or GT, we should panic!
+            let node = world.get::<Node>(entity).unwrap();
+            let transform = world.get::<GlobalTransform>(entity).unwrap();
+            let top = transform.translation().y - (node.unrounded_size().y / 2.);
+
+            viewport_size.y - top - insets.z - arrow_height(menu_scroll_view.down_arrow)
+        } else {
+            viewport_size.y
+                - insets.x
+                - insets.z
+                - arrow_height(menu_scroll_view.up_arrow)
+                - arrow_height(menu_scroll_view.down_arrow)
+        };
+
+        sizes.push((
+            entity,
+            Vec2::new(content_size.x, content_size.y.min(height_limit.max(0.))),
+        ));
+    }
+
+    for (entity, size) in sizes {
+        let Some(mut style) = world.get_mut::<Style>(entity) else {
+            continue;
+        };
+
+        if style.width != Val::Px(size.x) {
+            style.width = Val::Px(size.x);
+        }
+        if style.height != Val::Px(size.y) {
+            style.height = Val::Px(size.y);
+        }
+    }
+}
+
+fn update_menu_scroll_arrows(
+    q_scroll_views: Query<(&ScrollView, &MenuScrollView), Changed<ScrollView>>,
+    mut commands: Commands,
+) {
+    for (scroll_view, menu_scroll_view) in &q_scroll_views {
+        let overflow = scroll_view.overflow().y;
+        let offset = scroll_view.scroll_offset.y;
+
+        for (arrow, at_end) in [
+            (menu_scroll_view.up_arrow, offset <= 0.),
+            (menu_scroll_view.down_arrow, offset >= overflow),
+        ] {
+            let mut arrow = commands.entity(arrow);
+            if overflow > 0. {
+                arrow.add_pseudo_state(PseudoState::OverflowY);
+            } else {
+                arrow.remove_pseudo_state(PseudoState::OverflowY);
+            }
+
+            if at_end {
+                arrow.add_pseudo_state(PseudoState::Disabled);
+            } else {
+                arrow.remove_pseudo_state(PseudoState::Disabled);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum MenuScrollDirection {
+    #[default]
+    Up,
+    Down,
+}
+
+/// Placed on the [`ScrollView`] that holds the items of a menu, submenu or context menu.
+///
+/// The scroll view is sized to its content, limited by the viewport of the menu.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuScrollView {
+    container: Entity,
+    up_arrow: Entity,
+    down_arrow: Entity,
+    fixed_top: bool,
+}
+
+impl Default for MenuScrollView {
+    fn default() -> Self {
+        Self {
+            container: Entity::PLACEHOLDER,
+            up_arrow: Entity::PLACEHOLDER,
+            down_arrow: Entity::PLACEHOLDER,
+            fixed_top: false,
+        }
+    }
+}
+
+impl MenuScrollView {
+    /// Spawns the scroll arrows and the scroll view into the menu container.
+    ///
+    /// Returns the scroll view and its content container, where menu items should be spawned.
+    /// If `fixed_top` is set, the height is limited by the space below the top of the scroll view,
+    /// otherwise the container is expected to be moved to fit the viewport.
+    pub(crate) fn scaffold(container: &mut UiBuilder<Entity>, fixed_top: bool) -> (Entity, Entity) {
+        let mut menu_scroll_view = MenuScrollView {
+            container: container.id(),
+            fixed_top,
+            ..default()
+        };
+        let mut content = Entity::PLACEHOLDER;
+
+        let scroll_view = container
+            .scroll_view(None, |scroll_view| {
+                content = scroll_view.id();
+            })
+            .id();
+
+        menu_scroll_view.up_arrow =
+            MenuScrollArrow::spawn(container, scroll_view, MenuScrollDirection::Up);
+        menu_scroll_view.down_arrow =
+            MenuScrollArrow::spawn(container, scroll_view, MenuScrollDirection::Down);
+
+        let up_arrow = menu_scroll_view.up_arrow;
+        container.entity_commands().insert_children(0, &[up_arrow]);
+        container
+            .commands()
+            .entity(scroll_view)
+            .insert(menu_scroll_view);
+
+        // Let the content determine the size of the menu, not the other way around
+        container
+            .commands()
+            .style_unchecked(content)
+            .min_width(Val::Auto)
+            .min_height(Val::Auto);
+
+        (scroll_view, content)
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuScrollArrow {
+    scroll_view: Entity,
+    direction: MenuScrollDirection,
+    icon: Entity,
+}
+
+impl Default for MenuScrollArrow {
+    fn default() -> Self {
+        Self {
+            scroll_view: Entity::PLACEHOLDER,
+            direction: Default::default(),
+            icon: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl UiContext for MenuScrollArrow {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            MenuScrollArrow::ICON => Ok(self.icon),
+            _ => Err(format!(
+                "{} doesn't exist for MenuScrollArrow. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [MenuScrollArrow::ICON].into_iter()
+    }
+}
+
+impl DefaultTheme for MenuScrollArrow {
+    fn default_theme() -> Option<Theme<MenuScrollArrow>> {
+        MenuScrollArrow::theme().into()
+    }
+}
+
+impl MenuScrollArrow {
+    pub const ICON: &'static str = "Icon";
+
+    fn new(scroll_view: Entity, direction: MenuScrollDirection) -> Self {
+        Self {
+            scroll_view,
+            direction,
+            ..default()
+        }
+    }
+
+    pub fn direction(&self) -> MenuScrollDirection {
+        self.direction
+    }
+
+    pub fn theme() -> Theme<MenuScrollArrow> {
+        let base_theme = PseudoTheme::deferred_context(None, MenuScrollArrow::primary_style);
+        let overflow_theme = PseudoTheme::deferred(
+            vec![PseudoState::OverflowY],
+            MenuScrollArrow::overflow_style,
+        );
+        let disabled_theme = PseudoTheme::deferred_context(
+            vec![PseudoState::OverflowY, PseudoState::Disabled],
+            MenuScrollArrow::disabled_style,
+        );
+
+        Theme::new(vec![base_theme, overflow_theme, disabled_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        arrow: &MenuScrollArrow,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+            .height(Val::Px(theme_spacing.areas.extra_small))
+            .display(Display::None)
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(MenuScrollArrow::ICON)
+            .size(Val::Px(theme_spacing.icons.small))
+            .icon(arrow.icon_data(theme_data).with(
+                colors.on(OnColor::SurfaceVariant),
+                theme_spacing.icons.small,
+            ));
+    }
+
+    fn overflow_style(style_builder: &mut StyleBuilder, _theme_data: &ThemeData) {
+        style_builder.display(Display::Flex);
+    }
+
+    fn disabled_style(
+        style_builder: &mut StyleBuilder,
+        arrow: &MenuScrollArrow,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing();
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(MenuScrollArrow::ICON)
+            .icon(arrow.icon_data(theme_data).with(
+                colors.on(OnColor::SurfaceVariant).with_alpha(0.38),
+                theme_spacing.icons.small,
+            ));
+    }
+
+    fn icon_data(&self, theme_data: &ThemeData) -> IconData {
+        match self.direction {
+            MenuScrollDirection::Up => theme_data.icons.expand_less.clone(),
+            MenuScrollDirection::Down => theme_data.icons.expand_more.clone(),
+        }
+    }
+
+    fn button(direction: MenuScrollDirection) -> impl Bundle {
+        (
+            Name::new(match direction {
+                MenuScrollDirection::Up => "Scroll Up",
+                MenuScrollDirection::Down => "Scroll Down",
+            }),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn icon() -> impl Bundle {
+        (
+            Name::new("Icon"),
+            ImageBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            BorderColor::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+
+    fn spawn(
+        container: &mut UiBuilder<Entity>,
+        scroll_view: Entity,
+        direction: MenuScrollDirection,
+    ) -> Entity {
+        let mut arrow = MenuScrollArrow::new(scroll_view, direction);
+
+        container
+            .container(MenuScrollArrow::button(direction), |button| {
+                arrow.icon = button.spawn(MenuScrollArrow::icon()).id();
+            })
+            .insert(arrow)
+            .id()
+    }
+}
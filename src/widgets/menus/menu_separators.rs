@@ -38,7 +38,7 @@ impl MenuSeparator {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -88,7 +88,7 @@ impl MenuItemSeparator {
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
 
         style_builder
@@ -107,6 +107,12 @@ pub trait UiMenuItemSeparatorExt {
     fn separator(&mut self) -> UiBuilder<Entity>;
 }
 
+impl UiMenuItemSeparatorExt for UiBuilder<'_, Entity> {
+    fn separator(&mut self) -> UiBuilder<Entity> {
+        self.spawn((MenuItemSeparator::separator(), MenuItemSeparator))
+    }
+}
+
 impl UiMenuItemSeparatorExt for UiBuilder<'_, Menu> {
     fn separator(&mut self) -> UiBuilder<Entity> {
         let container_id = self.container();
@@ -2,17 +2,18 @@ use bevy::{prelude::*, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
+use crate::widgets::layout::container::UiContainerExt;
+
 use super::{
     context_menu::{ContextMenu, ContextMenuUpdate, UiContextMenuExt},
     menu::{Menu, MenuUpdate, UiMenuSubExt},
     menu_item::{MenuItem, MenuItemConfig},
+    menu_scroll::MenuScrollView,
 };
 
 const MENU_CONTAINER_FADE_TIMEOUT: f32 = 1.;
 const MENU_CONTAINER_SWITCH_TIMEOUT: f32 = 0.3;
 
-// TODO: Add vertically scrollable container and height constraint
-// TODO: Best effort position submenu within window bounds
 pub struct SubmenuPlugin;
 
 impl Plugin for SubmenuPlugin {
@@ -35,10 +36,12 @@ impl Plugin for SubmenuPlugin {
                 update_open_submenu_containers,
                 update_submenu_state,
                 update_submenu_style,
+                position_open_submenu_containers,
             )
                 .chain()
                 .in_set(SubmenuUpdate),
-        );
+        )
+        .add_systems(PostUpdate, delete_orphaned_submenu_containers);
     }
 }
 
@@ -213,6 +216,42 @@ fn update_submenu_style(
     }
 }
 
+fn position_open_submenu_containers(world: &mut World) {
+    let mut q_containers = world.query::<(Entity, &SubmenuContainer)>();
+    let placements: Vec<(Entity, Vec2)> = q_containers
+        .iter(world)
+        .filter(|(_, container)| container.is_open)
+        .filter_map(|(entity, container)| {
+            Submenu::container_placement(container.submenu, entity, world)
+                .map(|placement| (entity, placement))
+        })
+        .collect();
+
+    for (entity, placement) in placements {
+        let Some(mut style) = world.get_mut::<Style>(entity) else {
+            continue;
+        };
+
+        if style.left != Val::Px(placement.x) {
+            style.left = Val::Px(placement.x);
+        }
+        if style.top != Val::Px(placement.y) {
+            style.top = Val::Px(placement.y);
+        }
+    }
+}
+
+fn delete_orphaned_submenu_containers(
+    q_containers: Query<(Entity, &SubmenuContainer)>,
+    mut commands: Commands,
+) {
+    for (entity, container) in &q_containers {
+        if commands.get_entity(container.submenu).is_none() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
 fn close_containers_of(world: &mut World, external: Entity) {
     let mut q_all_containers = world.query::<(Entity, &mut SubmenuContainer)>();
     let mut containers_closed: Vec<Entity> =
@@ -241,6 +280,7 @@ pub struct SubmenuContainerState {
 #[reflect(Component)]
 pub struct SubmenuContainer {
     is_open: bool,
+    submenu: Entity,
     external_container: Entity,
 }
 
@@ -248,14 +288,15 @@ impl Default for SubmenuContainer {
     fn default() -> Self {
         Self {
             is_open: Default::default(),
+            submenu: Entity::PLACEHOLDER,
             external_container: Entity::PLACEHOLDER,
         }
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct SubmenuConfig {
-    pub name: String,
+    pub name: LocalizedText,
     pub alt_code: Option<KeyCode>,
     pub leading_icon: IconData,
 }
@@ -277,6 +318,7 @@ pub struct Submenu {
     is_open: bool,
     is_focused: bool,
     container: Entity,
+    content: Entity,
     external_container: Entity,
     leading: Entity,
     leading_icon: IconData,
@@ -293,6 +335,7 @@ impl Default for Submenu {
             is_open: false,
             is_focused: false,
             container: Entity::PLACEHOLDER,
+            content: Entity::PLACEHOLDER,
             external_container: Entity::PLACEHOLDER,
             leading: Entity::PLACEHOLDER,
             leading_icon: Default::default(),
@@ -312,6 +355,7 @@ impl Into<Submenu> for MenuItem {
             is_focused: false,
             external_container: Entity::PLACEHOLDER,
             container: Entity::PLACEHOLDER,
+            content: Entity::PLACEHOLDER,
             label: self.label(),
             leading: self.leading(),
             leading_icon: self.leading_icon(),
@@ -362,6 +406,16 @@ impl UiContext for Submenu {
 impl Submenu {
     pub const MENU_CONTAINER: &'static str = "MenuContainer";
 
+    /// The entity menu items are spawned into
+    pub(crate) fn item_container(&self) -> Entity {
+        self.content
+    }
+
+    /// The panel submenu containers are mounted to
+    pub(crate) fn panel(&self) -> Entity {
+        self.container
+    }
+
     pub fn theme() -> Theme<Submenu> {
         let base_theme = PseudoTheme::deferred_context(None, Submenu::primary_style);
         let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Submenu::open_style);
@@ -374,7 +428,7 @@ impl Submenu {
         menu_item: &Submenu,
         theme_data: &ThemeData,
     ) {
-        let theme_spacing = theme_data.spacing;
+        let theme_spacing = theme_data.spacing();
         let colors = theme_data.colors();
         let leading_icon = menu_item.leading_icon.clone();
         let trailing_icon = theme_data.icons.arrow_right.clone();
@@ -384,7 +438,6 @@ impl Submenu {
         style_builder
             .switch_target(Submenu::MENU_CONTAINER)
             .position_type(PositionType::Absolute)
-            .top(Val::Px(0.))
             .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
             .flex_direction(FlexDirection::Column)
@@ -398,23 +451,76 @@ impl Submenu {
             .visibility(Visibility::Hidden);
     }
 
-    fn open_style(style_builder: &mut StyleBuilder, entity: Entity, _: &Submenu, world: &World) {
+    fn open_style(
+        style_builder: &mut StyleBuilder,
+        entity: Entity,
+        submenu: &Submenu,
+        world: &World,
+    ) {
         let theme_data = world.resource::<ThemeData>().clone();
         let colors = theme_data.colors();
 
         style_builder.background_color(colors.container(Container::SurfaceHighest));
 
-        // Unsafe unwrap: if the menu item doesn't have a node, panic!
-        let node = world.get::<Node>(entity).unwrap();
+        let placement =
+            Submenu::container_placement(entity, submenu.container, world).unwrap_or_default();
 
         style_builder
             .switch_target(Submenu::MENU_CONTAINER)
-            .left(Val::Px(node.size().x))
+            .left(Val::Px(placement.x))
+            .top(Val::Px(placement.y))
             .display(Display::Flex)
             .visibility(Visibility::Inherited);
     }
 
-    fn container_bundle(external_container: Entity) -> impl Bundle {
+    /// Calculates the position of the submenu container relative to its parent.
+    ///
+    /// The container is placed after the submenu item in the layout direction (to the right, or to
+    /// the left in right-to-left layouts), aligned to its top. If it would overflow the viewport,
+    /// it is flipped to the other side and / or up, or pushed back inside the viewport as a last
+    /// resort.
+    pub fn container_placement(submenu: Entity, container: Entity, world: &World) -> Option<Vec2> {
+        let item_size = world.get::<Node>(submenu)?.unrounded_size();
+        let item_top_left = world
+            .get::<GlobalTransform>(submenu)?
+            .translation()
+            .truncate()
+            - (item_size / 2.);
+
+        let parent = world.get::<Parent>(container)?.get();
+        let parent_size = world.get::<Node>(parent)?.unrounded_size();
+        let parent_border = UiUtils::border_as_px(parent, world);
+        let parent_top_left = world
+            .get::<GlobalTransform>(parent)?
+            .translation()
+            .truncate()
+            - (parent_size / 2.)
+            + Vec2::new(parent_border.w, parent_border.x);
+
+        let container_size = world.get::<Node>(container)?.unrounded_size();
+        let viewport_size = UiUtils::viewport_size(container, world);
+
+        let direction = world.resource::<ThemeData>().layout_direction;
+        let after = item_top_left.x + item_size.x;
+        let before = item_top_left.x - container_size.x;
+        let fits_after = after + container_size.x <= viewport_size.x;
+        let fits_before = before >= 0.;
+
+        let mut position = Vec2::new(after, item_top_left.y);
+        position.x = match (direction.is_rtl(), fits_before, fits_after) {
+            (false, _, true) | (true, false, true) => after,
+            (true, true, _) | (false, true, false) => before,
+            (_, false, false) => (viewport_size.x - container_size.x).max(0.),
+        };
+
+        if position.y + container_size.y > viewport_size.y {
+            position.y = (item_top_left.y + item_size.y - container_size.y).max(0.);
+        }
+
+        Some(position - parent_top_left)
+    }
+
+    fn container_bundle(submenu: Entity, external_container: Entity) -> impl Bundle {
         (
             Name::new("Submenu Container"),
             NodeBundle {
@@ -433,19 +539,67 @@ impl Submenu {
             TrackedInteraction::default(),
             SubmenuContainerState::default(),
             SubmenuContainer {
+                submenu,
                 external_container,
                 ..default()
             },
         )
     }
+
+    /// Spawns a submenu item into `builder` and its container into `mount`.
+    ///
+    /// When `mount` is `None`, the container is placed under the submenu item itself.
+    pub(crate) fn scaffold(
+        builder: &mut UiBuilder<Entity>,
+        external_container: Entity,
+        mount: Option<Entity>,
+        config: SubmenuConfig,
+        spawn_items: impl FnOnce(&mut UiBuilder<Submenu>),
+    ) -> Entity {
+        let (id, menu_item) = MenuItem::scaffold(builder, config);
+        let mount = mount.unwrap_or(id);
+
+        let mut content = Entity::PLACEHOLDER;
+        let container = builder
+            .commands()
+            .ui_builder(mount)
+            .container(
+                Submenu::container_bundle(id, external_container),
+                |container| {
+                    (_, content) = MenuScrollView::scaffold(container, false);
+                },
+            )
+            .id();
+
+        let submenu = Submenu {
+            container,
+            content,
+            external_container,
+            ..menu_item.into()
+        };
+
+        let mut content_builder = builder.commands().ui_builder(submenu.clone());
+        spawn_items(&mut content_builder);
+
+        builder.commands().ui_builder(id).insert(submenu);
+        id
+    }
 }
 
 pub trait UiSubmenuSubExt {
+    /// The entity menu items are spawned into
     fn container(&self) -> Entity;
+
+    /// The submenu panel, nested submenu panels are mounted here to escape the scrolled area
+    fn panel(&self) -> Entity;
 }
 
 impl UiSubmenuSubExt for UiBuilder<'_, Submenu> {
     fn container(&self) -> Entity {
+        self.context().content
+    }
+
+    fn panel(&self) -> Entity {
         self.context().container
     }
 }
@@ -468,25 +622,9 @@ impl UiSubmenuExt for UiBuilder<'_, Entity> {
         config: impl Into<SubmenuConfig>,
         spawn_items: impl FnOnce(&mut UiBuilder<Submenu>),
     ) -> UiBuilder<Entity> {
-        let config = config.into();
         let external_container = self.id();
-        let (id, menu_item) = MenuItem::scaffold(self, config);
-        let container = self
-            .commands()
-            .ui_builder(id)
-            .spawn(Submenu::container_bundle(external_container))
-            .id();
+        let id = Submenu::scaffold(self, external_container, None, config.into(), spawn_items);
 
-        let submenu = Submenu {
-            container,
-            external_container,
-            ..menu_item.into()
-        };
-
-        let mut content_builder = self.commands().ui_builder(submenu.clone());
-        spawn_items(&mut content_builder);
-
-        self.commands().ui_builder(id).insert(submenu);
         self.commands().ui_builder(id)
     }
 }
@@ -498,11 +636,14 @@ impl UiSubmenuExt for UiBuilder<'_, Menu> {
         spawn_items: impl FnOnce(&mut UiBuilder<Submenu>),
     ) -> UiBuilder<Entity> {
         let container_id = self.container();
-        let id = self
-            .commands()
-            .ui_builder(container_id)
-            .submenu(config, spawn_items)
-            .id();
+        let panel = self.panel();
+        let id = Submenu::scaffold(
+            &mut self.commands().ui_builder(container_id),
+            panel,
+            panel.into(),
+            config.into(),
+            spawn_items,
+        );
 
         self.commands().ui_builder(id)
     }
@@ -515,11 +656,14 @@ impl UiSubmenuExt for UiBuilder<'_, Submenu> {
         spawn_items: impl FnOnce(&mut UiBuilder<Submenu>),
     ) -> UiBuilder<Entity> {
         let container_id = self.container();
-        let id = self
-            .commands()
-            .ui_builder(container_id)
-            .submenu(config, spawn_items)
-            .id();
+        let panel = self.panel();
+        let id = Submenu::scaffold(
+            &mut self.commands().ui_builder(container_id),
+            panel,
+            panel.into(),
+            config.into(),
+            spawn_items,
+        );
 
         self.commands().ui_builder(id)
     }
@@ -532,11 +676,14 @@ impl UiSubmenuExt for UiBuilder<'_, ContextMenu> {
         spawn_items: impl FnOnce(&mut UiBuilder<Submenu>),
     ) -> UiBuilder<Entity> {
         let container_id = self.container();
-        let id = self
-            .commands()
-            .ui_builder(container_id)
-            .submenu(config, spawn_items)
-            .id();
+        let panel = self.panel();
+        let id = Submenu::scaffold(
+            &mut self.commands().ui_builder(container_id),
+            panel,
+            panel.into(),
+            config.into(),
+            spawn_items,
+        );
 
         self.commands().ui_builder(id)
     }
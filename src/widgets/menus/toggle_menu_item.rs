@@ -1,6 +1,8 @@
-use bevy::prelude::*;
+use std::marker::PhantomData;
 
-use sickle_ui_scaffold::prelude::*;
+use bevy::{prelude::*, state::state::FreelyMutableState};
+
+use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateStatesExt};
 
 use super::{
     context_menu::{ContextMenu, UiContextMenuExt},
@@ -26,6 +28,7 @@ impl Plugin for ToggleMenuItemPlugin {
             (
                 update_toggle_menu_item_value,
                 update_toggle_menu_item_on_shortcut_press,
+                update_toggle_menu_item_groups,
                 update_toggle_menu_checkmark,
             )
                 .chain()
@@ -34,25 +37,213 @@ impl Plugin for ToggleMenuItemPlugin {
     }
 }
 
+/// Keeps toggle menu items in sync with a resource
+///
+/// Add one for each resource type used in a [`ToggleMenuItemResourceBinding`].
+pub struct ToggleMenuItemResourceBindingPlugin<R>
+where
+    R: Resource,
+{
+    resource: PhantomData<R>,
+}
+
+impl<R> Default for ToggleMenuItemResourceBindingPlugin<R>
+where
+    R: Resource,
+{
+    fn default() -> Self {
+        Self {
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R> Plugin for ToggleMenuItemResourceBindingPlugin<R>
+where
+    R: Resource,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_toggle_menu_item_from_resource::<R>
+                    .before(update_toggle_menu_item_value)
+                    .run_if(resource_exists::<R>),
+                update_resource_from_toggle_menu_item::<R>
+                    .after(update_toggle_menu_item_groups)
+                    .before(update_toggle_menu_checkmark)
+                    .run_if(resource_exists::<R>),
+            )
+                .in_set(ToggleMenuItemUpdate),
+        );
+    }
+}
+
+/// Keeps toggle menu items in sync with a state
+///
+/// Add one for each state type used in a [`ToggleMenuItemStateBinding`].
+pub struct ToggleMenuItemStateBindingPlugin<S>
+where
+    S: States + FreelyMutableState,
+{
+    state: PhantomData<S>,
+}
+
+impl<S> Default for ToggleMenuItemStateBindingPlugin<S>
+where
+    S: States + FreelyMutableState,
+{
+    fn default() -> Self {
+        Self { state: PhantomData }
+    }
+}
+
+impl<S> Plugin for ToggleMenuItemStateBindingPlugin<S>
+where
+    S: States + FreelyMutableState,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_toggle_menu_item_from_state::<S>
+                    .before(update_toggle_menu_item_value)
+                    .run_if(resource_exists::<State<S>>),
+                update_state_from_toggle_menu_item::<S>
+                    .after(update_toggle_menu_item_groups)
+                    .before(update_toggle_menu_checkmark)
+                    .run_if(resource_exists::<State<S>>),
+            )
+                .in_set(ToggleMenuItemUpdate),
+        );
+    }
+}
+
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct ToggleMenuItemUpdate;
 
 fn update_toggle_menu_item_value(
-    mut q_menu_items: Query<(&mut ToggleMenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_menu_items: Query<
+        (
+            &mut ToggleMenuItem,
+            &FluxInteraction,
+            Has<ToggleMenuItemGroup>,
+        ),
+        Changed<FluxInteraction>,
+    >,
 ) {
-    for (mut toggle, interaction) in &mut q_menu_items {
+    for (mut toggle, interaction, in_group) in &mut q_menu_items {
         if interaction.is_pressed() {
-            toggle.checked = !toggle.checked;
+            toggle.toggle(in_group);
         }
     }
 }
 
 fn update_toggle_menu_item_on_shortcut_press(
-    mut q_menu_items: Query<(&mut ToggleMenuItem, &Shortcut), Changed<Shortcut>>,
+    mut q_menu_items: Query<
+        (&mut ToggleMenuItem, &Shortcut, Has<ToggleMenuItemGroup>),
+        Changed<Shortcut>,
+    >,
 ) {
-    for (mut toggle, shortcut) in &mut q_menu_items {
+    for (mut toggle, shortcut, in_group) in &mut q_menu_items {
         if shortcut.pressed() {
-            toggle.checked = !toggle.checked;
+            toggle.toggle(in_group);
+        }
+    }
+}
+
+fn update_toggle_menu_item_groups(
+    mut q_menu_items: Query<(Entity, &mut ToggleMenuItem, &ToggleMenuItemGroup, &Parent)>,
+) {
+    let checked: Vec<(Entity, ToggleMenuItemGroup, Entity)> = q_menu_items
+        .iter_mut()
+        .filter(|(_, toggle, ..)| toggle.is_changed() && toggle.checked)
+        .map(|(entity, _, group, parent)| (entity, group.clone(), parent.get()))
+        .collect();
+
+    for (checked_item, checked_group, checked_parent) in checked {
+        for (entity, mut toggle, group, parent) in &mut q_menu_items {
+            if entity != checked_item
+                && toggle.checked
+                && *group == checked_group
+                && parent.get() == checked_parent
+            {
+                toggle.checked = false;
+            }
+        }
+    }
+}
+
+fn update_toggle_menu_item_from_resource<R: Resource>(
+    r_resource: Res<R>,
+    mut q_menu_items: Query<(Ref<ToggleMenuItemResourceBinding<R>>, &mut ToggleMenuItem)>,
+) {
+    for (binding, mut toggle) in &mut q_menu_items {
+        if !r_resource.is_changed() && !binding.is_added() {
+            continue;
+        }
+
+        let checked = (binding.get)(&r_resource);
+        if toggle.checked != checked {
+            toggle.checked = checked;
+        }
+    }
+}
+
+fn update_resource_from_toggle_menu_item<R: Resource>(
+    mut r_resource: ResMut<R>,
+    q_menu_items: Query<
+        (&ToggleMenuItemResourceBinding<R>, &ToggleMenuItem),
+        Changed<ToggleMenuItem>,
+    >,
+) {
+    for (binding, toggle) in &q_menu_items {
+        if (binding.get)(&r_resource) != toggle.checked {
+            (binding.set)(&mut r_resource, toggle.checked);
+        }
+    }
+}
+
+fn update_toggle_menu_item_from_state<S: States + FreelyMutableState>(
+    r_state: Res<State<S>>,
+    mut q_menu_items: Query<(Ref<ToggleMenuItemStateBinding<S>>, &mut ToggleMenuItem)>,
+) {
+    for (binding, mut toggle) in &mut q_menu_items {
+        if !r_state.is_changed() && !binding.is_added() {
+            continue;
+        }
+
+        let checked = *r_state.get() == binding.state;
+        if toggle.checked != checked {
+            toggle.checked = checked;
+        }
+    }
+}
+
+fn update_state_from_toggle_menu_item<S: States + FreelyMutableState>(
+    r_state: Res<State<S>>,
+    mut q_menu_items: Query<
+        (&ToggleMenuItemStateBinding<S>, &mut ToggleMenuItem),
+        Changed<ToggleMenuItem>,
+    >,
+    mut commands: Commands,
+) {
+    let mut in_transition = false;
+    for (binding, toggle) in &q_menu_items {
+        if toggle.checked && *r_state.get() != binding.state {
+            commands.next_state(binding.state.clone());
+            in_transition = true;
+        }
+    }
+
+    if in_transition {
+        return;
+    }
+
+    // A state cannot be left without entering another one
+    for (binding, mut toggle) in &mut q_menu_items {
+        if !toggle.checked && *r_state.get() == binding.state {
+            toggle.checked = true;
         }
     }
 }
@@ -74,13 +265,15 @@ fn update_toggle_menu_checkmark(
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ToggleMenuItemConfig {
-    pub name: String,
+    pub name: LocalizedText,
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
     pub shortcut: Option<Vec<KeyCode>>,
     pub initially_checked: bool,
+    /// Items in the same group under the same parent are mutually exclusive
+    pub group: Option<String>,
 }
 
 impl Into<MenuItemConfig> for ToggleMenuItemConfig {
@@ -108,6 +301,66 @@ pub struct ToggleMenuItem {
     alt_code: Option<KeyCode>,
 }
 
+impl ToggleMenuItem {
+    fn toggle(&mut self, in_group: bool) {
+        // Grouped items can only be unchecked by checking another item in the group
+        if in_group && self.checked {
+            return;
+        }
+
+        self.checked = !self.checked;
+    }
+}
+
+/// Groups toggle menu items under the same parent, only one of them can be checked at a time
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ToggleMenuItemGroup(pub String);
+
+/// Binds a toggle menu item to a value of a resource, both ways
+///
+/// Requires [`ToggleMenuItemResourceBindingPlugin`] for the resource type.
+/// When the item is part of a [`ToggleMenuItemGroup`], `set` is called with `false` for the
+/// items that get unchecked, which can be ignored for enum-like values.
+#[derive(Component)]
+pub struct ToggleMenuItemResourceBinding<R>
+where
+    R: Resource,
+{
+    get: fn(&R) -> bool,
+    set: fn(&mut R, bool),
+}
+
+impl<R> ToggleMenuItemResourceBinding<R>
+where
+    R: Resource,
+{
+    pub fn new(get: fn(&R) -> bool, set: fn(&mut R, bool)) -> Self {
+        Self { get, set }
+    }
+}
+
+/// Binds a toggle menu item to a state, the item is checked when the state is current
+///
+/// Checking the item transitions to the state using `next_state`.
+/// Requires [`ToggleMenuItemStateBindingPlugin`] for the state type.
+#[derive(Component, Clone, Debug)]
+pub struct ToggleMenuItemStateBinding<S>
+where
+    S: States + FreelyMutableState,
+{
+    state: S,
+}
+
+impl<S> ToggleMenuItemStateBinding<S>
+where
+    S: States + FreelyMutableState,
+{
+    pub fn new(state: S) -> Self {
+        Self { state }
+    }
+}
+
 impl Default for ToggleMenuItem {
     fn default() -> Self {
         Self {
@@ -205,6 +458,10 @@ impl ToggleMenuItem {
 pub trait UiToggleMenuItemExt {
     /// A toggle menu item in a menu, context menu, or submenu
     ///
+    /// Items with a `group` set in their config are exclusive with their grouped siblings.
+    /// Insert a [`ToggleMenuItemResourceBinding`] or [`ToggleMenuItemStateBinding`] to mirror
+    /// a resource or state value.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is used when the item is checked
     fn toggle_menu_item(&mut self, config: impl Into<ToggleMenuItemConfig>) -> UiBuilder<Entity>;
@@ -214,6 +471,7 @@ impl UiToggleMenuItemExt for UiBuilder<'_, Entity> {
     fn toggle_menu_item(&mut self, config: impl Into<ToggleMenuItemConfig>) -> UiBuilder<Entity> {
         let item_config = config.into();
         let checked = item_config.initially_checked;
+        let group = item_config.group.clone();
         let (id, menu_item) = MenuItem::scaffold(self, item_config);
         let toggle_item = ToggleMenuItem {
             checked,
@@ -221,6 +479,12 @@ impl UiToggleMenuItemExt for UiBuilder<'_, Entity> {
         };
 
         self.commands().ui_builder(id).insert(toggle_item);
+        if let Some(group) = group {
+            self.commands()
+                .ui_builder(id)
+                .insert(ToggleMenuItemGroup(group));
+        }
+
         self.commands().ui_builder(id)
     }
 }